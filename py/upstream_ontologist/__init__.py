@@ -55,6 +55,7 @@ Supported, but currently not set.
 
 from dataclasses import dataclass
 from email.utils import parseaddr
+from enum import Enum
 from typing import Optional
 
 import ruamel.yaml
@@ -63,6 +64,48 @@ from . import _upstream_ontologist
 
 SUPPORTED_CERTAINTIES = ["certain", "confident", "likely", "possible", None]
 
+
+class Certainty(str, Enum):
+    """How certain a guess is.
+
+    Members compare by certainty rather than alphabetically, and compare equal to
+    their string values, so they can be passed wherever a certainty string is accepted.
+    """
+
+    POSSIBLE = "possible"
+    LIKELY = "likely"
+    CONFIDENT = "confident"
+    CERTAIN = "certain"
+
+    def _rank(self, other):
+        try:
+            other = type(self)(other)
+        except ValueError:
+            return NotImplemented
+        members = list(type(self))
+        return members.index(self) - members.index(other)
+
+    def __lt__(self, other):
+        rank = self._rank(other)
+        return rank if rank is NotImplemented else rank < 0
+
+    def __le__(self, other):
+        rank = self._rank(other)
+        return rank if rank is NotImplemented else rank <= 0
+
+    def __gt__(self, other):
+        rank = self._rank(other)
+        return rank if rank is NotImplemented else rank > 0
+
+    def __ge__(self, other):
+        rank = self._rank(other)
+        return rank if rank is NotImplemented else rank >= 0
+
+    __hash__ = str.__hash__
+
+    def __str__(self):
+        return self.value
+
 version_string = "0.2.2"
 
 USER_AGENT = "upstream-ontologist/" + version_string
@@ -129,6 +172,16 @@ class Person:
 
 UpstreamDatum = _upstream_ontologist.UpstreamDatum
 UpstreamMetadata = _upstream_ontologist.UpstreamMetadata
+MetadataDiff = _upstream_ontologist.MetadataDiff
+Origin = _upstream_ontologist.Origin
+bug_database_url_from_bug_submit_url = (
+    _upstream_ontologist.bug_database_url_from_bug_submit_url
+)
+bug_submit_url_from_bug_database_url = (
+    _upstream_ontologist.bug_submit_url_from_bug_database_url
+)
+check_bug_database_canonical = _upstream_ontologist.check_bug_database_canonical
+check_bug_submit_url_canonical = _upstream_ontologist.check_bug_submit_url_canonical
 
 
 # If we're setting them new, put Name and Contact first
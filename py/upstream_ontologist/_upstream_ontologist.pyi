@@ -1,5 +1,6 @@
-from collections.abc import Iterator
-from typing import Any
+import os
+from collections.abc import Callable, Iterable, Iterator
+from typing import Any, overload
 
 def drop_vcs_in_scheme(url: str) -> str: ...
 def unsplit_vcs_url(repo_url: str, branch: str | None, subpath: str | None) -> str: ...
@@ -20,6 +21,8 @@ def probe_upstream_branch_url(url: str, version: str | None = None) -> bool | No
 def canonical_git_repo_url(url: str, net_access: bool | None = None) -> str: ...
 def check_repository_url_canonical(url: str, version: str | None = None) -> str: ...
 def known_bad_guess(datum: UpstreamDatum) -> bool: ...
+def add_known_bad_pattern(field: str, pattern: str) -> None: ...
+def remove_known_bad_pattern(field: str, pattern: str) -> None: ...
 def url_from_svn_co_command(command: bytes) -> str | None: ...
 def url_from_git_clone_command(command: bytes) -> str | None: ...
 def url_from_fossil_clone_command(command: bytes) -> str | None: ...
@@ -48,6 +51,11 @@ def fixup_rcp_style_git_repo_url(url: str) -> str: ...
 def check_upstream_metadata(
     data: UpstreamMetadata, version: str | None = None
 ) -> None: ...
+def update_metadata_file(
+    path: str | os.PathLike,
+    metadata: UpstreamMetadata,
+    minimum_certainty: str | None = None,
+) -> list[str]: ...
 
 class ParseError(Exception): ...
 class NoSuchForgeProject(Exception): ...
@@ -101,6 +109,19 @@ def guess_upstream_metadata(
     check: bool | None = None,
 ) -> UpstreamMetadata: ...
 def fix_upstream_metadata(upstream_metadata: UpstreamMetadata) -> None: ...
+def extrapolate_fields(
+    upstream_metadata: UpstreamMetadata,
+    net_access: bool | None = None,
+    iteration_limit: int | None = None,
+) -> None: ...
+def register_extrapolation(
+    from_fields: list[str],
+    to_fields: list[str],
+    callback: Callable[[UpstreamMetadata], list[UpstreamDatum]],
+) -> None: ...
+def unregister_extrapolation(
+    callback: Callable[[UpstreamMetadata], list[UpstreamDatum]],
+) -> None: ...
 def guess_upstream_metadata_items(
     path: str, trust_package: bool | None = None, minimum_certainty: str | None = None
 ) -> Iterator[UpstreamDatum]: ...
@@ -108,15 +129,288 @@ def update_from_guesses(
     upstream_metadata: UpstreamMetadata,
     items: list[UpstreamDatum],
 ) -> list[UpstreamDatum]: ...
+def guess_from_debian_watch(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_debian_control(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_debian_upstream_metadata(
+    path: str | os.PathLike,
+) -> UpstreamMetadata: ...
+def guess_from_debian_copyright(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_debian_changelog(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_meta_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_meta_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_makefile_pl(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_dist_ini(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_r_description(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_cabal(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_package_yaml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_opam(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_pubspec_yaml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_package_xml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_pod(
+    contents: str, origin: str | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_security_md(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_git_config(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_csproj(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_citation_cff(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_codemeta_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_zenodo_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_funding_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_snapcraft_yaml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_flatpak_manifest(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_desktop_file(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_appimage_builder_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_conda_meta(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_rpm_spec(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_pkgbuild(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_nix(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_build_gradle(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_gradle_properties(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_mix_exs(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_rebar_config(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_app_src(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_package_swift(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_spi_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_podspec(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_dub_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_dub_sdl(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_shard_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_project_clj(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_deps_edn(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_nimble(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_bower_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_wscript(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_vcpkg_json(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_conanfile(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_module_bazel(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_gemfile(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_rockspec(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_project_toml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_dockerfile(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_docker_compose(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_chart_yaml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_gitmodules(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_issue_template_config(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_codeowners(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_github_settings(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_sphinx_conf(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_mkdocs_yml(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_authors_file(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_license_file(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_changelog_file(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+def guess_version_from_git(
+    path: str, trust_package: bool | None = None
+) -> list[UpstreamDatum]: ...
+
+class MetadataDiff:
+    added: list[UpstreamDatum]
+    removed: list[UpstreamDatum]
+    changed: list[tuple[UpstreamDatum, UpstreamDatum]]
+    certainty_changed: list[tuple[UpstreamDatum, UpstreamDatum]]
+    def __bool__(self) -> bool: ...
 
 class UpstreamMetadata:
     def __init__(self, **kwargs): ...
-    ...
+    def __len__(self) -> int: ...
+    def __bool__(self) -> bool: ...
+    def keys(self) -> list[str]: ...
+    def copy(self) -> UpstreamMetadata: ...
+    def to_dict(self, include_certainty: bool = False) -> dict[str, Any]: ...
+    def __eq__(self, other: object) -> bool: ...
+    def diff(self, other: UpstreamMetadata) -> MetadataDiff: ...
+    def to_yaml(self) -> str: ...
+    @classmethod
+    def from_yaml(cls, text: str, certainty: str | None = None) -> UpstreamMetadata: ...
+    def merge(
+        self, other: UpstreamMetadata, strategy: str = "prefer_higher_certainty"
+    ) -> list[UpstreamDatum]: ...
+    def pop(self, field: str, *default: Any) -> Any: ...  # noqa: ANN401
+    def setdefault(self, field: str, datum: UpstreamDatum) -> UpstreamDatum: ...
+    def update(
+        self, other: UpstreamMetadata | Iterable[UpstreamDatum]
+    ) -> list[UpstreamDatum]: ...
 
     @classmethod
     def from_dict(cls, data, default_certainty: str | None = None): ...
 
+class _OriginKind:
+    @overload
+    def __get__(self, instance: None, owner: type[Origin]) -> _OriginKind: ...
+    @overload
+    def __get__(self, instance: Origin, owner: type[Origin]) -> str | None: ...
+    def __call__(self, value: str | os.PathLike[str]) -> Origin: ...
+
+class Origin:
+    path: _OriginKind
+    url: _OriginKind
+    other: _OriginKind
+    def __init__(self, kind: str, value: str | os.PathLike) -> None: ...
+    @property
+    def kind(self) -> str: ...
+
 class UpstreamDatum:
-    def __init__(self, name, value: Any, certainty: str | None = None): ...  # noqa: ANN401
+    def __init__(
+        self,
+        name,
+        value: Any,  # noqa: ANN401
+        certainty: str | None = None,
+        origin: Origin | str | None = None,
+    ): ...
+    origin: Origin | None
+    @property
+    def origins(self) -> list[Origin]: ...
+    def copy(self) -> UpstreamDatum: ...
 
 __version__: str
+def remote_crate_data(name: str) -> list[UpstreamDatum]: ...
+def remote_pypi_data(name: str) -> list[UpstreamDatum]: ...
+def remote_npm_data(name: str) -> list[UpstreamDatum]: ...
+def remote_rubygem_data(name: str) -> list[UpstreamDatum]: ...
+def remote_hackage_data(name: str) -> list[UpstreamDatum]: ...
+def remote_cran_data(name: str) -> list[UpstreamDatum]: ...
+def remote_packagist_data(name: str) -> list[UpstreamDatum]: ...
+def remote_nuget_data(id: str) -> list[UpstreamDatum]: ...
+def remote_maven_data(coordinates: str) -> list[UpstreamDatum]: ...
+def remote_pecl_data(name: str) -> list[UpstreamDatum]: ...
+def remote_hex_data(name: str) -> list[UpstreamDatum]: ...
+def remote_pub_data(name: str) -> list[UpstreamDatum]: ...
+def guess_from_github_api(repo_url: str) -> list[UpstreamDatum]: ...
+def guess_from_gitlab_api(repo_url: str) -> list[UpstreamDatum]: ...
+def guess_from_launchpad(
+    project: str, distribution: str | None = None, suite: str | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_gitea_api(repo_url: str) -> list[UpstreamDatum]: ...
+def guess_from_bitbucket_api(repo_url: str) -> list[UpstreamDatum]: ...
+def consult_repology(project_name: str) -> list[UpstreamDatum]: ...
+def guess_from_wikidata(
+    name: str | None = None, homepage: str | None = None
+) -> list[UpstreamDatum]: ...
+def guess_from_ecosystems(repo_url: str) -> list[UpstreamDatum]: ...
+def guess_from_openhub(name: str, api_key: str | None = None) -> list[UpstreamDatum]: ...
+def guess_from_debian_archive(name: str) -> list[UpstreamDatum]: ...
@@ -34,6 +34,9 @@ check_upstream_metadata = _upstream_ontologist.check_upstream_metadata
 extend_upstream_metadata = _upstream_ontologist.extend_upstream_metadata
 guess_upstream_metadata = _upstream_ontologist.guess_upstream_metadata
 known_bad_guess = _upstream_ontologist.known_bad_guess
+add_known_bad_pattern = _upstream_ontologist.add_known_bad_pattern
+remove_known_bad_pattern = _upstream_ontologist.remove_known_bad_pattern
+update_metadata_file = _upstream_ontologist.update_metadata_file
 
 
 def filter_bad_guesses(
@@ -43,6 +46,100 @@ def filter_bad_guesses(
 
 
 fix_upstream_metadata = _upstream_ontologist.fix_upstream_metadata
+extrapolate_fields = _upstream_ontologist.extrapolate_fields
+register_extrapolation = _upstream_ontologist.register_extrapolation
+unregister_extrapolation = _upstream_ontologist.unregister_extrapolation
 
 guess_upstream_metadata_items = _upstream_ontologist.guess_upstream_metadata_items
 update_from_guesses = _upstream_ontologist.update_from_guesses
+
+guess_from_debian_watch = _upstream_ontologist.guess_from_debian_watch
+guess_from_debian_control = _upstream_ontologist.guess_from_debian_control
+guess_from_debian_upstream_metadata = (
+    _upstream_ontologist.guess_from_debian_upstream_metadata
+)
+guess_from_debian_copyright = _upstream_ontologist.guess_from_debian_copyright
+guess_from_debian_changelog = _upstream_ontologist.guess_from_debian_changelog
+guess_from_meta_json = _upstream_ontologist.guess_from_meta_json
+guess_from_meta_yml = _upstream_ontologist.guess_from_meta_yml
+guess_from_makefile_pl = _upstream_ontologist.guess_from_makefile_pl
+guess_from_dist_ini = _upstream_ontologist.guess_from_dist_ini
+guess_from_r_description = _upstream_ontologist.guess_from_r_description
+guess_from_cabal = _upstream_ontologist.guess_from_cabal
+guess_from_package_yaml = _upstream_ontologist.guess_from_package_yaml
+guess_from_opam = _upstream_ontologist.guess_from_opam
+guess_from_pubspec_yaml = _upstream_ontologist.guess_from_pubspec_yaml
+guess_from_package_xml = _upstream_ontologist.guess_from_package_xml
+guess_from_pod = _upstream_ontologist.guess_from_pod
+guess_from_security_md = _upstream_ontologist.guess_from_security_md
+guess_from_git_config = _upstream_ontologist.guess_from_git_config
+guess_from_csproj = _upstream_ontologist.guess_from_csproj
+guess_from_citation_cff = _upstream_ontologist.guess_from_citation_cff
+guess_from_codemeta_json = _upstream_ontologist.guess_from_codemeta_json
+guess_from_zenodo_json = _upstream_ontologist.guess_from_zenodo_json
+guess_from_funding_yml = _upstream_ontologist.guess_from_funding_yml
+guess_from_snapcraft_yaml = _upstream_ontologist.guess_from_snapcraft_yaml
+guess_from_flatpak_manifest = _upstream_ontologist.guess_from_flatpak_manifest
+guess_from_desktop_file = _upstream_ontologist.guess_from_desktop_file
+guess_from_appimage_builder_yml = _upstream_ontologist.guess_from_appimage_builder_yml
+guess_from_conda_meta = _upstream_ontologist.guess_from_conda_meta
+guess_from_rpm_spec = _upstream_ontologist.guess_from_rpm_spec
+guess_from_pkgbuild = _upstream_ontologist.guess_from_pkgbuild
+guess_from_nix = _upstream_ontologist.guess_from_nix
+guess_from_build_gradle = _upstream_ontologist.guess_from_build_gradle
+guess_from_gradle_properties = _upstream_ontologist.guess_from_gradle_properties
+guess_from_mix_exs = _upstream_ontologist.guess_from_mix_exs
+guess_from_rebar_config = _upstream_ontologist.guess_from_rebar_config
+guess_from_app_src = _upstream_ontologist.guess_from_app_src
+guess_from_package_swift = _upstream_ontologist.guess_from_package_swift
+guess_from_spi_yml = _upstream_ontologist.guess_from_spi_yml
+guess_from_podspec = _upstream_ontologist.guess_from_podspec
+guess_from_dub_json = _upstream_ontologist.guess_from_dub_json
+guess_from_dub_sdl = _upstream_ontologist.guess_from_dub_sdl
+guess_from_shard_yml = _upstream_ontologist.guess_from_shard_yml
+guess_from_project_clj = _upstream_ontologist.guess_from_project_clj
+guess_from_deps_edn = _upstream_ontologist.guess_from_deps_edn
+guess_from_nimble = _upstream_ontologist.guess_from_nimble
+guess_from_bower_json = _upstream_ontologist.guess_from_bower_json
+guess_from_wscript = _upstream_ontologist.guess_from_wscript
+guess_from_vcpkg_json = _upstream_ontologist.guess_from_vcpkg_json
+guess_from_conanfile = _upstream_ontologist.guess_from_conanfile
+guess_from_module_bazel = _upstream_ontologist.guess_from_module_bazel
+guess_from_gemfile = _upstream_ontologist.guess_from_gemfile
+guess_from_rockspec = _upstream_ontologist.guess_from_rockspec
+guess_from_project_toml = _upstream_ontologist.guess_from_project_toml
+guess_from_dockerfile = _upstream_ontologist.guess_from_dockerfile
+guess_from_docker_compose = _upstream_ontologist.guess_from_docker_compose
+guess_from_chart_yaml = _upstream_ontologist.guess_from_chart_yaml
+guess_from_gitmodules = _upstream_ontologist.guess_from_gitmodules
+guess_from_issue_template_config = _upstream_ontologist.guess_from_issue_template_config
+guess_from_codeowners = _upstream_ontologist.guess_from_codeowners
+guess_from_github_settings = _upstream_ontologist.guess_from_github_settings
+guess_from_sphinx_conf = _upstream_ontologist.guess_from_sphinx_conf
+guess_from_mkdocs_yml = _upstream_ontologist.guess_from_mkdocs_yml
+guess_from_authors_file = _upstream_ontologist.guess_from_authors_file
+guess_from_license_file = _upstream_ontologist.guess_from_license_file
+guess_from_changelog_file = _upstream_ontologist.guess_from_changelog_file
+guess_version_from_git = _upstream_ontologist.guess_version_from_git
+remote_crate_data = _upstream_ontologist.remote_crate_data
+remote_pypi_data = _upstream_ontologist.remote_pypi_data
+remote_npm_data = _upstream_ontologist.remote_npm_data
+remote_rubygem_data = _upstream_ontologist.remote_rubygem_data
+remote_hackage_data = _upstream_ontologist.remote_hackage_data
+remote_cran_data = _upstream_ontologist.remote_cran_data
+remote_packagist_data = _upstream_ontologist.remote_packagist_data
+remote_nuget_data = _upstream_ontologist.remote_nuget_data
+remote_maven_data = _upstream_ontologist.remote_maven_data
+remote_pecl_data = _upstream_ontologist.remote_pecl_data
+remote_hex_data = _upstream_ontologist.remote_hex_data
+remote_pub_data = _upstream_ontologist.remote_pub_data
+guess_from_github_api = _upstream_ontologist.guess_from_github_api
+guess_from_gitlab_api = _upstream_ontologist.guess_from_gitlab_api
+guess_from_launchpad = _upstream_ontologist.guess_from_launchpad
+guess_from_gitea_api = _upstream_ontologist.guess_from_gitea_api
+guess_from_bitbucket_api = _upstream_ontologist.guess_from_bitbucket_api
+consult_repology = _upstream_ontologist.consult_repology
+guess_from_wikidata = _upstream_ontologist.guess_from_wikidata
+guess_from_ecosystems = _upstream_ontologist.guess_from_ecosystems
+guess_from_openhub = _upstream_ontologist.guess_from_openhub
+guess_from_debian_archive = _upstream_ontologist.guess_from_debian_archive
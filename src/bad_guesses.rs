@@ -0,0 +1,51 @@
+//! Additional patterns for values that are known to be bad guesses.
+//!
+//! upstream_ontologist has a fixed list of placeholder values; this allows callers to add
+//! their own, such as template values or internal placeholder domains.
+
+use lazy_regex::Regex;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::sync::Mutex;
+
+static PATTERNS: Mutex<Vec<(String, Regex)>> = Mutex::new(Vec::new());
+
+/// Add a pattern for values of a field that should be treated as bad guesses.
+///
+/// The regular expression has to match the complete value.
+#[pyfunction]
+pub fn add_known_bad_pattern(field: String, pattern: &str) -> PyResult<()> {
+    let regex = Regex::new(&format!("^(?:{})$", pattern))
+        .map_err(|e| PyValueError::new_err(format!("Invalid pattern: {}", e)))?;
+    PATTERNS.lock().unwrap().push((field, regex));
+    Ok(())
+}
+
+/// Remove a pattern previously added with add_known_bad_pattern.
+///
+/// Patterns that were never added are ignored.
+#[pyfunction]
+pub fn remove_known_bad_pattern(field: &str, pattern: &str) {
+    let anchored = format!("^(?:{})$", pattern);
+    PATTERNS
+        .lock()
+        .unwrap()
+        .retain(|(f, regex)| f != field || regex.as_str() != anchored);
+}
+
+/// Check whether a datum is a known bad guess, either according to upstream_ontologist or
+/// one of the registered patterns.
+pub fn known_bad_guess(datum: &upstream_ontologist::UpstreamDatum) -> bool {
+    if datum.known_bad_guess() {
+        return true;
+    }
+    let value = match datum.as_str() {
+        Some(value) => value,
+        None => return false,
+    };
+    PATTERNS
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(field, regex)| field == datum.field() && regex.is_match(value))
+}
@@ -0,0 +1,210 @@
+//! Conversions between the URLs of bug databases and the URLs for submitting bugs.
+//!
+//! upstream_ontologist only knows about GitHub, GitLab, Launchpad and SourceForge, and
+//! mangles GitLab submission URLs; the rules here are consulted first.
+
+use upstream_ontologist::{CanonicalizeError, HTTPJSONError};
+use url::Url;
+
+/// Convert between the Bugzilla CGI scripts for a product, e.g. from `buglist.cgi?product=foo`
+/// to `enter_bug.cgi?product=foo`.
+fn convert_bugzilla_url(url: &Url, from: &[&str], to: &str) -> Option<Url> {
+    let script = url.path_segments()?.next_back()?;
+    if !from.contains(&script) {
+        return None;
+    }
+    let product = url
+        .query_pairs()
+        .find(|(k, _)| k == "product")
+        .map(|(_, v)| v.into_owned())?;
+    let mut url = url.clone();
+    url.path_segments_mut().ok()?.pop().push(to);
+    url.set_fragment(None);
+    url.query_pairs_mut()
+        .clear()
+        .append_pair("product", &product);
+    Some(url)
+}
+
+/// Convert a URL for submitting bugs to the URL of the bug database it belongs to.
+///
+/// Issue submission URLs of the form `.../issues/new` are shared by GitHub, GitLab (with or
+/// without the `/-/` separator), Gitea and Forgejo, so this doesn't depend on identifying
+/// the forge.
+pub fn bug_database_url_from_bug_submit_url(url: &Url) -> Option<Url> {
+    if let Some(url) = convert_bugzilla_url(url, &["enter_bug.cgi"], "buglist.cgi") {
+        return Some(url);
+    }
+    let mut segments = url
+        .path_segments()?
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    if segments.len() < 3 || segments.pop()? != "new" || segments.last()? != &"issues" {
+        return None;
+    }
+    let mut url = url.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    url.path_segments_mut().ok()?.clear().extend(segments);
+    Some(url)
+}
+
+/// Convert the URL of a bug database to the URL for submitting bugs to it.
+///
+/// Unlike the other direction, `.../issues` is too generic to assume it is an issue list,
+/// so this only handles Bugzilla and hosts that are known to run Gitea.
+pub async fn bug_submit_url_from_bug_database_url(
+    url: &Url,
+    net_access: Option<bool>,
+) -> Option<Url> {
+    if let Some(url) = convert_bugzilla_url(
+        url,
+        &["buglist.cgi", "describecomponents.cgi"],
+        "enter_bug.cgi",
+    ) {
+        return Some(url);
+    }
+    let segments = url
+        .path_segments()?
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    if segments.len() != 3 || segments[2] != "issues" {
+        return None;
+    }
+    if !crate::providers::gitea::is_gitea_site(url.host_str()?, net_access).await {
+        return None;
+    }
+    let mut url = url.clone();
+    url.set_query(None);
+    url.set_fragment(None);
+    url.path_segments_mut()
+        .ok()?
+        .clear()
+        .extend(segments)
+        .push("new");
+    Some(url)
+}
+
+/// Verify a GitHub issue tracker URL using the GitHub API.
+///
+/// upstream_ontologist's check doesn't send a User-Agent, which GitHub rejects.
+async fn check_github_bug_database(url: &Url) -> Result<Url, CanonicalizeError> {
+    let segments = url
+        .path_segments()
+        .map(|s| s.filter(|s| !s.is_empty()).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if segments.len() != 3 || segments[2] != "issues" {
+        return Err(CanonicalizeError::InvalidUrl(
+            url.clone(),
+            "GitHub URL with missing path elements".to_string(),
+        ));
+    }
+    let (owner, repo) = crate::providers::github::github_repo(url.as_str()).ok_or_else(|| {
+        CanonicalizeError::InvalidUrl(url.clone(), "Not a GitHub repository".to_string())
+    })?;
+    let api_url = Url::parse(&format!("https://api.github.com/repos/{}/{}", owner, repo))
+        .map_err(|e| CanonicalizeError::InvalidUrl(url.clone(), e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&api_url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => {
+            return Err(CanonicalizeError::InvalidUrl(
+                url.clone(),
+                "Project does not exist".to_string(),
+            ))
+        }
+        Err(HTTPJSONError::Error {
+            status: 403 | 429, ..
+        }) => return Err(CanonicalizeError::RateLimited(url.clone())),
+        Err(e) => {
+            return Err(CanonicalizeError::Unverifiable(
+                url.clone(),
+                format!("Unable to verify bug database URL: {}", e),
+            ))
+        }
+    };
+    if data["has_issues"].as_bool() != Some(true) {
+        return Err(CanonicalizeError::InvalidUrl(
+            url.clone(),
+            "Project does not have issues enabled".to_string(),
+        ));
+    }
+    if data["archived"].as_bool() == Some(true) {
+        return Err(CanonicalizeError::InvalidUrl(
+            url.clone(),
+            "Project is archived".to_string(),
+        ));
+    }
+    let mut canonical = data["html_url"]
+        .as_str()
+        .and_then(|u| Url::parse(u).ok())
+        .ok_or_else(|| {
+            CanonicalizeError::Unverifiable(url.clone(), "no html_url in response".to_string())
+        })?;
+    canonical
+        .path_segments_mut()
+        .map_err(|_| CanonicalizeError::InvalidUrl(url.clone(), "invalid html_url".to_string()))?
+        .push("issues");
+    Ok(canonical)
+}
+
+/// Check that a bug database URL points at a live issue tracker, returning its canonical URL.
+///
+/// Forges that upstream_ontologist can't verify fall back to following any redirects.
+pub async fn check_bug_database_canonical(
+    url: &Url,
+    net_access: Option<bool>,
+) -> Result<Url, CanonicalizeError> {
+    if net_access == Some(false) {
+        return Err(CanonicalizeError::Unverifiable(
+            url.clone(),
+            "network access disabled".to_string(),
+        ));
+    }
+    if url.host_str() == Some("github.com") {
+        return check_github_bug_database(url).await;
+    }
+    if let Some(forge) = upstream_ontologist::find_forge(url, net_access).await {
+        match forge.check_bug_database_canonical(url).await {
+            Err(CanonicalizeError::Unverifiable(_, reason)) if reason == "Not implemented" => {}
+            result => return result,
+        }
+    }
+    upstream_ontologist::check_url_canonical(url).await
+}
+
+/// Check that a bug submission URL belongs to a live issue tracker, returning its canonical
+/// URL.
+///
+/// This verifies the bug database the URL belongs to, so trackers that have been disabled,
+/// such as GitHub repositories with issues turned off, are reported as invalid.
+pub async fn check_bug_submit_url_canonical(
+    url: &Url,
+    net_access: Option<bool>,
+) -> Result<Url, CanonicalizeError> {
+    let database = match bug_database_url_from_bug_submit_url(url) {
+        Some(database) => Some(database),
+        None => upstream_ontologist::bug_database_url_from_bug_submit_url(url, net_access).await,
+    };
+    let database = match database {
+        Some(database) => database,
+        None => {
+            if net_access == Some(false) {
+                return Err(CanonicalizeError::Unverifiable(
+                    url.clone(),
+                    "network access disabled".to_string(),
+                ));
+            }
+            return upstream_ontologist::check_url_canonical(url).await;
+        }
+    };
+    let database = check_bug_database_canonical(&database, net_access).await?;
+    let submit = match bug_submit_url_from_bug_database_url(&database, net_access).await {
+        Some(submit) => Some(submit),
+        None => {
+            upstream_ontologist::bug_submit_url_from_bug_database_url(&database, net_access).await
+        }
+    };
+    submit.ok_or_else(|| {
+        CanonicalizeError::Unverifiable(url.clone(), "no bug submit URL found".to_string())
+    })
+}
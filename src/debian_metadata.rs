@@ -0,0 +1,298 @@
+//! Reading and writing debian/upstream/metadata files (DEP-12).
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use serde_yaml::Value;
+use upstream_ontologist::{Person, UpstreamDatum, UpstreamMetadata};
+
+/// Convert a datum to the YAML representation used in debian/upstream/metadata.
+///
+/// People are written as "Name <email>" strings rather than upstream_ontologist's
+/// !Person tagged mappings, since other consumers of the file don't know that tag.
+pub(crate) fn datum_to_yaml(datum: &UpstreamDatum) -> Value {
+    match datum {
+        UpstreamDatum::Author(people) => Value::Sequence(
+            people
+                .iter()
+                .map(|p| Value::String(p.to_string()))
+                .collect(),
+        ),
+        UpstreamDatum::Maintainer(person) => Value::String(person.to_string()),
+        datum => serde_yaml::to_value(datum).unwrap(),
+    }
+}
+
+/// Render datums as YAML mapping entries.
+///
+/// Name and Contact come first, followed by the other fields sorted by name; this matches
+/// upstream_metadata_sort_key on the Python side.
+fn render_datums<'a>(
+    datums: impl Iterator<Item = &'a upstream_ontologist::UpstreamDatumWithMetadata>,
+) -> String {
+    let mut data = datums.collect::<Vec<_>>();
+    data.sort_by_key(|d| match d.datum.field() {
+        "Name" => (0, ""),
+        "Contact" => (1, ""),
+        field => (2, field),
+    });
+    let mut map = serde_yaml::Mapping::new();
+    for datum in data {
+        map.insert(
+            Value::String(datum.datum.field().to_string()),
+            datum_to_yaml(&datum.datum),
+        );
+    }
+    serde_yaml::to_string(&map).unwrap()
+}
+
+/// Render metadata as the contents of a debian/upstream/metadata file.
+pub(crate) fn to_yaml(metadata: &UpstreamMetadata) -> String {
+    format!("---\n{}", render_datums(metadata.iter()))
+}
+
+/// Find the lines that make up a top-level field in a YAML mapping.
+///
+/// A field continues for as long as lines are indented or list items. Blank lines and
+/// comments are only part of it if the field continues after them.
+fn field_lines(lines: &[String], field: &str) -> Option<std::ops::Range<usize>> {
+    let start = lines.iter().position(|line| {
+        line.strip_prefix(field)
+            .is_some_and(|rest| rest.starts_with(':'))
+    })?;
+    let mut end = start + 1;
+    for (i, line) in lines.iter().enumerate().skip(start + 1) {
+        if line.starts_with([' ', '\t']) || (line.starts_with('-') && !line.starts_with("---")) {
+            end = i + 1;
+        } else if !line.trim().is_empty() && !line.starts_with('#') {
+            break;
+        }
+    }
+    Some(start..end)
+}
+
+/// Find the comment at the end of a line, including the whitespace before it.
+///
+/// A '#' after whitespace starts a comment unless it is part of a quoted string, which is
+/// checked by parsing the line with and without it.
+fn trailing_comment(line: &str) -> &str {
+    let line = line.trim_end_matches('\n');
+    let Ok(value) = serde_yaml::from_str::<Value>(line) else {
+        return "";
+    };
+    let candidates = line
+        .char_indices()
+        .filter(|&(i, c)| c == '#' && line[..i].ends_with([' ', '\t']));
+    for (i, _) in candidates {
+        let content = line[..i].trim_end();
+        if serde_yaml::from_str::<Value>(content).is_ok_and(|v| v == value) {
+            return &line[content.len()..];
+        }
+    }
+    ""
+}
+
+/// Render a datum to replace the given lines of a file, keeping their comments.
+///
+/// A comment after the field name or its value stays there; other comments within the
+/// field are kept on their own lines before it.
+fn replace_lines(
+    lines: &[String],
+    datum: &upstream_ontologist::UpstreamDatumWithMetadata,
+) -> String {
+    let mut ret = String::new();
+    for line in &lines[1..] {
+        let comment = match line.trim_start() {
+            text if text.starts_with('#') => text.trim_end(),
+            _ => trailing_comment(line).trim(),
+        };
+        if !comment.is_empty() {
+            ret.push_str(comment);
+            ret.push('\n');
+        }
+    }
+    let rendered = render_datums(std::iter::once(datum));
+    let (first, rest) = rendered.split_once('\n').unwrap_or((&rendered, ""));
+    ret.push_str(first);
+    ret.push_str(trailing_comment(&lines[0]));
+    ret.push('\n');
+    ret.push_str(rest);
+    ret
+}
+
+/// Update a debian/upstream/metadata file from metadata, creating it if necessary.
+///
+/// Only datums at least as certain as minimum_certainty are considered. Values in the file
+/// are treated as certain, so they are only replaced by certain datums with a different
+/// value; missing fields are appended. The file is edited line by line, so comments and
+/// fields upstream_ontologist doesn't know about are kept.
+///
+/// Returns the names of the fields that were replaced, followed by those that were added.
+pub(crate) fn update_metadata_file(
+    path: &std::path::Path,
+    metadata: &UpstreamMetadata,
+    minimum_certainty: upstream_ontologist::Certainty,
+) -> PyResult<Vec<String>> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e.into()),
+    };
+    let existing = match serde_yaml::from_str::<Value>(&text) {
+        Ok(Value::Mapping(map)) => map,
+        Ok(Value::Null) => serde_yaml::Mapping::new(),
+        Ok(_) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: expected a mapping at the top level",
+                path.display()
+            )))
+        }
+        Err(e) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "{}: invalid YAML: {}",
+                path.display(),
+                e
+            )))
+        }
+    };
+    let mut lines = text
+        .split_inclusive('\n')
+        .map(String::from)
+        .collect::<Vec<_>>();
+    let mut changed = Vec::new();
+    let mut new = Vec::new();
+    for datum in metadata
+        .iter()
+        .filter(|d| d.certainty >= Some(minimum_certainty))
+    {
+        let field = datum.datum.field();
+        match existing.get(field) {
+            None => new.push(datum),
+            Some(value)
+                if datum.certainty == Some(upstream_ontologist::Certainty::Certain)
+                    && *value != datum_to_yaml(&datum.datum) =>
+            {
+                if let Some(range) = field_lines(&lines, field) {
+                    let text = replace_lines(&lines[range.clone()], datum);
+                    lines.splice(range, [text]);
+                    changed.push(field.to_string());
+                }
+            }
+            Some(_) => {}
+        }
+    }
+    if changed.is_empty() && new.is_empty() {
+        return Ok(Vec::new());
+    }
+    let mut text = lines.concat();
+    if text.trim().is_empty() {
+        text = "---\n".to_string();
+    } else if !text.ends_with('\n') {
+        text.push('\n');
+    }
+    text.push_str(&render_datums(new.iter().copied()));
+    std::fs::write(path, text)?;
+    changed.extend(new.iter().map(|d| d.datum.field().to_string()));
+    Ok(changed)
+}
+
+fn yaml_to_py(py: Python, value: &Value) -> PyResult<PyObject> {
+    Ok(match value {
+        Value::Null => py.None(),
+        Value::Bool(b) => b.into_py(py),
+        // Fields like Version are strings even when they look like numbers
+        Value::Number(n) => n.to_string().into_py(py),
+        Value::String(s) => s.into_py(py),
+        Value::Sequence(items) => PyList::new_bound(
+            py,
+            items
+                .iter()
+                .map(|item| yaml_to_py(py, item))
+                .collect::<PyResult<Vec<_>>>()?,
+        )
+        .into_py(py),
+        Value::Mapping(map) => {
+            let ret = pyo3::types::PyDict::new_bound(py);
+            for (k, v) in map {
+                ret.set_item(yaml_to_py(py, k)?, yaml_to_py(py, v)?)?;
+            }
+            ret.into_py(py)
+        }
+        Value::Tagged(tagged) if tagged.tag == "!Person" => {
+            serde_yaml::from_value::<Person>(tagged.value.clone())
+                .map_err(|e| {
+                    pyo3::exceptions::PyValueError::new_err(format!("Invalid person: {}", e))
+                })?
+                .to_object(py)
+        }
+        Value::Tagged(tagged) => yaml_to_py(py, &tagged.value)?,
+    })
+}
+
+/// Convert a field from a debian/upstream/metadata file to a datum.
+///
+/// Returns None for fields that upstream_ontologist doesn't know about.
+pub(crate) fn datum_from_yaml(
+    py: Python,
+    field: &str,
+    value: &Value,
+) -> PyResult<Option<UpstreamDatum>> {
+    let value = match (field, value) {
+        ("Debian-ITP", Value::Number(n)) => n.as_i64().into_py(py),
+        ("Registry", Value::Sequence(entries)) => entries
+            .iter()
+            .map(|entry| {
+                let name = entry.get("Name").and_then(|v| v.as_str());
+                let entry = entry.get("Entry").and_then(|v| v.as_str());
+                match (name, entry) {
+                    (Some(name), Some(entry)) => Ok((name, entry)),
+                    _ => Err(pyo3::exceptions::PyValueError::new_err(
+                        "Registry entries need a Name and an Entry",
+                    )),
+                }
+            })
+            .collect::<PyResult<Vec<_>>>()?
+            .into_py(py),
+        (_, value) => yaml_to_py(py, value)?,
+    };
+    // Values come from a file rather than from Python code, so report them as invalid
+    crate::datum_from_py(py, field, value).map_err(|e| {
+        pyo3::exceptions::PyValueError::new_err(format!("Invalid value for {}: {}", field, e))
+    })
+}
+
+/// Parse the contents of a debian/upstream/metadata file.
+///
+/// Unknown fields are skipped, since DEP-12 allows more fields than upstream_ontologist
+/// supports.
+pub(crate) fn from_yaml(
+    py: Python,
+    text: &str,
+    certainty: Option<upstream_ontologist::Certainty>,
+    origin: Option<upstream_ontologist::Origin>,
+) -> PyResult<UpstreamMetadata> {
+    let data: Value = serde_yaml::from_str(text)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("Invalid YAML: {}", e)))?;
+    let map = match data {
+        Value::Mapping(map) => map,
+        Value::Null => serde_yaml::Mapping::new(),
+        _ => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "expected a mapping at the top level",
+            ))
+        }
+    };
+    let mut ret = UpstreamMetadata::new();
+    for (field, value) in map.iter() {
+        let Some(field) = field.as_str() else {
+            continue;
+        };
+        match datum_from_yaml(py, field, value)? {
+            Some(datum) => ret.insert(upstream_ontologist::UpstreamDatumWithMetadata {
+                datum,
+                certainty,
+                origin: origin.clone(),
+            }),
+            None => log::debug!("Skipping unknown field {}", field),
+        }
+    }
+    Ok(ret)
+}
@@ -0,0 +1,146 @@
+//! Extrapolation rules registered from Python.
+//!
+//! These run alongside the rules built into upstream_ontologist, following the same
+//! conventions: a rule only runs if all of its input fields are known, and at least one of
+//! its output fields is missing or less certain than the inputs.
+
+use crate::{UpstreamDatum, UpstreamMetadata};
+use pyo3::prelude::*;
+use std::sync::Mutex;
+use upstream_ontologist::ProviderError;
+
+/// The default number of rounds of extrapolation, matching upstream_ontologist.
+const DEFAULT_ITERATION_LIMIT: usize = 10;
+
+struct Rule {
+    from_fields: Vec<String>,
+    to_fields: Vec<String>,
+    callback: PyObject,
+}
+
+static RULES: Mutex<Vec<Rule>> = Mutex::new(Vec::new());
+
+/// Register a rule that derives `to_fields` from `from_fields`.
+///
+/// The callback is called with the current UpstreamMetadata and returns a list of
+/// UpstreamDatum; datums without a certainty get the lowest certainty of the inputs.
+#[pyfunction]
+pub fn register_extrapolation(
+    from_fields: Vec<String>,
+    to_fields: Vec<String>,
+    callback: PyObject,
+) -> PyResult<()> {
+    if to_fields.is_empty() {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "extrapolation rule must produce at least one field",
+        ));
+    }
+    RULES.lock().unwrap().push(Rule {
+        from_fields,
+        to_fields,
+        callback,
+    });
+    Ok(())
+}
+
+/// Remove the rules registered with `callback`.
+///
+/// Callbacks are compared by identity; unknown callbacks are ignored.
+#[pyfunction]
+pub fn unregister_extrapolation(callback: PyObject) {
+    RULES
+        .lock()
+        .unwrap()
+        .retain(|rule| !rule.callback.is(&callback));
+}
+
+/// Apply the registered rules once, returning whether any of them changed the metadata.
+fn apply_registered_rules(metadata: &mut upstream_ontologist::UpstreamMetadata) -> PyResult<bool> {
+    Python::with_gil(|py| {
+        // Don't hold the lock while calling back into Python, which may register more rules
+        let rules = RULES
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|rule| Rule {
+                from_fields: rule.from_fields.clone(),
+                to_fields: rule.to_fields.clone(),
+                callback: rule.callback.clone_ref(py),
+            })
+            .collect::<Vec<_>>();
+
+        let mut changed = false;
+        for rule in rules {
+            let from_certainties = rule
+                .from_fields
+                .iter()
+                .map(|f| metadata.get(f).map(|d| d.certainty))
+                .collect::<Option<Vec<_>>>();
+            let from_certainty = match from_certainties {
+                Some(certainties) => certainties.into_iter().min().flatten(),
+                None => continue,
+            };
+            if rule.to_fields.iter().all(|f| {
+                metadata
+                    .get(f)
+                    .is_some_and(|d| d.certainty >= from_certainty)
+            }) {
+                continue;
+            }
+
+            let result = rule
+                .callback
+                .call1(py, (UpstreamMetadata(metadata.clone()),))?;
+            let datums = result.extract::<Vec<UpstreamDatum>>(py)?;
+            let items = datums
+                .into_iter()
+                .map(|datum| datum.0)
+                .filter(|item| {
+                    let declared = rule.to_fields.iter().any(|f| f == item.datum.field());
+                    if !declared {
+                        log::warn!(
+                            "Ignoring {} from extrapolation rule, which only declares {:?}",
+                            item.datum.field(),
+                            rule.to_fields
+                        );
+                    }
+                    declared
+                })
+                .map(|mut item| {
+                    if item.certainty.is_none() {
+                        item.certainty = from_certainty;
+                    }
+                    item
+                })
+                .collect::<Vec<_>>();
+            if !metadata.update(items.into_iter()).is_empty() {
+                changed = true;
+            }
+        }
+        Ok(changed)
+    })
+}
+
+/// Derive fields from other fields, using both the rules built into upstream_ontologist
+/// and those registered from Python, until nothing changes.
+pub async fn extrapolate_fields(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    net_access: bool,
+    iteration_limit: Option<usize>,
+) -> Result<(), ProviderError> {
+    let iteration_limit = iteration_limit.unwrap_or(DEFAULT_ITERATION_LIMIT);
+    for _ in 0..iteration_limit {
+        upstream_ontologist::extrapolate::extrapolate_fields(
+            metadata,
+            net_access,
+            Some(iteration_limit),
+        )
+        .await?;
+        let changed = apply_registered_rules(metadata)
+            .map_err(|e| ProviderError::Other(format!("Extrapolation rule failed: {}", e)))?;
+        if !changed {
+            return Ok(());
+        }
+    }
+    Err(ProviderError::ExtrapolationLimitExceeded(iteration_limit))
+}
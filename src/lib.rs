@@ -1,13 +1,50 @@
+// pyo3 0.22's #[pyfunction] and #[pymethods] wrappers convert PyErr into PyErr for every
+// function returning PyResult. The wrappers are generated as separate items, so an allow
+// on the function itself doesn't reach them.
+#![allow(clippy::useless_conversion)]
+
 use futures::StreamExt;
-use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyStopIteration, PyValueError};
-use pyo3::import_exception;
+use pyo3::exceptions::{PyKeyError, PyRuntimeError, PyStopIteration, PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyTuple, PyType};
 use std::str::FromStr;
-use upstream_ontologist::{Certainty, Origin};
+use upstream_ontologist::Certainty;
 use url::Url;
 
-import_exception!(urllib.error, HTTPError);
+mod bad_guesses;
+mod bugs;
+mod debian_metadata;
+mod extrapolate;
+mod merge_requests;
+mod provenance;
+mod providers;
+
+// pyo3 0.22's exception macros check for its gil-refs feature, which this crate doesn't have
+#[allow(unexpected_cfgs)]
+mod exceptions {
+    use pyo3::import_exception;
+
+    import_exception!(urllib.error, HTTPError);
+    import_exception!(upstream_ontologist, UrlUnverifiable);
+    import_exception!(upstream_ontologist, InvalidUrl);
+}
+use exceptions::{InvalidUrl, UrlUnverifiable};
+
+fn canonicalize_error_to_py(e: upstream_ontologist::CanonicalizeError) -> PyErr {
+    use upstream_ontologist::CanonicalizeError;
+    match e {
+        CanonicalizeError::InvalidUrl(url, reason) => {
+            InvalidUrl::new_err((url.to_string(), reason))
+        }
+        CanonicalizeError::Unverifiable(url, reason) => {
+            UrlUnverifiable::new_err((url.to_string(), reason))
+        }
+        CanonicalizeError::RateLimited(url) => {
+            UrlUnverifiable::new_err((url.to_string(), "rate limited".to_string()))
+        }
+    }
+}
 
 #[pyfunction]
 fn drop_vcs_in_scheme(url: &str) -> String {
@@ -20,6 +57,9 @@ fn drop_vcs_in_scheme(url: &str) -> String {
 fn canonical_git_repo_url(url: &str, net_access: Option<bool>) -> PyResult<String> {
     let url =
         Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    if let Some(canonical) = providers::bitbucket::canonical_repo_url(url.as_str()) {
+        return Ok(canonical);
+    }
     let rt = tokio::runtime::Runtime::new().unwrap();
     Ok(rt
         .block_on(upstream_ontologist::vcs::canonical_git_repo_url(
@@ -37,10 +77,90 @@ fn find_public_repo_url(url: &str, net_access: Option<bool>) -> PyResult<Option<
     )))
 }
 
+#[pyfunction]
+#[pyo3(signature = (url, net_access=None))]
+fn bug_database_url_from_bug_submit_url(
+    url: &str,
+    net_access: Option<bool>,
+) -> PyResult<Option<String>> {
+    let url =
+        Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    if let Some(database) = bugs::bug_database_url_from_bug_submit_url(&url) {
+        return Ok(Some(database.to_string()));
+    }
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    Ok(rt
+        .block_on(upstream_ontologist::bug_database_url_from_bug_submit_url(
+            &url, net_access,
+        ))
+        .map(|u| u.to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (url, net_access=None))]
+fn bug_submit_url_from_bug_database_url(
+    url: &str,
+    net_access: Option<bool>,
+) -> PyResult<Option<String>> {
+    let url =
+        Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    if let Some(submit) = rt.block_on(bugs::bug_submit_url_from_bug_database_url(&url, net_access))
+    {
+        return Ok(Some(submit.to_string()));
+    }
+    Ok(rt
+        .block_on(upstream_ontologist::bug_submit_url_from_bug_database_url(
+            &url, net_access,
+        ))
+        .map(|u| u.to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (url, net_access=None))]
+fn check_bug_database_canonical(url: &str, net_access: Option<bool>) -> PyResult<String> {
+    let url =
+        Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(bugs::check_bug_database_canonical(&url, net_access))
+        .map(|u| u.to_string())
+        .map_err(canonicalize_error_to_py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (url, net_access=None))]
+fn check_bug_submit_url_canonical(url: &str, net_access: Option<bool>) -> PyResult<String> {
+    let url =
+        Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(bugs::check_bug_submit_url_canonical(&url, net_access))
+        .map(|u| u.to_string())
+        .map_err(canonicalize_error_to_py)
+}
+
+#[pyfunction]
+#[pyo3(signature = (url, net_access=None))]
+fn repo_url_from_merge_request_url(
+    url: &str,
+    net_access: Option<bool>,
+) -> PyResult<Option<String>> {
+    let url =
+        Url::parse(url).map_err(|e| PyRuntimeError::new_err(format!("Invalid URL: {}", e)))?;
+    if let Some(repo_url) = merge_requests::repo_url_from_merge_request_url(&url) {
+        return Ok(Some(repo_url.to_string()));
+    }
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    Ok(rt
+        .block_on(upstream_ontologist::repo_url_from_merge_request_url(
+            &url, net_access,
+        ))
+        .map(|u| u.to_string()))
+}
+
 #[pyfunction]
 fn known_bad_guess(py: Python, datum: PyObject) -> PyResult<bool> {
     let datum: upstream_ontologist::UpstreamDatum = datum.extract(py)?;
-    Ok(datum.known_bad_guess())
+    Ok(bad_guesses::known_bad_guess(&datum))
 }
 
 #[pyfunction]
@@ -99,8 +219,259 @@ fn extract_str_value(py: Python, value: PyObject) -> PyResult<String> {
     value.extract::<String>(py)
 }
 
-#[derive(Clone)]
+/// Extract a person from either a Person object or a "Name <email>" string.
+fn extract_person(py: Python, value: &PyObject) -> PyResult<upstream_ontologist::Person> {
+    if let Ok(text) = value.extract::<String>(py) {
+        return Ok(upstream_ontologist::Person::from(text.as_str()));
+    }
+    value.extract(py).map_err(|_| {
+        PyTypeError::new_err(format!(
+            "expected a Person or string, got {}",
+            value.bind(py).get_type()
+        ))
+    })
+}
+
+/// Extract a list of people, also accepting a single person.
+fn extract_people(py: Python, value: PyObject) -> PyResult<Vec<upstream_ontologist::Person>> {
+    if let Ok(person) = extract_person(py, &value) {
+        return Ok(vec![person]);
+    }
+    value
+        .extract::<Vec<PyObject>>(py)?
+        .iter()
+        .map(|item| extract_person(py, item))
+        .collect()
+}
+
+/// Extract a list of strings, also accepting a single string.
+fn extract_strings(py: Python, value: PyObject) -> PyResult<Vec<String>> {
+    if let Ok(text) = value.extract::<String>(py) {
+        return Ok(vec![text]);
+    }
+    value.extract(py)
+}
+
+/// Where a datum was found: a local file, a remote URL or something else.
+///
+/// For compatibility with code that treated origins as strings, origins compare equal to
+/// (and hash like) their string form. Strings and paths are also accepted wherever an
+/// Origin is.
+#[pyclass(module = "upstream_ontologist._upstream_ontologist")]
+struct Origin(upstream_ontologist::Origin);
+
+impl FromPyObject<'_> for Origin {
+    fn extract_bound(ob: &Bound<PyAny>) -> PyResult<Self> {
+        if let Ok(origin) = ob.downcast::<Origin>() {
+            return Ok(Origin(origin.borrow().0.clone()));
+        }
+        Ok(Origin(ob.extract()?))
+    }
+}
+
+/// Both a constructor and an accessor for one kind of origin.
+///
+/// On the class, `Origin.path("setup.py")` creates an origin; on an instance, `origin.path`
+/// returns the path, or None if the origin isn't a path.
 #[pyclass]
+struct OriginKind(&'static str);
+
+#[pymethods]
+impl OriginKind {
+    fn __get__(
+        slf: Py<Self>,
+        py: Python,
+        instance: Option<&Bound<PyAny>>,
+        _owner: Option<&Bound<PyAny>>,
+    ) -> PyResult<PyObject> {
+        let origin = match instance.map(|i| i.downcast::<Origin>()) {
+            Some(Ok(origin)) => origin.borrow(),
+            _ => return Ok(slf.into_py(py)),
+        };
+        Ok(match (slf.borrow(py).0, &origin.0) {
+            ("path", upstream_ontologist::Origin::Path(path)) => path.clone().into_py(py),
+            ("url", upstream_ontologist::Origin::Url(url)) => url.to_string().into_py(py),
+            ("other", upstream_ontologist::Origin::Other(s)) => s.clone().into_py(py),
+            _ => py.None(),
+        })
+    }
+
+    fn __call__(&self, value: &Bound<PyAny>) -> PyResult<Origin> {
+        Ok(Origin(match self.0 {
+            "path" => upstream_ontologist::Origin::Path(value.extract()?),
+            "url" => upstream_ontologist::Origin::Url(
+                Url::parse(&value.extract::<String>()?)
+                    .map_err(|e| PyValueError::new_err(format!("Invalid URL: {}", e)))?,
+            ),
+            _ => upstream_ontologist::Origin::Other(value.extract()?),
+        }))
+    }
+}
+
+#[pymethods]
+impl Origin {
+    #[classattr]
+    fn path() -> OriginKind {
+        OriginKind("path")
+    }
+
+    #[classattr]
+    fn url() -> OriginKind {
+        OriginKind("url")
+    }
+
+    #[classattr]
+    fn other() -> OriginKind {
+        OriginKind("other")
+    }
+
+    #[new]
+    fn new(kind: &str, value: &Bound<PyAny>) -> PyResult<Self> {
+        match kind {
+            "path" => OriginKind("path").__call__(value),
+            "url" => OriginKind("url").__call__(value),
+            "other" => OriginKind("other").__call__(value),
+            _ => Err(PyValueError::new_err(format!(
+                "Unknown origin kind: {}",
+                kind
+            ))),
+        }
+    }
+
+    fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (&'static str, PyObject))> {
+        let py = slf.py();
+        let origin = slf.borrow();
+        let value = match &origin.0 {
+            upstream_ontologist::Origin::Path(path) => path.clone().into_py(py),
+            upstream_ontologist::Origin::Url(url) => url.to_string().into_py(py),
+            upstream_ontologist::Origin::Other(s) => s.clone().into_py(py),
+        };
+        Ok((slf.get_type().into_py(py), (origin.kind(), value)))
+    }
+
+    /// One of "path", "url" or "other".
+    #[getter]
+    fn kind(&self) -> &'static str {
+        match self.0 {
+            upstream_ontologist::Origin::Path(_) => "path",
+            upstream_ontologist::Origin::Url(_) => "url",
+            upstream_ontologist::Origin::Other(_) => "other",
+        }
+    }
+
+    fn __eq__(&self, other: &Bound<PyAny>) -> bool {
+        if let Ok(other) = other.downcast::<Origin>() {
+            return self.0 == other.borrow().0;
+        }
+        match other.extract::<String>() {
+            Ok(other) => self.0.to_string() == other,
+            Err(_) => false,
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<isize> {
+        pyo3::types::PyString::new_bound(py, &self.0.to_string()).hash()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Origin.{}({:?})", self.kind(), self.0.to_string())
+    }
+}
+
+fn parse_certainty(certainty: &str) -> PyResult<Certainty> {
+    Certainty::from_str(certainty)
+        .map_err(|e| PyValueError::new_err(format!("Invalid certainty: {}", e)))
+}
+
+/// Convert a certainty to a member of the Python Certainty enum.
+fn certainty_to_py(py: Python, certainty: Certainty) -> PyResult<PyObject> {
+    static CERTAINTY: GILOnceCell<PyObject> = GILOnceCell::new();
+    let cls = CERTAINTY.get_or_try_init(py, || {
+        PyModule::import_bound(py, "upstream_ontologist")?
+            .getattr("Certainty")
+            .map(Bound::unbind)
+    })?;
+    cls.call1(py, (certainty.to_string(),))
+}
+
+/// Convert a Python value to a datum for the given field, or None if the field is unknown.
+pub(crate) fn datum_from_py(
+    py: Python,
+    field: &str,
+    value: PyObject,
+) -> PyResult<Option<upstream_ontologist::UpstreamDatum>> {
+    Ok(Some(match field {
+        "Name" => upstream_ontologist::UpstreamDatum::Name(extract_str_value(py, value)?),
+        "Version" => upstream_ontologist::UpstreamDatum::Version(extract_str_value(py, value)?),
+        "Summary" => upstream_ontologist::UpstreamDatum::Summary(extract_str_value(py, value)?),
+        "Description" => {
+            upstream_ontologist::UpstreamDatum::Description(extract_str_value(py, value)?)
+        }
+        "Homepage" => upstream_ontologist::UpstreamDatum::Homepage(extract_str_value(py, value)?),
+        "Repository" => {
+            // Check if the value is a list rather than a string
+            if let Ok(value) = value.extract::<Vec<String>>(py) {
+                upstream_ontologist::UpstreamDatum::Repository(value.join(" "))
+            } else {
+                upstream_ontologist::UpstreamDatum::Repository(extract_str_value(py, value)?)
+            }
+        }
+        "Repository-Browse" => {
+            upstream_ontologist::UpstreamDatum::RepositoryBrowse(extract_str_value(py, value)?)
+        }
+        "License" => upstream_ontologist::UpstreamDatum::License(extract_str_value(py, value)?),
+        "Author" => upstream_ontologist::UpstreamDatum::Author(extract_people(py, value)?),
+        "Bug-Database" => {
+            upstream_ontologist::UpstreamDatum::BugDatabase(extract_str_value(py, value)?)
+        }
+        "Bug-Submit" => {
+            upstream_ontologist::UpstreamDatum::BugSubmit(extract_str_value(py, value)?)
+        }
+        "Contact" => upstream_ontologist::UpstreamDatum::Contact(extract_str_value(py, value)?),
+        "Cargo-Crate" => {
+            upstream_ontologist::UpstreamDatum::CargoCrate(extract_str_value(py, value)?)
+        }
+        "Security-MD" => {
+            upstream_ontologist::UpstreamDatum::SecurityMD(extract_str_value(py, value)?)
+        }
+        "Security-Contact" => {
+            upstream_ontologist::UpstreamDatum::SecurityContact(extract_str_value(py, value)?)
+        }
+        "Keywords" => upstream_ontologist::UpstreamDatum::Keywords(extract_strings(py, value)?),
+        "Maintainer" => upstream_ontologist::UpstreamDatum::Maintainer(extract_person(py, &value)?),
+        "Copyright" => upstream_ontologist::UpstreamDatum::Copyright(value.extract(py)?),
+        "Documentation" => upstream_ontologist::UpstreamDatum::Documentation(value.extract(py)?),
+        "Go-Import-Path" => upstream_ontologist::UpstreamDatum::GoImportPath(value.extract(py)?),
+        "Download" => upstream_ontologist::UpstreamDatum::Download(value.extract(py)?),
+        "Wiki" => upstream_ontologist::UpstreamDatum::Wiki(value.extract(py)?),
+        "MailingList" => upstream_ontologist::UpstreamDatum::MailingList(value.extract(py)?),
+        "SourceForge-Project" => {
+            upstream_ontologist::UpstreamDatum::SourceForgeProject(value.extract(py)?)
+        }
+        "Archive" => upstream_ontologist::UpstreamDatum::Archive(value.extract(py)?),
+        "Demo" => upstream_ontologist::UpstreamDatum::Demo(value.extract(py)?),
+        "Pecl-Package" => upstream_ontologist::UpstreamDatum::PeclPackage(value.extract(py)?),
+        "Haskell-Package" => upstream_ontologist::UpstreamDatum::HaskellPackage(value.extract(py)?),
+        "Funding" => upstream_ontologist::UpstreamDatum::Funding(value.extract(py)?),
+        "Changelog" => upstream_ontologist::UpstreamDatum::Changelog(value.extract(py)?),
+        "Debian-ITP" => upstream_ontologist::UpstreamDatum::DebianITP(value.extract(py)?),
+        "Screenshots" => {
+            upstream_ontologist::UpstreamDatum::Screenshots(extract_strings(py, value)?)
+        }
+        "Cite-As" => upstream_ontologist::UpstreamDatum::CiteAs(value.extract(py)?),
+        "Registry" => upstream_ontologist::UpstreamDatum::Registry(value.extract(py)?),
+        "Donation" => upstream_ontologist::UpstreamDatum::Donation(value.extract(py)?),
+        "Webservice" => upstream_ontologist::UpstreamDatum::Webservice(value.extract(py)?),
+        _ => return Ok(None),
+    }))
+}
+
+#[derive(Clone)]
+#[pyclass(module = "upstream_ontologist._upstream_ontologist")]
 struct UpstreamDatum(pub(crate) upstream_ontologist::UpstreamDatumWithMetadata);
 
 #[pymethods]
@@ -114,128 +485,13 @@ impl UpstreamDatum {
         certainty: Option<String>,
         origin: Option<Origin>,
     ) -> PyResult<Self> {
+        let origin = origin.map(|o| o.0);
         Ok(UpstreamDatum(
             upstream_ontologist::UpstreamDatumWithMetadata {
-                datum: match field.as_str() {
-                    "Name" => {
-                        upstream_ontologist::UpstreamDatum::Name(extract_str_value(py, value)?)
-                    }
-                    "Version" => {
-                        upstream_ontologist::UpstreamDatum::Version(extract_str_value(py, value)?)
-                    }
-                    "Summary" => {
-                        upstream_ontologist::UpstreamDatum::Summary(extract_str_value(py, value)?)
-                    }
-                    "Description" => upstream_ontologist::UpstreamDatum::Description(
-                        extract_str_value(py, value)?,
-                    ),
-                    "Homepage" => {
-                        upstream_ontologist::UpstreamDatum::Homepage(extract_str_value(py, value)?)
-                    }
-                    "Repository" => {
-                        // Check if the value is a list rather than a string
-                        if let Ok(value) = value.extract::<Vec<String>>(py) {
-                            upstream_ontologist::UpstreamDatum::Repository(value.join(" "))
-                        } else {
-                            upstream_ontologist::UpstreamDatum::Repository(extract_str_value(
-                                py, value,
-                            )?)
-                        }
-                    }
-                    "Repository-Browse" => upstream_ontologist::UpstreamDatum::RepositoryBrowse(
-                        extract_str_value(py, value)?,
-                    ),
-                    "License" => {
-                        upstream_ontologist::UpstreamDatum::License(extract_str_value(py, value)?)
-                    }
-                    "Author" => {
-                        upstream_ontologist::UpstreamDatum::Author(value.extract(py).unwrap())
-                    }
-                    "Bug-Database" => upstream_ontologist::UpstreamDatum::BugDatabase(
-                        extract_str_value(py, value)?,
-                    ),
-                    "Bug-Submit" => {
-                        upstream_ontologist::UpstreamDatum::BugSubmit(extract_str_value(py, value)?)
-                    }
-                    "Contact" => {
-                        upstream_ontologist::UpstreamDatum::Contact(extract_str_value(py, value)?)
-                    }
-                    "Cargo-Crate" => upstream_ontologist::UpstreamDatum::CargoCrate(
-                        extract_str_value(py, value)?,
-                    ),
-                    "Security-MD" => upstream_ontologist::UpstreamDatum::SecurityMD(
-                        extract_str_value(py, value)?,
-                    ),
-                    "Security-Contact" => upstream_ontologist::UpstreamDatum::SecurityContact(
-                        extract_str_value(py, value)?,
-                    ),
-                    "Keywords" => {
-                        upstream_ontologist::UpstreamDatum::Keywords(value.extract(py).unwrap())
-                    }
-                    "Maintainer" => {
-                        upstream_ontologist::UpstreamDatum::Maintainer(value.extract(py).unwrap())
-                    }
-                    "Copyright" => {
-                        upstream_ontologist::UpstreamDatum::Copyright(value.extract(py).unwrap())
-                    }
-                    "Documentation" => upstream_ontologist::UpstreamDatum::Documentation(
-                        value.extract(py).unwrap(),
-                    ),
-                    "Go-Import-Path" => {
-                        upstream_ontologist::UpstreamDatum::GoImportPath(value.extract(py).unwrap())
-                    }
-                    "Download" => {
-                        upstream_ontologist::UpstreamDatum::Download(value.extract(py).unwrap())
-                    }
-                    "Wiki" => upstream_ontologist::UpstreamDatum::Wiki(value.extract(py).unwrap()),
-                    "MailingList" => {
-                        upstream_ontologist::UpstreamDatum::MailingList(value.extract(py).unwrap())
-                    }
-                    "SourceForge-Project" => {
-                        upstream_ontologist::UpstreamDatum::SourceForgeProject(
-                            value.extract(py).unwrap(),
-                        )
-                    }
-                    "Archive" => {
-                        upstream_ontologist::UpstreamDatum::Archive(value.extract(py).unwrap())
-                    }
-                    "Demo" => upstream_ontologist::UpstreamDatum::Demo(value.extract(py).unwrap()),
-                    "Pecl-Package" => {
-                        upstream_ontologist::UpstreamDatum::PeclPackage(value.extract(py).unwrap())
-                    }
-                    "Haskell-Package" => upstream_ontologist::UpstreamDatum::HaskellPackage(
-                        value.extract(py).unwrap(),
-                    ),
-                    "Funding" => {
-                        upstream_ontologist::UpstreamDatum::Funding(value.extract(py).unwrap())
-                    }
-                    "Changelog" => {
-                        upstream_ontologist::UpstreamDatum::Changelog(value.extract(py).unwrap())
-                    }
-                    "Debian-ITP" => {
-                        upstream_ontologist::UpstreamDatum::DebianITP(value.extract(py).unwrap())
-                    }
-                    "Screenshots" => {
-                        upstream_ontologist::UpstreamDatum::Screenshots(value.extract(py).unwrap())
-                    }
-                    "Cite-As" => {
-                        upstream_ontologist::UpstreamDatum::CiteAs(value.extract(py).unwrap())
-                    }
-                    "Registry" => {
-                        upstream_ontologist::UpstreamDatum::Registry(value.extract(py).unwrap())
-                    }
-                    "Donation" => {
-                        upstream_ontologist::UpstreamDatum::Donation(value.extract(py).unwrap())
-                    }
-                    "Webservice" => {
-                        upstream_ontologist::UpstreamDatum::Webservice(value.extract(py).unwrap())
-                    }
-                    _ => {
-                        return Err(PyValueError::new_err(format!("Unknown field: {}", field)));
-                    }
-                },
+                datum: datum_from_py(py, &field, value)?
+                    .ok_or_else(|| PyValueError::new_err(format!("Unknown field: {}", field)))?,
                 origin,
-                certainty: certainty.map(|s| Certainty::from_str(&s).unwrap()),
+                certainty: certainty.map(|s| parse_certainty(&s)).transpose()?,
             },
         ))
     }
@@ -260,22 +516,35 @@ impl UpstreamDatum {
 
     #[getter]
     fn origin(&self) -> Option<Origin> {
-        self.0.origin.clone()
+        self.0.origin.clone().map(Origin)
     }
 
     #[setter]
     fn set_origin(&mut self, origin: Option<Origin>) {
-        self.0.origin = origin;
+        self.0.origin = origin.map(|o| o.0);
     }
 
+    /// The chain of origins this datum was derived from, oldest first.
+    ///
+    /// This ends with the datum's own origin. Datums found by looking up earlier findings
+    /// in a registry are preceded by the origins of those findings.
     #[getter]
-    fn certainty(&self) -> Option<String> {
-        self.0.certainty.map(|c| c.to_string())
+    fn origins(&self) -> Vec<Origin> {
+        provenance::origins(&self.0)
+            .into_iter()
+            .map(Origin)
+            .collect()
+    }
+
+    #[getter]
+    fn certainty(&self, py: Python) -> PyResult<Option<PyObject>> {
+        self.0.certainty.map(|c| certainty_to_py(py, c)).transpose()
     }
 
     #[setter]
-    pub fn set_certainty(&mut self, certainty: Option<String>) {
-        self.0.certainty = certainty.map(|s| Certainty::from_str(&s).unwrap());
+    pub fn set_certainty(&mut self, certainty: Option<String>) -> PyResult<()> {
+        self.0.certainty = certainty.map(|s| parse_certainty(&s)).transpose()?;
+        Ok(())
     }
 
     fn __eq__(lhs: &Bound<Self>, rhs: &Bound<Self>) -> PyResult<bool> {
@@ -286,6 +555,50 @@ impl UpstreamDatum {
         Ok(lhs.borrow().0 != rhs.borrow().0)
     }
 
+    /// Hash the field and value.
+    ///
+    /// Certainty and origin are left out, since they are commonly updated. Changing the
+    /// value of a datum that is in a set or used as a dictionary key is not supported.
+    fn __hash__(&self) -> u64 {
+        use std::hash::{Hash, Hasher};
+        // UpstreamDatum doesn't implement Hash; its string form is equal whenever the
+        // datums are
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        self.0.datum.field().hash(&mut hasher);
+        self.0.datum.to_string().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn __reduce__(
+        slf: &Bound<Self>,
+    ) -> PyResult<(PyObject, (String, PyObject, Option<String>, Option<Origin>))> {
+        let py = slf.py();
+        let datum = slf.borrow();
+        Ok((
+            slf.get_type().into_py(py),
+            (
+                datum.0.datum.field().to_string(),
+                datum.value(py)?,
+                datum.0.certainty.map(|c| c.to_string()),
+                datum.0.origin.clone().map(Origin),
+            ),
+        ))
+    }
+
+    // Values are converted to Python objects on access, so copies never share state
+    pub fn copy(&self) -> Self {
+        self.clone()
+    }
+
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<PyAny>) -> Self {
+        self.clone()
+    }
+
     fn __str__(&self) -> PyResult<String> {
         Ok(format!("{}: {}", self.0.datum.field(), self.0.datum))
     }
@@ -309,7 +622,54 @@ impl UpstreamDatum {
     }
 }
 
-#[pyclass]
+/// Differences between two UpstreamMetadata objects, as returned by UpstreamMetadata.diff.
+#[pyclass(get_all)]
+struct MetadataDiff {
+    /// Datums only present in the other metadata
+    added: Vec<UpstreamDatum>,
+    /// Datums only present in the original metadata
+    removed: Vec<UpstreamDatum>,
+    /// (old, new) pairs for fields whose value changed
+    changed: Vec<(UpstreamDatum, UpstreamDatum)>,
+    /// (old, new) pairs for fields with the same value but a different certainty
+    certainty_changed: Vec<(UpstreamDatum, UpstreamDatum)>,
+}
+
+#[pymethods]
+impl MetadataDiff {
+    fn __bool__(&self) -> bool {
+        !(self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.certainty_changed.is_empty())
+    }
+
+    fn __repr__(&self) -> String {
+        let fields = |datums: &[UpstreamDatum]| {
+            datums
+                .iter()
+                .map(|d| d.0.datum.field())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let pair_fields = |pairs: &[(UpstreamDatum, UpstreamDatum)]| {
+            pairs
+                .iter()
+                .map(|(_, new)| new.0.datum.field())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        format!(
+            "MetadataDiff(added=[{}], removed=[{}], changed=[{}], certainty_changed=[{}])",
+            fields(&self.added),
+            fields(&self.removed),
+            pair_fields(&self.changed),
+            pair_fields(&self.certainty_changed)
+        )
+    }
+}
+
+#[pyclass(module = "upstream_ontologist._upstream_ontologist")]
 struct UpstreamMetadata(pub(crate) upstream_ontologist::UpstreamMetadata);
 
 #[allow(non_snake_case)]
@@ -331,6 +691,114 @@ impl UpstreamMetadata {
         self.0.contains_key(field)
     }
 
+    fn __len__(&self) -> usize {
+        self.0.len()
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.0.is_empty()
+    }
+
+    /// Return the metadata as a dictionary mapping fields to plain values.
+    ///
+    /// With include_certainty, each field maps to a dictionary with "value" and
+    /// "certainty" keys instead.
+    #[pyo3(signature = (include_certainty=false))]
+    pub fn to_dict(&self, py: Python, include_certainty: bool) -> PyResult<PyObject> {
+        let ret = PyDict::new_bound(py);
+        for datum in self.0.iter() {
+            let value = UpstreamDatum(datum.clone()).value(py)?;
+            if include_certainty {
+                let entry = PyDict::new_bound(py);
+                entry.set_item("value", value)?;
+                entry.set_item(
+                    "certainty",
+                    datum
+                        .certainty
+                        .map(|c| certainty_to_py(py, c))
+                        .transpose()?,
+                )?;
+                ret.set_item(datum.datum.field(), entry)?;
+            } else {
+                ret.set_item(datum.datum.field(), value)?;
+            }
+        }
+        Ok(ret.into())
+    }
+
+    // Field order doesn't matter for equality, unlike for the underlying Vec
+    fn __eq__(&self, other: &Bound<PyAny>) -> bool {
+        let Ok(other) = other.downcast::<UpstreamMetadata>() else {
+            return false;
+        };
+        let other = &other.borrow().0;
+        self.0.len() == other.len()
+            && self
+                .0
+                .iter()
+                .all(|datum| other.get(datum.datum.field()) == Some(datum))
+    }
+
+    /// Compare against another UpstreamMetadata, treating self as the old state.
+    ///
+    /// Origins are ignored; only values and certainties are compared.
+    pub fn diff(&self, other: &UpstreamMetadata) -> MetadataDiff {
+        let mut ret = MetadataDiff {
+            added: Vec::new(),
+            removed: Vec::new(),
+            changed: Vec::new(),
+            certainty_changed: Vec::new(),
+        };
+        for old in self.0.iter() {
+            match other.0.get(old.datum.field()) {
+                None => ret.removed.push(UpstreamDatum(old.clone())),
+                Some(new) if new.datum != old.datum => ret
+                    .changed
+                    .push((UpstreamDatum(old.clone()), UpstreamDatum(new.clone()))),
+                Some(new) if new.certainty != old.certainty => ret
+                    .certainty_changed
+                    .push((UpstreamDatum(old.clone()), UpstreamDatum(new.clone()))),
+                Some(_) => {}
+            }
+        }
+        for new in other.0.iter() {
+            if !self.0.contains_key(new.datum.field()) {
+                ret.added.push(UpstreamDatum(new.clone()));
+            }
+        }
+        ret
+    }
+
+    fn __getstate__(&self) -> Vec<UpstreamDatum> {
+        self.values()
+    }
+
+    fn __setstate__(&mut self, state: Vec<UpstreamDatum>) {
+        self.0 = upstream_ontologist::UpstreamMetadata::from_data(
+            state.into_iter().map(|d| d.0).collect(),
+        );
+    }
+
+    /// Return a copy; datums are stored on the Rust side, so this is always a deep copy.
+    pub fn copy(&self) -> Self {
+        UpstreamMetadata(self.0.clone())
+    }
+
+    fn __copy__(&self) -> Self {
+        self.copy()
+    }
+
+    fn __deepcopy__(&self, _memo: &Bound<PyAny>) -> Self {
+        self.copy()
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|datum| datum.datum.field().to_string())
+            .collect()
+    }
+
     pub fn items(&self) -> Vec<(String, UpstreamDatum)> {
         self.0
             .iter()
@@ -361,6 +829,105 @@ impl UpstreamMetadata {
         value.unwrap_or(default)
     }
 
+    /// Remove a field and return its datum, or the default if it isn't set.
+    #[pyo3(signature = (field, *default))]
+    pub fn pop(&mut self, py: Python, field: &str, default: &Bound<PyTuple>) -> PyResult<PyObject> {
+        if default.len() > 1 {
+            return Err(PyTypeError::new_err(format!(
+                "pop expected at most 2 arguments, got {}",
+                default.len() + 1
+            )));
+        }
+        match self.0.remove(field) {
+            Some(datum) => Ok(UpstreamDatum(datum).into_py(py)),
+            None if !default.is_empty() => Ok(default.get_item(0)?.unbind()),
+            None => Err(PyKeyError::new_err(format!("No such field: {}", field))),
+        }
+    }
+
+    /// Return the datum for a field, setting it to the given datum if it isn't set.
+    pub fn setdefault(&mut self, field: &str, datum: UpstreamDatum) -> PyResult<UpstreamDatum> {
+        if let Some(existing) = self.0.get(field) {
+            return Ok(UpstreamDatum(existing.clone()));
+        }
+        if field != datum.0.datum.field() {
+            return Err(PyValueError::new_err(format!(
+                "Datum for {} can't be stored as {}",
+                datum.0.datum.field(),
+                field
+            )));
+        }
+        self.0.insert(datum.0.clone());
+        Ok(datum)
+    }
+
+    /// Merge datums from another UpstreamMetadata or an iterable of datums.
+    ///
+    /// Like update_from_guesses, existing fields are only replaced by datums with a higher
+    /// certainty. Returns the datums that were changed.
+    pub fn update(&mut self, other: &Bound<PyAny>) -> PyResult<Vec<UpstreamDatum>> {
+        let items = other
+            .iter()?
+            .map(|item| item?.extract::<UpstreamDatum>().map(|d| d.0))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(self
+            .0
+            .update(items.into_iter())
+            .into_iter()
+            .map(UpstreamDatum)
+            .collect())
+    }
+
+    /// Merge datums from another UpstreamMetadata, resolving conflicting fields.
+    ///
+    /// The strategy is one of:
+    ///  * "prefer_self": keep existing fields, only add missing ones
+    ///  * "prefer_other": replace existing fields with those from other
+    ///  * "prefer_higher_certainty": keep whichever datum is more certain; on a tie,
+    ///    a datum with a known origin wins over one without, and otherwise the
+    ///    existing datum is kept
+    ///
+    /// Returns the datums that were added or replaced.
+    #[pyo3(signature = (other, strategy="prefer_higher_certainty"))]
+    pub fn merge(
+        &mut self,
+        other: &UpstreamMetadata,
+        strategy: &str,
+    ) -> PyResult<Vec<UpstreamDatum>> {
+        let prefer_new: fn(
+            &upstream_ontologist::UpstreamDatumWithMetadata,
+            &upstream_ontologist::UpstreamDatumWithMetadata,
+        ) -> bool = match strategy {
+            "prefer_self" => |_, _| false,
+            "prefer_other" => |_, _| true,
+            "prefer_higher_certainty" => |old, new| {
+                // None sorts below any certainty
+                match new.certainty.cmp(&old.certainty) {
+                    std::cmp::Ordering::Greater => true,
+                    std::cmp::Ordering::Less => false,
+                    std::cmp::Ordering::Equal => old.origin.is_none() && new.origin.is_some(),
+                }
+            },
+            _ => {
+                return Err(PyValueError::new_err(format!(
+                    "Unknown merge strategy: {}",
+                    strategy
+                )))
+            }
+        };
+        let mut changed = Vec::new();
+        for new in other.0.iter() {
+            // insert() appends rather than replaces, so existing datums are updated in place
+            match self.0.get_mut(new.datum.field()) {
+                None => self.0.insert(new.clone()),
+                Some(old) if old != new && prefer_new(old, new) => *old = new.clone(),
+                Some(_) => continue,
+            }
+            changed.push(UpstreamDatum(new.clone()));
+        }
+        Ok(changed)
+    }
+
     fn __setitem__(&mut self, field: &str, datum: UpstreamDatum) -> PyResult<()> {
         assert_eq!(field, datum.0.datum.field());
         self.0.insert(datum.0);
@@ -382,6 +949,26 @@ impl UpstreamMetadata {
         ret
     }
 
+    /// Render as the contents of a debian/upstream/metadata file.
+    pub fn to_yaml(&self) -> String {
+        debian_metadata::to_yaml(&self.0)
+    }
+
+    /// Load from the contents of a debian/upstream/metadata file.
+    ///
+    /// Fields that aren't known are skipped.
+    #[classmethod]
+    #[pyo3(signature = (text, certainty=None))]
+    pub fn from_yaml(
+        _cls: &Bound<PyType>,
+        py: Python,
+        text: &str,
+        certainty: Option<String>,
+    ) -> PyResult<Self> {
+        let certainty = certainty.map(|c| parse_certainty(&c)).transpose()?;
+        Ok(Self(debian_metadata::from_yaml(py, text, certainty, None)?))
+    }
+
     #[classmethod]
     #[pyo3(signature = (d, default_certainty=None))]
     pub fn from_dict(
@@ -433,6 +1020,20 @@ impl UpstreamMetadata {
     }
 }
 
+#[pyfunction]
+#[pyo3(signature = (path, metadata, minimum_certainty=None))]
+fn update_metadata_file(
+    path: std::path::PathBuf,
+    metadata: &UpstreamMetadata,
+    minimum_certainty: Option<String>,
+) -> PyResult<Vec<String>> {
+    let minimum_certainty = minimum_certainty
+        .map(|c| parse_certainty(&c))
+        .transpose()?
+        .unwrap_or(Certainty::Certain);
+    debian_metadata::update_metadata_file(&path, &metadata.0, minimum_certainty)
+}
+
 #[pyfunction]
 #[pyo3(signature = (metadata, version=None))]
 fn check_upstream_metadata(metadata: &mut UpstreamMetadata, version: Option<&str>) -> PyResult<()> {
@@ -458,7 +1059,7 @@ fn extend_upstream_metadata(
         .transpose()
         .map_err(|e: String| PyValueError::new_err(format!("Invalid minimum_certainty: {}", e)))?;
     let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(upstream_ontologist::extend_upstream_metadata(
+    rt.block_on(providers::extend_upstream_metadata(
         &mut metadata.0,
         path.as_path(),
         minimum_certainty,
@@ -479,7 +1080,7 @@ fn guess_upstream_metadata(
 ) -> PyResult<UpstreamMetadata> {
     let rt = tokio::runtime::Runtime::new().unwrap();
     Ok(UpstreamMetadata(rt.block_on(
-        upstream_ontologist::guess_upstream_metadata(
+        providers::guess_upstream_metadata(
             path.as_path(),
             trust_package,
             net_access,
@@ -499,7 +1100,7 @@ fn guess_upstream_metadata_items(
 ) -> PyResult<Vec<PyObject>> {
     let rt = tokio::runtime::Runtime::new().unwrap();
     let metadata = rt.block_on(
-        upstream_ontologist::guess_upstream_metadata_items(
+        providers::guess_upstream_metadata_items(
             path.as_path(),
             trust_package,
             minimum_certainty
@@ -525,6 +1126,22 @@ fn fix_upstream_metadata(metadata: &mut UpstreamMetadata) -> PyResult<()> {
     Ok(())
 }
 
+#[pyfunction]
+#[pyo3(signature = (metadata, net_access=None, iteration_limit=None))]
+fn extrapolate_fields(
+    metadata: &mut UpstreamMetadata,
+    net_access: Option<bool>,
+    iteration_limit: Option<usize>,
+) -> PyResult<()> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    rt.block_on(extrapolate::extrapolate_fields(
+        &mut metadata.0,
+        net_access.unwrap_or(false),
+        iteration_limit,
+    ))?;
+    Ok(())
+}
+
 #[pyfunction]
 fn update_from_guesses(
     py: Python,
@@ -559,11 +1176,20 @@ fn _upstream_ontologist(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(drop_vcs_in_scheme))?;
     m.add_wrapped(wrap_pyfunction!(canonical_git_repo_url))?;
     m.add_wrapped(wrap_pyfunction!(find_public_repo_url))?;
+    m.add_wrapped(wrap_pyfunction!(repo_url_from_merge_request_url))?;
+    m.add_wrapped(wrap_pyfunction!(bug_database_url_from_bug_submit_url))?;
+    m.add_wrapped(wrap_pyfunction!(bug_submit_url_from_bug_database_url))?;
+    m.add_wrapped(wrap_pyfunction!(check_bug_database_canonical))?;
+    m.add_wrapped(wrap_pyfunction!(check_bug_submit_url_canonical))?;
     m.add_wrapped(wrap_pyfunction!(fixup_rcp_style_git_repo_url))?;
     m.add_wrapped(wrap_pyfunction!(check_upstream_metadata))?;
+    m.add_wrapped(wrap_pyfunction!(update_metadata_file))?;
     m.add_wrapped(wrap_pyfunction!(extend_upstream_metadata))?;
     m.add_wrapped(wrap_pyfunction!(guess_upstream_metadata))?;
     m.add_wrapped(wrap_pyfunction!(fix_upstream_metadata))?;
+    m.add_wrapped(wrap_pyfunction!(extrapolate_fields))?;
+    m.add_wrapped(wrap_pyfunction!(extrapolate::register_extrapolation))?;
+    m.add_wrapped(wrap_pyfunction!(extrapolate::unregister_extrapolation))?;
     m.add_wrapped(wrap_pyfunction!(guess_upstream_metadata_items))?;
     m.add_wrapped(wrap_pyfunction!(update_from_guesses))?;
     m.add_wrapped(wrap_pyfunction!(find_secure_repo_url))?;
@@ -571,7 +1197,103 @@ fn _upstream_ontologist(m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(fixup_broken_git_details))?;
     m.add_class::<UpstreamMetadata>()?;
     m.add_class::<UpstreamDatum>()?;
+    m.add_class::<Origin>()?;
+    m.add_class::<MetadataDiff>()?;
     m.add_wrapped(wrap_pyfunction!(known_bad_guess))?;
+    m.add_wrapped(wrap_pyfunction!(bad_guesses::add_known_bad_pattern))?;
+    m.add_wrapped(wrap_pyfunction!(bad_guesses::remove_known_bad_pattern))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_debian_watch))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_debian_control))?;
+    m.add_wrapped(wrap_pyfunction!(
+        providers::guess_from_debian_upstream_metadata
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_debian_copyright))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_debian_changelog))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_meta_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_meta_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_makefile_pl))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_dist_ini))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_r_description))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_cabal))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_package_yaml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_opam))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_pubspec_yaml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_package_xml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_pod))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_security_md))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_git_config))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_csproj))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_citation_cff))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_codemeta_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_zenodo_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_funding_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_snapcraft_yaml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_flatpak_manifest))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_desktop_file))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_appimage_builder_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_conda_meta))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_rpm_spec))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_pkgbuild))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_nix))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_build_gradle))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_gradle_properties))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_mix_exs))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_rebar_config))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_app_src))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_package_swift))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_spi_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_podspec))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_dub_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_dub_sdl))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_shard_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_project_clj))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_deps_edn))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_nimble))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_bower_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_wscript))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_vcpkg_json))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_conanfile))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_module_bazel))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_gemfile))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_rockspec))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_project_toml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_dockerfile))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_docker_compose))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_chart_yaml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_gitmodules))?;
+    m.add_wrapped(wrap_pyfunction!(
+        providers::guess_from_issue_template_config
+    ))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_codeowners))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_github_settings))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_sphinx_conf))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_mkdocs_yml))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_authors_file))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_license_file))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_changelog_file))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_version_from_git))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_crate_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_pypi_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_npm_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_rubygem_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_hackage_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_cran_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_packagist_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_nuget_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_maven_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_pecl_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_hex_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::remote_pub_data))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_github_api))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_gitlab_api))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_gitea_api))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_bitbucket_api))?;
+    m.add_wrapped(wrap_pyfunction!(providers::consult_repology))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_wikidata))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_ecosystems))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_openhub))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_debian_archive))?;
+    m.add_wrapped(wrap_pyfunction!(providers::guess_from_launchpad))?;
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
     Ok(())
 }
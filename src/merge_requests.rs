@@ -0,0 +1,40 @@
+//! Mapping merge request URLs back to the repository they were proposed against.
+
+use url::Url;
+
+/// Path segments that precede the number of a merge request, by forge.
+///
+/// GitHub uses `pull`, Gitea and Forgejo `pulls`, GitLab `merge_requests` (usually after a
+/// `-` separator) and Bitbucket `pull-requests`.
+const MERGE_REQUEST_SEGMENTS: &[&str] = &["pull", "pulls", "merge_requests", "pull-requests"];
+
+/// Return the repository URL for a merge request URL.
+///
+/// upstream_ontologist only knows about GitLab, and keeps its `/-/` separator, so the common
+/// shapes are handled here first. Trailing paths such as `/files` or `/diffs` are ignored.
+pub fn repo_url_from_merge_request_url(url: &Url) -> Option<Url> {
+    let segments = url
+        .path_segments()?
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    let index = segments.iter().enumerate().position(|(i, s)| {
+        i >= 2
+            && MERGE_REQUEST_SEGMENTS.contains(s)
+            && segments
+                .get(i + 1)
+                .is_some_and(|n| n.parse::<u32>().is_ok())
+    })?;
+    let mut repo = &segments[..index];
+    if repo.last() == Some(&"-") {
+        repo = &repo[..repo.len() - 1];
+    }
+    if repo.len() < 2 {
+        return None;
+    }
+    let mut url = url.clone();
+    url.set_scheme("https").ok()?;
+    url.set_query(None);
+    url.set_fragment(None);
+    url.path_segments_mut().ok()?.clear().extend(repo);
+    Some(url)
+}
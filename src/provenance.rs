@@ -0,0 +1,58 @@
+//! The origins of datums derived from other datums.
+//!
+//! upstream_ontologist records a single origin per datum. When a datum is derived from
+//! earlier findings, e.g. a Homepage from looking up the Cargo-Crate on crates.io, the
+//! origins of those findings are recorded here, so that the whole chain can be reported.
+
+use std::sync::Mutex;
+use upstream_ontologist::{Origin, UpstreamDatumWithMetadata};
+
+struct Derivation {
+    field: String,
+    value: String,
+    origin: Origin,
+    sources: Vec<Origin>,
+}
+
+static DERIVATIONS: Mutex<Vec<Derivation>> = Mutex::new(Vec::new());
+
+fn matches(derivation: &Derivation, datum: &UpstreamDatumWithMetadata) -> bool {
+    datum.origin.as_ref() == Some(&derivation.origin)
+        && derivation.field == datum.datum.field()
+        && derivation.value == datum.datum.to_string()
+}
+
+/// Record that a datum was derived from findings with the given chain of origins.
+///
+/// Datums are identified by their field, value and origin, so a later derivation of the
+/// same datum replaces an earlier one. Datums without an origin can't be identified.
+pub(crate) fn record(datum: &UpstreamDatumWithMetadata, sources: Vec<Origin>) {
+    let Some(origin) = datum.origin.clone() else {
+        return;
+    };
+    let mut derivations = DERIVATIONS.lock().unwrap();
+    derivations.retain(|d| !matches(d, datum));
+    if !sources.is_empty() {
+        derivations.push(Derivation {
+            field: datum.datum.field().to_string(),
+            value: datum.datum.to_string(),
+            origin,
+            sources,
+        });
+    }
+}
+
+/// The chain of origins a datum was derived from, oldest first and ending in its own origin.
+pub(crate) fn origins(datum: &UpstreamDatumWithMetadata) -> Vec<Origin> {
+    let Some(origin) = datum.origin.clone() else {
+        return vec![];
+    };
+    let derivations = DERIVATIONS.lock().unwrap();
+    let mut ret = derivations
+        .iter()
+        .find(|d| matches(d, datum))
+        .map(|d| d.sources.clone())
+        .unwrap_or_default();
+    ret.push(origin);
+    ret
+}
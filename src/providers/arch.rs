@@ -0,0 +1,95 @@
+use lazy_regex::regex;
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::providers::arch::parse_pkgbuild_variables;
+use upstream_ontologist::vcs::{strip_vcs_prefixes, VCSES};
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Expand $var and ${var} references to other PKGBUILD variables.
+fn expand_variables(value: &str, variables: &HashMap<String, Vec<String>>) -> String {
+    regex!(r"\$\{(\w+)\}|\$(\w+)")
+        .replace_all(value, |c: &lazy_regex::Captures| {
+            let name = c.get(1).or_else(|| c.get(2)).unwrap().as_str();
+            variables
+                .get(name)
+                .map_or_else(|| c[0].to_string(), |v| v.join(" "))
+        })
+        .into_owned()
+}
+
+/// Guess upstream metadata from an Arch Linux PKGBUILD.
+pub fn guess_from_pkgbuild(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    let variables = parse_pkgbuild_variables(&contents);
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+    let get = |key: &str| {
+        variables
+            .get(key)
+            .and_then(|v| v.first())
+            .map(|v| expand_variables(v, &variables))
+    };
+
+    // VCS packages (e.g. foo-git) build from the tip of a branch, and compute pkgver
+    let mut is_vcs_package = false;
+    if let Some(pkgname) = get("pkgname") {
+        let name = VCSES
+            .iter()
+            .find_map(|vcs| pkgname.strip_suffix(&format!("-{}", vcs)))
+            .map(|name| name.to_string());
+        is_vcs_package = name.is_some();
+        push(
+            UpstreamDatum::Name(name.unwrap_or(pkgname)),
+            Certainty::Likely,
+        );
+    }
+    if !is_vcs_package {
+        if let Some(pkgver) = get("pkgver") {
+            push(UpstreamDatum::Version(pkgver), Certainty::Likely);
+        }
+    }
+    if let Some(pkgdesc) = get("pkgdesc") {
+        push(UpstreamDatum::Summary(pkgdesc), Certainty::Certain);
+    }
+    if let Some(url) = get("url") {
+        push(UpstreamDatum::Homepage(url), Certainty::Certain);
+    }
+    if let Some(licenses) = variables.get("license").filter(|l| !l.is_empty()) {
+        push(
+            UpstreamDatum::License(licenses.join(" AND ")),
+            Certainty::Certain,
+        );
+    }
+
+    for source in variables.get("source").into_iter().flatten() {
+        let source = expand_variables(source, &variables);
+        // Sources may be given a local name, as in name::url
+        let url = source
+            .split_once("::")
+            .map_or(source.as_str(), |(_, url)| url);
+        let is_git = url.starts_with("git+") || url.starts_with("git://");
+        if !is_git {
+            continue;
+        }
+        // Drop fragments that select a branch, tag or commit
+        let url = url.split_once('#').map_or(url, |(url, _)| url);
+        push(
+            UpstreamDatum::Repository(strip_vcs_prefixes(url).to_string()),
+            Certainty::Likely,
+        );
+    }
+
+    Ok(results)
+}
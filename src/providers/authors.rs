@@ -0,0 +1,131 @@
+use lazy_regex::{regex_captures, regex_is_match};
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum Role {
+    Author,
+    Maintainer,
+    Other,
+}
+
+/// Determine the role of the people listed under a section header.
+fn section_role(header: &str) -> Role {
+    let header = header.to_lowercase();
+    if header.contains("maintainer") {
+        Role::Maintainer
+    } else if header.contains("author")
+        || header.contains("developer")
+        || header.contains("contributor")
+    {
+        Role::Author
+    } else {
+        // e.g. translators, artwork, thanks
+        Role::Other
+    }
+}
+
+/// Parse a single entry, e.g. "Name <email>", "Name (url)" or "Name <email> (url)".
+fn parse_person(entry: &str) -> Option<Person> {
+    if let Some((_, rest, url)) = regex_captures!(r"^(.*?)\s*\((https?://[^)]+)\)$", entry) {
+        return Some(Person {
+            url: Some(url.to_string()),
+            ..parse_person(rest)?
+        });
+    }
+    let person = Person::from(entry);
+    person.name.as_ref()?;
+    Some(person)
+}
+
+/// Guess upstream metadata from an AUTHORS, MAINTAINERS or CONTRIBUTORS file.
+///
+/// The file name determines whether people are authors or maintainers, unless they are
+/// listed under a section header such as "Maintainers:" or "## Authors". Sections for
+/// other roles, such as translators, are skipped.
+pub fn guess_from_authors_file(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    let filename = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_uppercase())
+        .unwrap_or_default();
+    let (default_role, certainty) = if filename.starts_with("MAINTAINERS") {
+        (Role::Maintainer, Certainty::Likely)
+    } else if filename.starts_with("CONTRIBUTORS") {
+        (Role::Author, Certainty::Possible)
+    } else {
+        (Role::Author, Certainty::Likely)
+    };
+
+    let mut role = default_role;
+    let mut authors = Vec::new();
+    let mut maintainers = Vec::new();
+    let mut lines = contents.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        // Markdown headers, "Header:" and underlined headers
+        if let Some(header) = trimmed
+            .strip_prefix('#')
+            .or_else(|| trimmed.strip_suffix(':'))
+        {
+            role = section_role(header);
+            continue;
+        }
+        if lines
+            .peek()
+            .is_some_and(|next| regex_is_match!(r"^\s*(=+|-+)\s*$", next))
+        {
+            role = section_role(trimmed);
+            lines.next();
+            continue;
+        }
+        // Linux-style MAINTAINERS entries, e.g. "M: Name <email>"
+        let (entry, entry_role) = match regex_captures!(r"^([A-Z]):\s*(.*)$", trimmed) {
+            Some((_, "M", entry)) => (entry, Role::Maintainer),
+            Some(_) => continue,
+            None => (trimmed, role),
+        };
+        let entry = entry.trim_start_matches(['*', '-', '+']).trim();
+        // Skip prose; entries are short unless they include an email address
+        if !entry.contains('<') && entry.split_whitespace().count() > 5 {
+            continue;
+        }
+        if !entry.chars().next().is_some_and(char::is_alphabetic) {
+            continue;
+        }
+        let person = match parse_person(entry) {
+            Some(person) => person,
+            None => continue,
+        };
+        match entry_role {
+            Role::Author => authors.push(person),
+            Role::Maintainer => maintainers.push(person),
+            Role::Other => {}
+        }
+    }
+
+    let mut results = Vec::new();
+    if !authors.is_empty() {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Author(authors),
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        });
+    }
+    for maintainer in maintainers {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Maintainer(maintainer),
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        });
+    }
+    Ok(results)
+}
@@ -0,0 +1,125 @@
+use lazy_regex::regex;
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the top-level calls of a Starlark file, with their keyword arguments.
+///
+/// Only string and list-of-string arguments are kept; lists are returned as their string
+/// elements.
+fn starlark_calls(contents: &str) -> Vec<(&str, HashMap<&str, Vec<String>>)> {
+    let mut calls = Vec::new();
+    for c in regex!(r"(?m)^(\w+)\s*\(").captures_iter(contents) {
+        let start = c.get(0).unwrap().end();
+        let mut depth = 1;
+        let mut in_string = None;
+        let mut escaped = false;
+        let mut end = contents.len();
+        for (i, ch) in contents[start..].char_indices() {
+            if let Some(quote) = in_string {
+                if escaped {
+                    escaped = false;
+                } else if ch == '\\' {
+                    escaped = true;
+                } else if ch == quote {
+                    in_string = None;
+                }
+                continue;
+            }
+            match ch {
+                '"' | '\'' => in_string = Some(ch),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        end = start + i;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let args = regex!(r#"\b(\w+)\s*=\s*("[^"]*"|'[^']*'|\[[^\]]*\])"#)
+            .captures_iter(&contents[start..end])
+            .map(|a| {
+                let values = regex!(r#""([^"]*)"|'([^']*)'"#)
+                    .captures_iter(a.get(2).unwrap().as_str())
+                    .map(|s| s.get(1).or_else(|| s.get(2)).unwrap().as_str().to_string())
+                    .collect();
+                (a.get(1).unwrap().as_str(), values)
+            })
+            .collect();
+        calls.push((c.get(1).unwrap().as_str(), args));
+    }
+    calls
+}
+
+/// Guess upstream metadata from a Bazel MODULE.bazel file.
+///
+/// Besides the module declaration, overrides and repository rules that refer back to the
+/// module itself are used to find its repository.
+pub fn guess_from_module_bazel(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    let calls = starlark_calls(&contents);
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let first =
+        |args: &HashMap<&str, Vec<String>>, key| args.get(key).and_then(|v| v.first()).cloned();
+
+    let module = calls
+        .iter()
+        .find(|(function, _)| *function == "module")
+        .map(|(_, args)| args);
+    let name = module.and_then(|args| first(args, "name"));
+    if let Some(name) = name.as_ref() {
+        push(UpstreamDatum::Name(name.clone()), Certainty::Certain);
+    }
+    if let Some(version) = module.and_then(|args| first(args, "version")) {
+        push(UpstreamDatum::Version(version), Certainty::Certain);
+    }
+
+    let name = match name {
+        Some(name) => name,
+        None => return Ok(results),
+    };
+    for (function, args) in &calls {
+        let target = first(args, "module_name").or_else(|| first(args, "name"));
+        if target.as_ref() != Some(&name) {
+            continue;
+        }
+        match *function {
+            "git_override" | "git_repository" | "new_git_repository" => {
+                if let Some(remote) = first(args, "remote") {
+                    push(UpstreamDatum::Repository(remote), Certainty::Likely);
+                }
+            }
+            "archive_override" | "http_archive" => {
+                for url in args
+                    .get("urls")
+                    .cloned()
+                    .unwrap_or_default()
+                    .into_iter()
+                    .chain(first(args, "url"))
+                {
+                    push(UpstreamDatum::Download(url), Certainty::Possible);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(results)
+}
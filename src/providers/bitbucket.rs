@@ -0,0 +1,146 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Extract the workspace and repository name from a bitbucket.org URL.
+pub fn bitbucket_repo(url: &str) -> Option<(String, String)> {
+    let url = url::Url::parse(url.split_whitespace().next()?).ok()?;
+    if url.host_str() != Some("bitbucket.org") {
+        return None;
+    }
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+    let workspace = segments.next()?;
+    let repo = segments.next()?;
+    Some((
+        workspace.to_string(),
+        repo.strip_suffix(".git").unwrap_or(repo).to_string(),
+    ))
+}
+
+/// Derive the canonical clone URL for a bitbucket.org repository or any page within it.
+pub fn canonical_repo_url(url: &str) -> Option<String> {
+    let (workspace, repo) = bitbucket_repo(url)?;
+    Some(format!("https://bitbucket.org/{}/{}.git", workspace, repo))
+}
+
+/// Query the Bitbucket API for the metadata of a repository.
+pub async fn guess_from_bitbucket_api(repo_url: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let (workspace, repo) = match bitbucket_repo(repo_url) {
+        Some(parts) => parts,
+        None => {
+            return Err(ProviderError::Other(format!(
+                "Not a Bitbucket repository: {}",
+                repo_url
+            )))
+        }
+    };
+    let url = format!(
+        "https://api.bitbucket.org/2.0/repositories/{}/{}",
+        workspace, repo
+    )
+    .parse()
+    .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_bitbucket_repository(&data))
+}
+
+/// Extract metadata from a Bitbucket repository API response.
+fn parse_bitbucket_repository(data: &Value) -> Vec<UpstreamDatum> {
+    let get_str = |value: &Value, key| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    };
+
+    let mut datums = Vec::new();
+    if let Some(description) = get_str(data, "description") {
+        datums.push(UpstreamDatum::Summary(description));
+    }
+    if let Some(website) = get_str(data, "website") {
+        datums.push(UpstreamDatum::Homepage(website));
+    }
+    if let Some(html_url) = get_str(&data["links"]["html"], "href") {
+        if let Some(branch) = get_str(&data["mainbranch"], "name") {
+            datums.push(UpstreamDatum::RepositoryBrowse(format!(
+                "{}/src/{}",
+                html_url, branch
+            )));
+        }
+        if data.get("has_issues").and_then(Value::as_bool) == Some(true) {
+            datums.push(UpstreamDatum::BugDatabase(format!("{}/issues", html_url)));
+        }
+    }
+
+    datums
+}
+
+pub struct BitbucketApi;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for BitbucketApi {
+    fn name(&self) -> &'static str {
+        "Bitbucket"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Summary", "Homepage", "Bug-Database", "Repository-Browse"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the Bitbucket API", guess_from_bitbucket_api(name).await)
+    }
+}
+
+impl super::Registry for BitbucketApi {
+    fn page_url(&self, repo_url: &str) -> String {
+        repo_url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bitbucket_repository() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "full_name": "tortoisehg/thg",
+                "description": "TortoiseHg is a set of graphical tools for Mercurial.",
+                "website": "https://tortoisehg.bitbucket.io/",
+                "has_issues": true,
+                "mainbranch": {"type": "named_branch", "name": "default"},
+                "links": {
+                    "html": {"href": "https://bitbucket.org/tortoisehg/thg"},
+                    "clone": []
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_bitbucket_repository(&data),
+            vec![
+                UpstreamDatum::Summary(
+                    "TortoiseHg is a set of graphical tools for Mercurial.".to_string()
+                ),
+                UpstreamDatum::Homepage("https://tortoisehg.bitbucket.io/".to_string()),
+                UpstreamDatum::RepositoryBrowse(
+                    "https://bitbucket.org/tortoisehg/thg/src/default".to_string()
+                ),
+                UpstreamDatum::BugDatabase(
+                    "https://bitbucket.org/tortoisehg/thg/issues".to_string()
+                ),
+            ]
+        );
+    }
+}
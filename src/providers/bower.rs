@@ -0,0 +1,113 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Authors are either "Name <email> (url)" strings or objects with name, email and homepage.
+fn bower_person(value: &Value) -> Option<Person> {
+    match value {
+        Value::String(s) => {
+            let (s, url) = match s.trim().strip_suffix(')').and_then(|s| s.rsplit_once('(')) {
+                Some((s, url)) => (s.trim(), Some(url.to_string())),
+                None => (s.trim(), None),
+            };
+            Some(Person {
+                url,
+                ..Person::from(s)
+            })
+        }
+        Value::Object(o) => Some(Person {
+            name: o.get("name").and_then(Value::as_str).map(String::from),
+            email: o.get("email").and_then(Value::as_str).map(String::from),
+            url: o.get("homepage").and_then(Value::as_str).map(String::from),
+        }),
+        _ => None,
+    }
+}
+
+/// Guess upstream metadata from a bower.json file.
+pub fn guess_from_bower_json(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_json::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+    }
+    if let Some(description) = get_str("description") {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(homepage) = get_str("homepage") {
+        push(
+            UpstreamDatum::Homepage(homepage.to_string()),
+            Certainty::Certain,
+        );
+    }
+    // The version field is deprecated, since bower uses git tags instead
+    if let Some(version) = get_str("version") {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Possible,
+        );
+    }
+    match data.get("license") {
+        Some(Value::String(license)) => push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        ),
+        Some(Value::Array(licenses)) => {
+            let licenses = licenses
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+            if !licenses.is_empty() {
+                push(
+                    UpstreamDatum::License(licenses.join(" OR ")),
+                    Certainty::Certain,
+                );
+            }
+        }
+        _ => {}
+    }
+    match data.get("repository") {
+        Some(Value::String(url)) => push(
+            UpstreamDatum::Repository(url.to_string()),
+            Certainty::Certain,
+        ),
+        Some(Value::Object(repository)) => {
+            if let Some(url) = repository.get("url").and_then(Value::as_str) {
+                push(
+                    UpstreamDatum::Repository(url.to_string()),
+                    Certainty::Certain,
+                );
+            }
+        }
+        _ => {}
+    }
+    if let Some(authors) = data.get("authors").and_then(Value::as_array) {
+        let authors = authors.iter().filter_map(bower_person).collect::<Vec<_>>();
+        if !authors.is_empty() {
+            push(UpstreamDatum::Author(authors), Certainty::Certain);
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,110 @@
+use lazy_regex::{regex_captures, regex_is_match};
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Changelog file names, in order of preference.
+pub const CHANGELOG_FILES: &[&str] = &[
+    "CHANGELOG.md",
+    "CHANGELOG.rst",
+    "CHANGELOG",
+    "CHANGES.md",
+    "CHANGES.rst",
+    "CHANGES",
+    "NEWS.md",
+    "NEWS.rst",
+    "NEWS",
+    "HISTORY.md",
+    "HISTORY.rst",
+];
+
+/// Find the most recent released version in a changelog.
+///
+/// Versions are only looked for in headings: Markdown headings, underlined headings and
+/// lines starting with a version, e.g. "1.2.3 (2024-01-01)". Entries marked as unreleased
+/// are skipped.
+fn latest_version(contents: &str) -> Option<String> {
+    let mut lines = contents.lines().peekable();
+    while let Some(line) = lines.next() {
+        let underlined = lines
+            .peek()
+            .is_some_and(|next| regex_is_match!(r"^(=+|-+|~+)\s*$", next));
+        let heading = if let Some(heading) = line.strip_prefix('#') {
+            heading.trim_start_matches('#')
+        } else if underlined
+            || regex_is_match!(
+                r"^(\[?v?\d|(?i:version|release)\b|\* Noteworthy changes in release)",
+                line
+            )
+        {
+            line
+        } else {
+            continue;
+        };
+        if heading.to_lowercase().contains("unreleased") {
+            continue;
+        }
+        if let Some((_, version)) = regex_captures!(
+            r"\bv?(\d+(?:\.\d+)+(?:[-.]?(?:alpha|beta|rc|a|b)\d*)?)\b",
+            heading
+        ) {
+            return Some(version.to_string());
+        }
+    }
+    None
+}
+
+/// Guess the latest released version from a changelog file.
+pub fn guess_from_changelog_file(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    Ok(latest_version(&contents)
+        .map(|version| UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Version(version),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        })
+        .into_iter()
+        .collect())
+}
+
+/// Add a Changelog entry pointing at the hosted changelog file.
+///
+/// This is only possible for repositories on GitHub and GitLab, which can refer to files on
+/// the default branch without knowing its name.
+pub fn extend_changelog_url(metadata: &mut upstream_ontologist::UpstreamMetadata, path: &Path) {
+    if metadata.contains_key("Changelog") {
+        return;
+    }
+    let filename = match CHANGELOG_FILES.iter().find(|f| path.join(f).exists()) {
+        Some(filename) => filename,
+        None => return,
+    };
+    let repository = match metadata.get("Repository") {
+        Some(repository) => repository.clone(),
+        None => return,
+    };
+    let url = match repository.datum.as_str().and_then(|url| {
+        regex_captures!(
+            r"^https://(github\.com|gitlab\.com)/([^/]+/[^/]+?)(?:\.git)?/?$",
+            url
+        )
+    }) {
+        Some((_, "github.com", project)) => {
+            format!("https://github.com/{}/blob/HEAD/{}", project, filename)
+        }
+        Some((_, _, project)) => {
+            format!("https://gitlab.com/{}/-/blob/HEAD/{}", project, filename)
+        }
+        None => return,
+    };
+    metadata.insert(UpstreamDatumWithMetadata {
+        datum: UpstreamDatum::Changelog(url),
+        certainty: std::cmp::min(repository.certainty, Some(Certainty::Likely)),
+        origin: Some(path.join(filename).as_path().into()),
+    });
+}
@@ -0,0 +1,131 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+fn doi_url(doi: &str) -> String {
+    format!("https://doi.org/{}", doi)
+}
+
+fn cff_person(author: &Value) -> Option<Person> {
+    // Entities (e.g. organisations) have a single "name" rather than given/family names
+    let name = match (
+        author.get("given-names").and_then(Value::as_str),
+        author.get("family-names").and_then(Value::as_str),
+    ) {
+        (Some(given), Some(family)) => Some(format!("{} {}", given, family)),
+        (given, family) => given
+            .or(family)
+            .or_else(|| author.get("name").and_then(Value::as_str))
+            .map(|s| s.to_string()),
+    };
+    name.as_ref()?;
+    Some(Person {
+        name,
+        email: author
+            .get("email")
+            .and_then(Value::as_str)
+            .map(|s| s.to_string()),
+        url: author
+            .get("orcid")
+            .or_else(|| author.get("website"))
+            .and_then(Value::as_str)
+            .map(|s| s.to_string()),
+    })
+}
+
+/// Guess upstream metadata from a CITATION.cff file.
+///
+/// See https://citation-file-format.github.io/ for the format.
+pub fn guess_from_citation_cff(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let cff: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(title) = cff.get("title").and_then(Value::as_str) {
+        push(UpstreamDatum::Name(title.to_string()), Certainty::Likely);
+    }
+    if let Some(summary) = cff.get("abstract").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Summary(summary.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(version) = cff.get("version").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(authors) = cff.get("authors").and_then(Value::as_sequence) {
+        let authors = authors.iter().filter_map(cff_person).collect::<Vec<_>>();
+        if !authors.is_empty() {
+            push(UpstreamDatum::Author(authors), Certainty::Certain);
+        }
+    }
+    if let Some(url) = cff.get("repository-code").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Repository(url.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(url) = cff.get("url").and_then(Value::as_str) {
+        push(UpstreamDatum::Homepage(url.to_string()), Certainty::Certain);
+    }
+    match cff.get("license") {
+        Some(Value::String(license)) => {
+            push(UpstreamDatum::License(license.clone()), Certainty::Certain)
+        }
+        Some(Value::Sequence(licenses)) => {
+            let licenses = licenses
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>();
+            if !licenses.is_empty() {
+                push(
+                    UpstreamDatum::License(licenses.join(" OR ")),
+                    Certainty::Certain,
+                );
+            }
+        }
+        _ => {}
+    }
+    if let Some(keywords) = cff.get("keywords").and_then(Value::as_sequence) {
+        push(
+            UpstreamDatum::Keywords(
+                keywords
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|s| s.to_string())
+                    .collect(),
+            ),
+            Certainty::Certain,
+        );
+    }
+
+    // The preferred citation wins over the DOI of the software itself
+    if let Some(doi) = cff
+        .get("preferred-citation")
+        .and_then(|c| c.get("doi"))
+        .and_then(Value::as_str)
+    {
+        push(UpstreamDatum::CiteAs(doi_url(doi)), Certainty::Certain);
+    } else if let Some(doi) = cff.get("doi").and_then(Value::as_str) {
+        push(UpstreamDatum::CiteAs(doi_url(doi)), Certainty::Certain);
+    }
+
+    Ok(results)
+}
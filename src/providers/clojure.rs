@@ -0,0 +1,253 @@
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Split EDN source into its top-level forms.
+///
+/// This only understands enough of EDN to find the boundaries of strings, collections and
+/// atoms; comments are skipped.
+fn edn_forms(source: &str) -> Vec<&str> {
+    let bytes = source.as_bytes();
+    let mut forms = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b';' => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            c if c.is_ascii_whitespace() || c == b',' => i += 1,
+            _ => {
+                let start = i;
+                let mut depth = 0;
+                let mut in_string = false;
+                while i < bytes.len() {
+                    let c = bytes[i];
+                    if in_string {
+                        if c == b'\\' {
+                            i += 1;
+                        } else if c == b'"' {
+                            in_string = false;
+                            if depth == 0 {
+                                i += 1;
+                                break;
+                            }
+                        }
+                    } else if c == b'"' {
+                        if depth == 0 && i > start {
+                            break;
+                        }
+                        in_string = true;
+                    } else if b"([{".contains(&c) {
+                        depth += 1;
+                    } else if b")]}".contains(&c) {
+                        if depth == 0 {
+                            // Unbalanced closing delimiter; skip it
+                            i += 1;
+                            break;
+                        }
+                        depth -= 1;
+                        if depth == 0 {
+                            i += 1;
+                            break;
+                        }
+                    } else if depth == 0 && (c.is_ascii_whitespace() || c == b',' || c == b';') {
+                        break;
+                    }
+                    i += 1;
+                }
+                forms.push(&source[start..i.min(bytes.len())]);
+            }
+        }
+    }
+    forms
+}
+
+/// Return the contents of a collection form, without its delimiters.
+fn edn_collection_body(form: &str) -> Option<&str> {
+    let form = form.strip_prefix('#').unwrap_or(form);
+    // Unbalanced forms (e.g. in a truncated file) run to the end of the input
+    let (body, close) = if let Some(body) = form.strip_prefix('(') {
+        (body, ')')
+    } else if let Some(body) = form.strip_prefix('[') {
+        (body, ']')
+    } else {
+        (form.strip_prefix('{')?, '}')
+    };
+    body.strip_suffix(close)
+}
+
+fn edn_string(form: &str) -> Option<String> {
+    let inner = form.strip_prefix('"')?.strip_suffix('"')?;
+    Some(inner.replace("\\\"", "\"").replace("\\\\", "\\"))
+}
+
+/// Collect the `:keyword value` pairs from a sequence of forms.
+fn edn_keyword_entries<'a>(forms: &[&'a str]) -> Vec<(&'a str, &'a str)> {
+    let mut entries = Vec::new();
+    let mut i = 0;
+    while i < forms.len() {
+        match forms[i].strip_prefix(':') {
+            Some(key) if i + 1 < forms.len() => {
+                entries.push((key, forms[i + 1]));
+                i += 2;
+            }
+            _ => i += 1,
+        }
+    }
+    entries
+}
+
+fn license_names(form: &str) -> Vec<String> {
+    if let Some(name) = edn_string(form) {
+        return vec![name];
+    }
+    let forms = match edn_collection_body(form) {
+        Some(body) => edn_forms(body),
+        None => return vec![],
+    };
+    if form.starts_with('{') {
+        edn_keyword_entries(&forms)
+            .into_iter()
+            .filter(|(key, _)| *key == "name")
+            .filter_map(|(_, value)| edn_string(value))
+            .collect()
+    } else {
+        forms.into_iter().flat_map(license_names).collect()
+    }
+}
+
+/// Convert project metadata entries, as used by both Leiningen and tools.build, into datums.
+fn guess_from_entries(path: &Path, entries: &[(&str, &str)]) -> Vec<UpstreamDatumWithMetadata> {
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let mut licenses = Vec::new();
+    for (key, value) in entries {
+        match *key {
+            "description" => {
+                if let Some(description) = edn_string(value) {
+                    push(UpstreamDatum::Summary(description), Certainty::Certain);
+                }
+            }
+            "url" => {
+                if let Some(url) = edn_string(value) {
+                    push(UpstreamDatum::Homepage(url), Certainty::Certain);
+                }
+            }
+            "version" => {
+                if let Some(version) = edn_string(value) {
+                    push(UpstreamDatum::Version(version), Certainty::Likely);
+                }
+            }
+            "lib" => {
+                let name = value.rsplit('/').next().unwrap();
+                push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+            }
+            "license" | "licenses" => licenses.extend(license_names(value)),
+            "scm" => {
+                let forms = edn_collection_body(value)
+                    .map(edn_forms)
+                    .unwrap_or_default();
+                if let Some(url) = edn_keyword_entries(&forms)
+                    .into_iter()
+                    .find(|(key, _)| *key == "url")
+                    .and_then(|(_, value)| edn_string(value))
+                {
+                    push(UpstreamDatum::Repository(url), Certainty::Certain);
+                }
+            }
+            _ => {}
+        }
+    }
+    if !licenses.is_empty() {
+        push(
+            UpstreamDatum::License(licenses.join(" OR ")),
+            Certainty::Certain,
+        );
+    }
+
+    results
+}
+
+/// Guess upstream metadata from a Leiningen project.clj file.
+pub fn guess_from_project_clj(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let defproject = edn_forms(&contents)
+        .into_iter()
+        .filter_map(edn_collection_body)
+        .map(edn_forms)
+        .find(|forms| forms.first() == Some(&"defproject"))
+        .ok_or_else(|| ProviderError::ParseError("no defproject form found".to_string()))?;
+
+    let mut results = Vec::new();
+    if let Some(name) = defproject.get(1) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Name(name.rsplit('/').next().unwrap().to_string()),
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        });
+    }
+    if let Some(version) = defproject.get(2).and_then(|v| edn_string(v)) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Version(version),
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        });
+    }
+    let entries = edn_keyword_entries(defproject.get(3..).unwrap_or_default());
+    results.extend(guess_from_entries(
+        path,
+        &entries
+            .into_iter()
+            .filter(|(key, _)| *key != "version")
+            .collect::<Vec<_>>(),
+    ));
+
+    Ok(results)
+}
+
+/// Find the first map that describes the library being built, i.e. one with a :lib or
+/// :scm entry, such as the :exec-args of a tools.build alias.
+fn find_pom_entries(form: &str) -> Option<Vec<(&str, &str)>> {
+    let forms = edn_forms(edn_collection_body(form)?);
+    if form.starts_with('{') {
+        let entries = edn_keyword_entries(&forms);
+        if entries
+            .iter()
+            .any(|(key, _)| *key == "lib" || *key == "scm")
+        {
+            return Some(entries);
+        }
+    }
+    forms.into_iter().find_map(find_pom_entries)
+}
+
+/// Guess upstream metadata from a Clojure deps.edn file.
+///
+/// deps.edn itself has no project metadata; this looks for the pom settings that are
+/// commonly passed to tools.build or deployment aliases.
+pub fn guess_from_deps_edn(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    Ok(edn_forms(&contents)
+        .into_iter()
+        .find_map(find_pom_entries)
+        .map(|entries| guess_from_entries(path, &entries))
+        .unwrap_or_default())
+}
@@ -0,0 +1,235 @@
+use lazy_regex::{regex, regex_captures};
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+fn push(results: &mut Vec<UpstreamDatumWithMetadata>, path: &Path, datum: UpstreamDatum) {
+    results.push(UpstreamDatumWithMetadata {
+        datum,
+        certainty: Some(Certainty::Certain),
+        origin: Some(path.into()),
+    });
+}
+
+/// Find the string value of a `spec.attr = '...'` assignment.
+fn ruby_string_attribute<'a>(contents: &'a str, attr: &str) -> Option<&'a str> {
+    regex!(r#"(?m)^\s*\w+\.(\w+)\s*=\s*(?:'([^']*)'|"([^"]*)")"#)
+        .captures_iter(contents)
+        .find(|c| &c[1] == attr)
+        .and_then(|c| c.get(2).or_else(|| c.get(3)))
+        .map(|m| m.as_str())
+}
+
+/// Find the value of a `:key => '...'` (or `key: '...'`) entry in a Ruby hash.
+fn ruby_hash_entry<'a>(hash: &'a str, key: &str) -> Option<&'a str> {
+    regex!(r#"(?::(\w+)\s*=>|\b(\w+):)\s*(?:'([^']*)'|"([^"]*)")"#)
+        .captures_iter(hash)
+        .find(|c| c.get(1).or_else(|| c.get(2)).unwrap().as_str() == key)
+        .and_then(|c| c.get(3).or_else(|| c.get(4)))
+        .map(|m| m.as_str())
+}
+
+/// Find the value of a `spec.attr = <<-DESC ... DESC` heredoc assignment.
+fn ruby_heredoc_attribute(contents: &str, attr: &str) -> Option<String> {
+    let (start, marker) = regex!(r"(?m)^\s*\w+\.(\w+)\s*=\s*<<[-~]?(\w+)\s*$")
+        .captures_iter(contents)
+        .find(|c| &c[1] == attr)
+        .map(|c| (c.get(0).unwrap().end(), c.get(2).unwrap().as_str()))?;
+    let lines = contents[start..]
+        .lines()
+        .skip(1)
+        .take_while(|line| line.trim() != marker)
+        .map(str::trim)
+        .collect::<Vec<_>>();
+    Some(lines.join("\n").trim().to_string())
+}
+
+/// Guess upstream metadata from a Ruby CocoaPods podspec.
+fn guess_from_ruby_podspec(path: &Path, contents: &str) -> Vec<UpstreamDatumWithMetadata> {
+    let mut results = Vec::new();
+
+    if let Some(name) = ruby_string_attribute(contents, "name") {
+        push(&mut results, path, UpstreamDatum::Name(name.to_string()));
+    }
+    if let Some(homepage) = ruby_string_attribute(contents, "homepage") {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Homepage(homepage.to_string()),
+        );
+    }
+    if let Some(summary) = ruby_string_attribute(contents, "summary") {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Summary(summary.to_string()),
+        );
+    }
+    if let Some(description) = ruby_string_attribute(contents, "description") {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Description(description.to_string()),
+        );
+    } else if let Some(description) = ruby_heredoc_attribute(contents, "description") {
+        push(&mut results, path, UpstreamDatum::Description(description));
+    }
+    if let Some(license) = ruby_string_attribute(contents, "license") {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::License(license.to_string()),
+        );
+    } else if let Some(license) = regex_captures!(r"\.license\s*=\s*\{([^}]*)\}", contents)
+        .and_then(|(_, hash)| ruby_hash_entry(hash, "type"))
+    {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::License(license.to_string()),
+        );
+    }
+    if let Some(git) = regex_captures!(r"\.source\s*=\s*\{([^}]*)\}", contents)
+        .and_then(|(_, hash)| ruby_hash_entry(hash, "git"))
+    {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Repository(git.to_string()),
+        );
+    }
+
+    if let Some(author) = ruby_string_attribute(contents, "author")
+        .or_else(|| ruby_string_attribute(contents, "authors"))
+    {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Author(vec![Person::from(author)]),
+        );
+    } else if let Some((_, hash)) = regex_captures!(r"\.authors?\s*=\s*\{([^}]*)\}", contents) {
+        let authors = regex!(r#"['"]([^'"]+)['"]\s*=>\s*['"]([^'"]+)['"]"#)
+            .captures_iter(hash)
+            .map(|c| Person {
+                name: Some(c[1].to_string()),
+                email: Some(c[2].to_string()),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+        if !authors.is_empty() {
+            push(&mut results, path, UpstreamDatum::Author(authors));
+        }
+    }
+
+    if let Some(screenshot) = ruby_string_attribute(contents, "screenshot") {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Screenshots(vec![screenshot.to_string()]),
+        );
+    } else if let Some((_, list)) = regex_captures!(r"\.screenshots\s*=\s*\[([^\]]*)\]", contents) {
+        let screenshots = regex!(r#"['"]([^'"]+)['"]"#)
+            .captures_iter(list)
+            .map(|c| c[1].to_string())
+            .collect::<Vec<_>>();
+        if !screenshots.is_empty() {
+            push(&mut results, path, UpstreamDatum::Screenshots(screenshots));
+        }
+    }
+
+    results
+}
+
+/// Guess upstream metadata from a JSON podspec, as published in the CocoaPods specs repo.
+fn guess_from_json_podspec(
+    path: &Path,
+    contents: &str,
+) -> Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let data: Value =
+        serde_json::from_str(contents).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+    let mut results = Vec::new();
+    let get_str = |key: &str| data.get(key).and_then(Value::as_str).map(|s| s.to_string());
+
+    if let Some(name) = get_str("name") {
+        push(&mut results, path, UpstreamDatum::Name(name));
+    }
+    if let Some(homepage) = get_str("homepage") {
+        push(&mut results, path, UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(summary) = get_str("summary") {
+        push(&mut results, path, UpstreamDatum::Summary(summary));
+    }
+    if let Some(description) = get_str("description") {
+        push(&mut results, path, UpstreamDatum::Description(description));
+    }
+    if let Some(license) = data
+        .get("license")
+        .and_then(|l| l.as_str().or_else(|| l.get("type").and_then(Value::as_str)))
+    {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::License(license.to_string()),
+        );
+    }
+    if let Some(git) = data
+        .get("source")
+        .and_then(|s| s.get("git"))
+        .and_then(Value::as_str)
+    {
+        push(
+            &mut results,
+            path,
+            UpstreamDatum::Repository(git.to_string()),
+        );
+    }
+    let authors = match data.get("authors").or_else(|| data.get("author")) {
+        Some(Value::String(author)) => vec![Person::from(author.as_str())],
+        Some(Value::Array(authors)) => authors
+            .iter()
+            .filter_map(Value::as_str)
+            .map(Person::from)
+            .collect(),
+        Some(Value::Object(authors)) => authors
+            .iter()
+            .map(|(name, email)| Person {
+                name: Some(name.to_string()),
+                email: email.as_str().map(|s| s.to_string()),
+                ..Default::default()
+            })
+            .collect(),
+        _ => vec![],
+    };
+    if !authors.is_empty() {
+        push(&mut results, path, UpstreamDatum::Author(authors));
+    }
+    let screenshots = match data.get("screenshots").or_else(|| data.get("screenshot")) {
+        Some(Value::String(screenshot)) => vec![screenshot.to_string()],
+        Some(Value::Array(screenshots)) => screenshots
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|s| s.to_string())
+            .collect(),
+        _ => vec![],
+    };
+    if !screenshots.is_empty() {
+        push(&mut results, path, UpstreamDatum::Screenshots(screenshots));
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from a CocoaPods podspec, in either the Ruby or the JSON form.
+pub fn guess_from_podspec(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    if path.extension().and_then(|e| e.to_str()) == Some("json") {
+        guess_from_json_podspec(path, &contents)
+    } else {
+        Ok(guess_from_ruby_podspec(path, &contents))
+    }
+}
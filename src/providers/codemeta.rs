@@ -0,0 +1,153 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Values in codemeta can be given either as a single item or as a list.
+fn as_list(value: &Value) -> Vec<&Value> {
+    match value {
+        Value::Array(items) => items.iter().collect(),
+        Value::Null => vec![],
+        value => vec![value],
+    }
+}
+
+/// Extract a URL from a value that is either a plain string or a schema.org object.
+fn as_url(value: &Value) -> Option<&str> {
+    value
+        .as_str()
+        .or_else(|| value.get("url").and_then(Value::as_str))
+        .or_else(|| value.get("@id").and_then(Value::as_str))
+}
+
+fn codemeta_person(author: &Value) -> Option<Person> {
+    let name = match (
+        author.get("givenName").and_then(Value::as_str),
+        author.get("familyName").and_then(Value::as_str),
+    ) {
+        (Some(given), Some(family)) => Some(format!("{} {}", given, family)),
+        (given, family) => given
+            .or(family)
+            .or_else(|| author.get("name").and_then(Value::as_str))
+            .map(|s| s.to_string()),
+    };
+    name.as_ref()?;
+    Some(Person {
+        name,
+        email: author
+            .get("email")
+            .and_then(Value::as_str)
+            .map(|s| s.trim_start_matches("mailto:").to_string()),
+        url: author
+            .get("@id")
+            .or_else(|| author.get("url"))
+            .and_then(Value::as_str)
+            .map(|s| s.to_string()),
+    })
+}
+
+/// Guess upstream metadata from a codemeta.json file.
+///
+/// See https://codemeta.github.io/terms/ for the supported terms.
+pub fn guess_from_codemeta_json(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_json::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(name) = data.get("name").and_then(Value::as_str) {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+    }
+    if let Some(description) = data.get("description").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Description(description.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(version) = data.get("version").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(url) = data.get("url").and_then(as_url) {
+        push(UpstreamDatum::Homepage(url.to_string()), Certainty::Certain);
+    }
+    if let Some(url) = data.get("codeRepository").and_then(as_url) {
+        push(
+            UpstreamDatum::Repository(url.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(url) = data.get("issueTracker").and_then(as_url) {
+        push(
+            UpstreamDatum::BugDatabase(url.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(url) = data.get("readme").and_then(as_url) {
+        push(
+            UpstreamDatum::Documentation(url.to_string()),
+            Certainty::Likely,
+        );
+    }
+    for license in data.get("license").map(as_list).unwrap_or_default() {
+        if let Some(license) = as_url(license) {
+            // Licenses are usually given as SPDX URLs
+            let license = license
+                .strip_prefix("https://spdx.org/licenses/")
+                .or_else(|| license.strip_prefix("http://spdx.org/licenses/"))
+                .unwrap_or(license);
+            push(
+                UpstreamDatum::License(license.to_string()),
+                Certainty::Certain,
+            );
+        }
+    }
+    let authors = data
+        .get("author")
+        .map(as_list)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(codemeta_person)
+        .collect::<Vec<_>>();
+    if !authors.is_empty() {
+        push(UpstreamDatum::Author(authors), Certainty::Certain);
+    }
+    for funding in data.get("funding").map(as_list).unwrap_or_default() {
+        if let Some(url) = as_url(funding) {
+            push(UpstreamDatum::Funding(url.to_string()), Certainty::Certain);
+        }
+    }
+    match data.get("keywords") {
+        Some(Value::String(keywords)) => push(
+            UpstreamDatum::Keywords(keywords.split(',').map(|s| s.trim().to_string()).collect()),
+            Certainty::Certain,
+        ),
+        Some(Value::Array(keywords)) => push(
+            UpstreamDatum::Keywords(
+                keywords
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|s| s.to_string())
+                    .collect(),
+            ),
+            Certainty::Certain,
+        ),
+        _ => {}
+    }
+
+    Ok(results)
+}
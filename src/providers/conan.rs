@@ -0,0 +1,108 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the string literals assigned to a class attribute in a Python source file.
+///
+/// The value may be a single string, or a parenthesized or bracketed expression spanning
+/// several lines, e.g. a tuple of topics or implicitly concatenated strings.
+fn python_attribute_strings(contents: &str, attr: &str) -> Option<Vec<String>> {
+    let start = regex!(r"(?m)^[ \t]+(\w+)\s*=\s*")
+        .captures_iter(contents)
+        .find(|c| &c[1] == attr)
+        .map(|c| c.get(0).unwrap().end())?;
+    let rest = &contents[start..];
+    let value = if rest.starts_with(['(', '[']) {
+        let mut depth = 0;
+        let end = rest
+            .char_indices()
+            .find(|(_, c)| {
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth -= 1,
+                    _ => {}
+                }
+                depth == 0
+            })
+            .map(|(i, _)| i + 1)
+            .unwrap_or(rest.len());
+        &rest[..end]
+    } else {
+        rest.lines().next().unwrap_or_default()
+    };
+    Some(
+        regex!(r#""((?:[^"\\]|\\.)*)"|'((?:[^'\\]|\\.)*)'"#)
+            .captures_iter(value)
+            .map(|c| c.get(1).or_else(|| c.get(2)).unwrap().as_str().to_string())
+            .collect(),
+    )
+}
+
+/// Guess upstream metadata from a Conan conanfile.py recipe.
+///
+/// The recipe is not executed; only class attributes with literal string values are used.
+/// The `url` attribute refers to the repository of the recipe, which is not necessarily
+/// that of the upstream project. conanfile.txt files only list dependencies, so they
+/// have no metadata to extract.
+pub fn guess_from_conanfile(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |attr| {
+        python_attribute_strings(&contents, attr)
+            .filter(|values| !values.is_empty())
+            .map(|values| values.concat())
+    };
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name), Certainty::Likely);
+    }
+    if let Some(version) = get_str("version") {
+        push(UpstreamDatum::Version(version), Certainty::Likely);
+    }
+    if let Some(description) = get_str("description") {
+        push(UpstreamDatum::Summary(description), Certainty::Certain);
+    }
+    if let Some(homepage) = get_str("homepage") {
+        push(UpstreamDatum::Homepage(homepage), Certainty::Certain);
+    }
+    if let Some(url) = get_str("url") {
+        if !url.contains("conan-center-index") {
+            push(UpstreamDatum::Repository(url), Certainty::Possible);
+        }
+    }
+    if let Some(author) = get_str("author") {
+        push(
+            UpstreamDatum::Author(vec![Person::from(author.as_str())]),
+            Certainty::Likely,
+        );
+    }
+    if let Some(licenses) = python_attribute_strings(&contents, "license") {
+        if !licenses.is_empty() {
+            push(
+                UpstreamDatum::License(licenses.join(" OR ")),
+                Certainty::Certain,
+            );
+        }
+    }
+    if let Some(topics) = python_attribute_strings(&contents, "topics") {
+        if !topics.is_empty() {
+            push(UpstreamDatum::Keywords(topics), Certainty::Certain);
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,100 @@
+use lazy_regex::regex;
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+const UNEXPANDED: &str = "__unexpanded__";
+
+/// Expand the subset of Jinja that conda recipes commonly use.
+///
+/// Variables defined with `{% set name = "value" %}` are substituted in `{{ name }}`
+/// expressions; other statements are dropped.
+fn expand_jinja(contents: &str) -> String {
+    let mut variables = HashMap::new();
+    for captures in
+        regex!(r#"\{%-?\s*set\s+(\w+)\s*=\s*["']([^"']*)["']\s*-?%\}"#).captures_iter(contents)
+    {
+        variables.insert(captures[1].to_string(), captures[2].to_string());
+    }
+
+    let contents = regex!(r"\{%.*?%\}").replace_all(contents, "");
+    regex!(r"\{\{(.*?)\}\}")
+        .replace_all(&contents, |captures: &lazy_regex::Captures| {
+            let expression = captures[1].trim();
+            let (name, filter) = match expression.split_once('|') {
+                Some((name, filter)) => (name.trim(), Some(filter.trim())),
+                None => (expression, None),
+            };
+            match (variables.get(name), filter) {
+                (Some(value), None) => value.clone(),
+                (Some(value), Some("lower")) => value.to_lowercase(),
+                (Some(value), Some("upper")) => value.to_uppercase(),
+                // Anything else is replaced with a marker, so the YAML still parses
+                _ => UNEXPANDED.to_string(),
+            }
+        })
+        .into_owned()
+}
+
+/// Guess upstream metadata from a conda-build recipe (meta.yaml).
+pub fn guess_from_conda_meta(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = expand_jinja(&std::fs::read_to_string(path)?);
+    let data: Value =
+        serde_yaml::from_str(&contents).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |section: &str, key: &str| {
+        data.get(section)
+            .and_then(|s| s.get(key))
+            .and_then(Value::as_str)
+            .filter(|v| !v.contains(UNEXPANDED))
+            .map(|v| v.trim().to_string())
+    };
+
+    if let Some(name) = get_str("package", "name") {
+        push(UpstreamDatum::Name(name), Certainty::Likely);
+    }
+    if let Some(version) = get_str("package", "version") {
+        push(UpstreamDatum::Version(version), Certainty::Certain);
+    }
+    if let Some(home) = get_str("about", "home") {
+        push(UpstreamDatum::Homepage(home), Certainty::Certain);
+    }
+    if let Some(dev_url) = get_str("about", "dev_url") {
+        push(UpstreamDatum::Repository(dev_url), Certainty::Certain);
+    }
+    if let Some(doc_url) = get_str("about", "doc_url") {
+        push(UpstreamDatum::Documentation(doc_url), Certainty::Certain);
+    }
+    if let Some(license) = get_str("about", "license") {
+        push(UpstreamDatum::License(license), Certainty::Certain);
+    }
+    if let Some(summary) = get_str("about", "summary") {
+        push(UpstreamDatum::Summary(summary), Certainty::Certain);
+    }
+    if let Some(description) = get_str("about", "description") {
+        push(UpstreamDatum::Description(description), Certainty::Certain);
+    }
+    if let Some(git_url) = get_str("source", "git_url") {
+        push(UpstreamDatum::Repository(git_url), Certainty::Likely);
+    }
+    if let Some(url) = get_str("source", "url") {
+        push(UpstreamDatum::Download(url), Certainty::Possible);
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,138 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Fetch the DESCRIPTION fields of an R package from a JSON API, if the package exists.
+async fn load_description(url: &str) -> Result<Option<Value>, ProviderError> {
+    let url = url
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => Ok(Some(data)),
+        Err(HTTPJSONError::Error { status: 404, .. }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Look up an R package on CRAN, falling back to Bioconductor.
+///
+/// CRAN is queried through crandb, Bioconductor through its R-universe mirror; both
+/// return the fields from the package's DESCRIPTION file.
+pub async fn remote_cran_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let data = match load_description(&format!("https://crandb.r-pkg.org/{}", name)).await? {
+        Some(data) => data,
+        None => match load_description(&format!(
+            "https://bioc.r-universe.dev/api/packages/{}",
+            name
+        ))
+        .await?
+        {
+            Some(data) => data,
+            None => return Ok(vec![]),
+        },
+    };
+    Ok(parse_description(&data))
+}
+
+/// Extract metadata from the DESCRIPTION fields returned by crandb or R-universe.
+fn parse_description(data: &Value) -> Vec<UpstreamDatum> {
+    let get_str = |key| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_str("Package") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(title) = get_str("Title") {
+        datums.push(UpstreamDatum::Summary(title));
+    }
+    if let Some(version) = get_str("Version") {
+        datums.push(UpstreamDatum::Version(version));
+    }
+    if let Some(license) = get_str("License") {
+        datums.push(UpstreamDatum::License(license));
+    }
+    if let Some(bug_reports) = get_str("BugReports") {
+        datums.push(UpstreamDatum::BugDatabase(bug_reports));
+    }
+    // URL is a comma-separated list, optionally with labels in parentheses
+    let urls = get_str("URL").unwrap_or_default();
+    let mut homepage = None;
+    for url in urls
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|u| u.starts_with("http://") || u.starts_with("https://"))
+    {
+        if let Some(repository) = super::forge_repository_url(url) {
+            datums.push(UpstreamDatum::Repository(repository));
+        } else if homepage.is_none() {
+            homepage = Some(url.to_string());
+        }
+    }
+    if let Some(homepage) = homepage {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+
+    datums
+}
+
+pub struct Cran;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Cran {
+    fn name(&self) -> &'static str {
+        "CRAN"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Bug-Database", "Summary"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("CRAN", remote_cran_data(name).await)
+    }
+}
+
+impl super::Registry for Cran {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://cran.r-project.org/package={}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_description() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "Package": "dplyr",
+                "Title": "A Grammar of Data\n        Manipulation",
+                "Version": "1.1.4",
+                "License": "MIT + file LICENSE",
+                "URL": "https://dplyr.tidyverse.org, https://github.com/tidyverse/dplyr",
+                "BugReports": "https://github.com/tidyverse/dplyr/issues"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_description(&data),
+            vec![
+                UpstreamDatum::Name("dplyr".to_string()),
+                UpstreamDatum::Summary("A Grammar of Data Manipulation".to_string()),
+                UpstreamDatum::Version("1.1.4".to_string()),
+                UpstreamDatum::License("MIT + file LICENSE".to_string()),
+                UpstreamDatum::BugDatabase("https://github.com/tidyverse/dplyr/issues".to_string()),
+                UpstreamDatum::Repository("https://github.com/tidyverse/dplyr".to_string()),
+                UpstreamDatum::Homepage("https://dplyr.tidyverse.org".to_string()),
+            ]
+        );
+    }
+}
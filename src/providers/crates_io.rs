@@ -0,0 +1,141 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Look up a crate on crates.io.
+///
+/// Unlike the crates.io support in upstream_ontologist, this includes the documentation URL
+/// and the license of the most recent version.
+pub async fn remote_crate_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://crates.io/api/v1/crates/{}", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_crate_data(&data))
+}
+
+/// Extract metadata from a crates.io API response.
+fn parse_crate_data(data: &Value) -> Vec<UpstreamDatum> {
+    let krate = &data["crate"];
+    let get_str = |key| krate.get(key).and_then(Value::as_str).map(String::from);
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_str("name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(homepage) = get_str("homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(repository) = get_str("repository") {
+        datums.push(UpstreamDatum::Repository(repository));
+    }
+    if let Some(documentation) = get_str("documentation") {
+        datums.push(UpstreamDatum::Documentation(documentation));
+    }
+    if let Some(description) = get_str("description") {
+        datums.push(UpstreamDatum::Summary(description.trim().to_string()));
+    }
+    if let Some(version) = get_str("max_stable_version").or_else(|| get_str("newest_version")) {
+        datums.push(UpstreamDatum::Version(version));
+    }
+    // Versions are listed newest first
+    if let Some(license) = data["versions"]
+        .get(0)
+        .and_then(|v| v.get("license"))
+        .and_then(Value::as_str)
+    {
+        datums.push(UpstreamDatum::License(license.to_string()));
+    }
+
+    datums
+}
+
+pub struct CratesIo;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for CratesIo {
+    fn name(&self) -> &'static str {
+        "crates.io"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Documentation",
+            "Summary",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("crates.io", remote_crate_data(name).await)
+    }
+}
+
+impl super::Registry for CratesIo {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://crates.io/crates/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_crate_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "crate": {
+                    "name": "breezyshim",
+                    "homepage": "https://www.breezy-vcs.org/",
+                    "repository": "https://github.com/breezy-team/breezyshim",
+                    "documentation": "https://docs.rs/breezyshim",
+                    "description": "Rust shim around the Breezy Python API\n",
+                    "max_stable_version": "0.1.227",
+                    "newest_version": "0.2.0-rc1"
+                },
+                "versions": [
+                    {"num": "0.2.0-rc1", "license": "GPL-2.0+"},
+                    {"num": "0.1.227", "license": "GPL-2.0"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_crate_data(&data),
+            vec![
+                UpstreamDatum::Name("breezyshim".to_string()),
+                UpstreamDatum::Homepage("https://www.breezy-vcs.org/".to_string()),
+                UpstreamDatum::Repository("https://github.com/breezy-team/breezyshim".to_string()),
+                UpstreamDatum::Documentation("https://docs.rs/breezyshim".to_string()),
+                UpstreamDatum::Summary("Rust shim around the Breezy Python API".to_string()),
+                UpstreamDatum::Version("0.1.227".to_string()),
+                UpstreamDatum::License("GPL-2.0+".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_crate_data_missing_fields() {
+        let data: Value = serde_json::from_str(
+            r#"{"crate": {"name": "foo", "homepage": null, "newest_version": "0.1.0"}, "versions": []}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_crate_data(&data),
+            vec![
+                UpstreamDatum::Name("foo".to_string()),
+                UpstreamDatum::Version("0.1.0".to_string()),
+            ]
+        );
+    }
+}
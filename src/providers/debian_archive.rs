@@ -0,0 +1,209 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Hosts of Debian packaging repositories, rather than of upstream repositories.
+const PACKAGING_HOSTS: &[&str] = &[
+    "salsa.debian.org",
+    "anonscm.debian.org",
+    "alioth.debian.org",
+];
+
+async fn load_sources_json(path: &str) -> Result<Option<Value>, ProviderError> {
+    let url: url::Url = format!("https://sources.debian.org/{}", path)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) if data.get("error").is_some() => Ok(None),
+        Ok(data) => Ok(Some(data)),
+        Err(HTTPJSONError::Error { status: 404, .. }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn is_packaging_url(url: &str) -> bool {
+    url::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| PACKAGING_HOSTS.contains(&h)))
+        .unwrap_or(false)
+}
+
+/// Return the value of a field in the source paragraph of a debian/control file.
+fn source_field(control: &str, field: &str) -> Option<String> {
+    let mut lines = control
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .filter(|line| !line.starts_with('#'));
+    let value = lines.find_map(|line| {
+        let (name, value) = line.split_once(':')?;
+        if name.eq_ignore_ascii_case(field) {
+            Some(value.trim().to_string())
+        } else {
+            None
+        }
+    })?;
+    Some(value).filter(|v| !v.is_empty())
+}
+
+/// Look up the packaging of a project in Debian, using sources.debian.org.
+///
+/// The source package is assumed to be named after the project. Ubuntu syncs most of its
+/// packages from Debian, so this covers those too. Vcs fields that point at Debian's own
+/// packaging repositories are skipped, since they don't describe the upstream project.
+pub async fn guess_from_debian_archive(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let package = name.to_lowercase();
+    let versions = match load_sources_json(&format!("api/src/{}/", package)).await? {
+        Some(data) => data,
+        None => return Ok(vec![]),
+    };
+    let version = match latest_version(&versions) {
+        Some(version) => version.to_string(),
+        None => return Ok(vec![]),
+    };
+    let control_info =
+        match load_sources_json(&format!("api/src/{}/{}/debian/control/", package, version)).await?
+        {
+            Some(data) => data,
+            None => return Ok(vec![]),
+        };
+    let raw_url = match control_info["raw_url"].as_str() {
+        Some(raw_url) => raw_url,
+        None => return Ok(vec![]),
+    };
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(format!("https://sources.debian.org{}", raw_url))
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(ProviderError::Other(format!(
+            "HTTP error: {}",
+            response.status()
+        )));
+    }
+    let control = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    Ok(parse_control(&control))
+}
+
+/// Find the most recent version in a sources.debian.org package response.
+///
+/// Versions are listed newest first.
+fn latest_version(versions: &Value) -> Option<&str> {
+    versions["versions"]
+        .as_array()
+        .and_then(|versions| versions.first())
+        .and_then(|v| v["version"].as_str())
+}
+
+/// Extract upstream metadata from the source paragraph of a debian/control file.
+fn parse_control(control: &str) -> Vec<UpstreamDatum> {
+    let mut datums = Vec::new();
+    if let Some(homepage) = source_field(control, "Homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(vcs_git) = source_field(control, "Vcs-Git") {
+        // Strip any branch or subpath, e.g. "https://example.com/foo.git -b upstream"
+        let url = vcs_git.split_whitespace().next().unwrap_or_default();
+        if !is_packaging_url(url) {
+            datums.push(UpstreamDatum::Repository(url.to_string()));
+        }
+    }
+    if let Some(vcs_browser) = source_field(control, "Vcs-Browser") {
+        if !is_packaging_url(&vcs_browser) {
+            datums.push(UpstreamDatum::RepositoryBrowse(vcs_browser));
+        }
+    }
+
+    datums
+}
+
+pub struct DebianArchive;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for DebianArchive {
+    fn name(&self) -> &'static str {
+        "Debian"
+    }
+
+    // The source package may have the same name as an unrelated project
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Possible
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Repository-Browse"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the Debian archive", guess_from_debian_archive(name).await)
+    }
+}
+
+impl super::Registry for DebianArchive {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://sources.debian.org/src/{}/", name.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_version() {
+        let versions: Value = serde_json::from_str(
+            r#"{
+                "package": "dulwich",
+                "path": "dulwich",
+                "pathl": [["dulwich", "/src/dulwich/"]],
+                "suite": "",
+                "type": "package",
+                "versions": [
+                    {"area": "main", "suites": ["sid", "trixie"], "version": "0.22.1-1"},
+                    {"area": "main", "suites": ["bookworm"], "version": "0.21.2-1"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(latest_version(&versions), Some("0.22.1-1"));
+    }
+
+    #[test]
+    fn test_parse_control() {
+        let control = "\
+# Comments are ignored
+Source: dulwich
+Maintainer: Debian Python Team <team+python@tracker.debian.org>
+Homepage: https://www.dulwich.io/
+Vcs-Git: https://salsa.debian.org/python-team/packages/dulwich.git
+Vcs-Browser: https://github.com/jelmer/dulwich
+
+Package: python3-dulwich
+Homepage: https://example.com/
+";
+        assert_eq!(
+            parse_control(control),
+            vec![
+                UpstreamDatum::Homepage("https://www.dulwich.io/".to_string()),
+                UpstreamDatum::RepositoryBrowse("https://github.com/jelmer/dulwich".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_control_vcs_git_branch() {
+        let control = "Source: foo\nVcs-Git: https://github.com/example/foo.git -b upstream\n";
+        assert_eq!(
+            parse_control(control),
+            vec![UpstreamDatum::Repository(
+                "https://github.com/example/foo.git".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a freedesktop.org .desktop file.
+///
+/// There is no datum for icons, so the Icon key is not used.
+pub fn guess_from_desktop_file(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let mut in_desktop_entry = false;
+    for line in contents.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_desktop_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_desktop_entry || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = match line.split_once('=') {
+            Some((key, value)) => (key.trim(), value.trim().to_string()),
+            None => continue,
+        };
+        // Localized variants such as Name[de] are ignored
+        match key {
+            "Name" => push(UpstreamDatum::Name(value), Certainty::Likely),
+            "Comment" => push(UpstreamDatum::Summary(value), Certainty::Likely),
+            "URL" | "X-AppImage-Homepage" | "X-Homepage" => {
+                push(UpstreamDatum::Homepage(value), Certainty::Likely)
+            }
+            _ => {}
+        }
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from an AppImageBuilder.yml recipe.
+pub fn guess_from_appimage_builder_yml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let app_info = match data.get("AppDir").and_then(|d| d.get("app_info")) {
+        Some(app_info) => app_info,
+        None => return Ok(results),
+    };
+
+    if let Some(name) = app_info.get("name").and_then(Value::as_str) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Name(name.to_string()),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        });
+    }
+    // The version is frequently a placeholder filled in by CI
+    if let Some(version) = app_info
+        .get("version")
+        .and_then(Value::as_str)
+        .filter(|v| !v.contains('{') && !v.starts_with('$'))
+    {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Version(version.to_string()),
+            certainty: Some(Certainty::Possible),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,121 @@
+use lazy_regex::regex;
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Map an OCI image annotation to the datum it describes.
+fn oci_label_datum(key: &str, value: &str) -> Option<UpstreamDatum> {
+    let value = value.to_string();
+    match key.strip_prefix("org.opencontainers.image.")? {
+        "source" => Some(UpstreamDatum::Repository(value)),
+        "url" => Some(UpstreamDatum::Homepage(value)),
+        "documentation" => Some(UpstreamDatum::Documentation(value)),
+        "licenses" => Some(UpstreamDatum::License(value)),
+        _ => None,
+    }
+}
+
+fn push_labels<'a>(
+    results: &mut Vec<UpstreamDatumWithMetadata>,
+    path: &Path,
+    labels: impl Iterator<Item = (&'a str, &'a str)>,
+) {
+    for (key, value) in labels {
+        if let Some(datum) = oci_label_datum(key, value) {
+            // Labels describe the image, which usually but not always packages the project
+            results.push(UpstreamDatumWithMetadata {
+                datum,
+                certainty: Some(Certainty::Likely),
+                origin: Some(path.into()),
+            });
+        }
+    }
+}
+
+/// Guess upstream metadata from the OCI annotations in the LABEL instructions of a
+/// Dockerfile.
+pub fn guess_from_dockerfile(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    // Join continuation lines
+    let contents = regex!(r"\\[ \t]*\r?\n").replace_all(&contents, " ");
+
+    let mut results = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        let args = match line.split_once(char::is_whitespace) {
+            Some((instruction, args)) if instruction.eq_ignore_ascii_case("LABEL") => args,
+            _ => continue,
+        };
+        let pairs = regex!(r#"([\w.\-]+)=(?:"((?:[^"\\]|\\.)*)"|(\S+))"#)
+            .captures_iter(args)
+            .map(|c| {
+                (
+                    c.get(1).unwrap().as_str(),
+                    c.get(2).or_else(|| c.get(3)).unwrap().as_str(),
+                )
+            })
+            .collect::<Vec<_>>();
+        if pairs.is_empty() {
+            // Legacy form: LABEL key value
+            if let Some((key, value)) = args.split_once(char::is_whitespace) {
+                push_labels(
+                    &mut results,
+                    path,
+                    std::iter::once((key, value.trim().trim_matches('"'))),
+                );
+            }
+        } else {
+            push_labels(&mut results, path, pairs.into_iter());
+        }
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from the OCI annotations in the labels of a docker-compose
+/// file.
+pub fn guess_from_docker_compose(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let services = data.get("services").and_then(Value::as_mapping);
+    for service in services.into_iter().flat_map(|s| s.values()) {
+        for labels in service
+            .get("labels")
+            .into_iter()
+            .chain(service.get("build").and_then(|b| b.get("labels")))
+        {
+            // Labels are either a mapping or a list of key=value strings
+            match labels {
+                Value::Mapping(labels) => push_labels(
+                    &mut results,
+                    path,
+                    labels
+                        .iter()
+                        .filter_map(|(k, v)| Some((k.as_str()?, v.as_str()?))),
+                ),
+                Value::Sequence(labels) => push_labels(
+                    &mut results,
+                    path,
+                    labels
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .filter_map(|l| l.split_once('=')),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,285 @@
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, HTTPJSONError, Person, ProviderError, UpstreamDatum,
+    UpstreamDatumWithMetadata,
+};
+use xmltree::{Element, XMLNode};
+
+/// Guess upstream metadata from an MSBuild project file (*.csproj, Directory.Build.props).
+pub fn guess_from_csproj(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let root = Element::parse(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        })
+    };
+
+    let property_groups = root.children.iter().filter_map(|node| match node {
+        XMLNode::Element(element) if element.name == "PropertyGroup" => Some(element),
+        _ => None,
+    });
+
+    for group in property_groups {
+        for node in &group.children {
+            let element = match node {
+                XMLNode::Element(element) => element,
+                _ => continue,
+            };
+            let value = match element.get_text() {
+                Some(text) if !text.trim().is_empty() => text.trim().to_string(),
+                _ => continue,
+            };
+            match element.name.as_str() {
+                "PackageId" => push(UpstreamDatum::Name(value)),
+                "Version" | "PackageVersion" => push(UpstreamDatum::Version(value)),
+                "Description" => push(UpstreamDatum::Description(value)),
+                "PackageProjectUrl" => push(UpstreamDatum::Homepage(value)),
+                "RepositoryUrl" => push(UpstreamDatum::Repository(value)),
+                "PackageLicenseExpression" => push(UpstreamDatum::License(value)),
+                "PackageTags" => push(UpstreamDatum::Keywords(
+                    value
+                        .split(|c: char| c == ';' || c.is_whitespace())
+                        .filter(|s| !s.is_empty())
+                        .map(|s| s.to_string())
+                        .collect(),
+                )),
+                "Authors" => push(UpstreamDatum::Author(
+                    value
+                        .split([',', ';'])
+                        .map(|s| s.trim())
+                        .filter(|s| !s.is_empty())
+                        .map(Person::from)
+                        .collect(),
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Find the ID under which the package in a directory is published on NuGet.
+///
+/// The ID is taken from a .nuspec file, or from the PackageId of a project file. Directories
+/// with several candidates are ambiguous, so no ID is returned for them.
+pub fn package_id(path: &Path) -> Option<String> {
+    let mut ids = std::fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter_map(|p| {
+            let tag = match p.extension().and_then(|ext| ext.to_str()) {
+                Some("nuspec") => "id",
+                Some("csproj") => "PackageId",
+                _ => return None,
+            };
+            let root = Element::parse(std::fs::File::open(&p).ok()?).ok()?;
+            root.children
+                .iter()
+                .filter_map(|node| match node {
+                    XMLNode::Element(e) if e.name == "metadata" || e.name == "PropertyGroup" => {
+                        e.get_child(tag)
+                    }
+                    _ => None,
+                })
+                .find_map(|e| e.get_text())
+                .map(|id| id.trim().to_string())
+        });
+    let id = ids.next()?;
+    if ids.next().is_some() {
+        return None;
+    }
+    Some(id)
+}
+
+/// Look up a package on NuGet.
+///
+/// The nuspec of the latest release is fetched from the package content API, since it
+/// carries the repository metadata that the registration API leaves out.
+pub async fn remote_nuget_data(id: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let id = id.to_lowercase();
+    let url = format!("https://api.nuget.org/v3-flatcontainer/{}/index.json", id)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let index = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let version = match latest_version(&index) {
+        Some(version) => version,
+        None => return Ok(vec![]),
+    };
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(format!(
+            "https://api.nuget.org/v3-flatcontainer/{}/{}/{}.nuspec",
+            id, version, id
+        ))
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(ProviderError::Other(format!(
+            "HTTP error: {}",
+            response.status()
+        )));
+    }
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    parse_nuspec(&text)
+}
+
+/// Pick the version to describe from a NuGet package version index.
+///
+/// Versions are listed oldest first; the latest stable release is preferred.
+fn latest_version(index: &serde_json::Value) -> Option<&str> {
+    let versions = index["versions"]
+        .as_array()
+        .map(|versions| {
+            versions
+                .iter()
+                .filter_map(|v| v.as_str())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    versions
+        .iter()
+        .rev()
+        .find(|v| !v.contains('-'))
+        .or_else(|| versions.last())
+        .copied()
+}
+
+/// Extract metadata from a .nuspec file.
+fn parse_nuspec(text: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let root =
+        Element::parse(text.as_bytes()).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+    let metadata = match root.get_child("metadata") {
+        Some(metadata) => metadata,
+        None => return Ok(vec![]),
+    };
+    let get_text = |tag| {
+        metadata
+            .get_child(tag)
+            .and_then(|e| e.get_text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(id) = get_text("id") {
+        datums.push(UpstreamDatum::Name(id));
+    }
+    if let Some(version) = get_text("version") {
+        datums.push(UpstreamDatum::Version(version));
+    }
+    if let Some(description) = get_text("description") {
+        datums.push(UpstreamDatum::Description(description));
+    }
+    if let Some(project_url) = get_text("projectUrl") {
+        datums.push(UpstreamDatum::Homepage(project_url));
+    }
+    if let Some(license) = metadata
+        .get_child("license")
+        .filter(|e| e.attributes.get("type").map(String::as_str) == Some("expression"))
+        .and_then(|e| e.get_text())
+    {
+        datums.push(UpstreamDatum::License(license.trim().to_string()));
+    }
+    if let Some(repository) = metadata
+        .get_child("repository")
+        .and_then(|e| e.attributes.get("url"))
+    {
+        datums.push(UpstreamDatum::Repository(repository.to_string()));
+    }
+
+    Ok(datums)
+}
+
+pub struct NuGet;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for NuGet {
+    fn name(&self) -> &'static str {
+        "NuGet"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Description", "License"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("NuGet", remote_nuget_data(name).await)
+    }
+}
+
+impl super::Registry for NuGet {
+    fn page_url(&self, id: &str) -> String {
+        format!("https://www.nuget.org/packages/{}", id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_version() {
+        let index: serde_json::Value =
+            serde_json::from_str(r#"{"versions": ["12.0.3", "13.0.1", "13.0.4-beta1"]}"#).unwrap();
+        assert_eq!(latest_version(&index), Some("13.0.1"));
+        let index: serde_json::Value =
+            serde_json::from_str(r#"{"versions": ["1.0.0-rc1"]}"#).unwrap();
+        assert_eq!(latest_version(&index), Some("1.0.0-rc1"));
+        let index: serde_json::Value = serde_json::from_str(r#"{"versions": []}"#).unwrap();
+        assert_eq!(latest_version(&index), None);
+    }
+
+    #[test]
+    fn test_parse_nuspec() {
+        let text = r#"<?xml version="1.0" encoding="utf-8"?>
+<package xmlns="http://schemas.microsoft.com/packaging/2013/05/nuspec.xsd">
+  <metadata minClientVersion="2.12">
+    <id>Newtonsoft.Json</id>
+    <version>13.0.3</version>
+    <authors>James Newton-King</authors>
+    <license type="expression">MIT</license>
+    <projectUrl>https://www.newtonsoft.com/json</projectUrl>
+    <description>Json.NET is a popular high-performance JSON framework for .NET</description>
+    <repository type="git" url="https://github.com/JamesNK/Newtonsoft.Json" commit="0a2e291c0d9c0c7675d445703e51750363a549ef" />
+  </metadata>
+</package>"#;
+        assert_eq!(
+            parse_nuspec(text).unwrap(),
+            vec![
+                UpstreamDatum::Name("Newtonsoft.Json".to_string()),
+                UpstreamDatum::Version("13.0.3".to_string()),
+                UpstreamDatum::Description(
+                    "Json.NET is a popular high-performance JSON framework for .NET".to_string()
+                ),
+                UpstreamDatum::Homepage("https://www.newtonsoft.com/json".to_string()),
+                UpstreamDatum::License("MIT".to_string()),
+                UpstreamDatum::Repository("https://github.com/JamesNK/Newtonsoft.Json".to_string()),
+            ]
+        );
+    }
+}
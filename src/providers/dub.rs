@@ -0,0 +1,107 @@
+use lazy_regex::regex;
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Convert the top-level settings of a dub package description into datums.
+fn dub_datums(
+    path: &Path,
+    settings: impl Iterator<Item = (String, Vec<String>)>,
+) -> Vec<UpstreamDatumWithMetadata> {
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    for (key, mut values) in settings {
+        if values.is_empty() {
+            continue;
+        }
+        match key.as_str() {
+            "name" => {
+                let name = values.remove(0);
+                push(UpstreamDatum::Name(name.clone()), Certainty::Certain);
+                // Packages are published to code.dlang.org under their name
+                push(
+                    UpstreamDatum::Registry(vec![("DUB".to_string(), name)]),
+                    Certainty::Possible,
+                );
+            }
+            "description" => push(UpstreamDatum::Summary(values.remove(0)), Certainty::Certain),
+            "homepage" => push(
+                UpstreamDatum::Homepage(values.remove(0)),
+                Certainty::Certain,
+            ),
+            "license" => push(UpstreamDatum::License(values.remove(0)), Certainty::Certain),
+            "copyright" => push(
+                UpstreamDatum::Copyright(values.remove(0)),
+                Certainty::Certain,
+            ),
+            "authors" => push(
+                UpstreamDatum::Author(values.iter().map(|a| Person::from(a.as_str())).collect()),
+                Certainty::Certain,
+            ),
+            _ => {}
+        }
+    }
+
+    results
+}
+
+/// Guess upstream metadata from a dub.json package description.
+pub fn guess_from_dub_json(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: serde_json::Map<String, Value> =
+        serde_json::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let settings = data.into_iter().map(|(key, value)| {
+        let values = match value {
+            Value::String(s) => vec![s],
+            Value::Array(items) => items
+                .into_iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                .collect(),
+            _ => vec![],
+        };
+        (key, values)
+    });
+    Ok(dub_datums(path, settings))
+}
+
+/// Guess upstream metadata from a dub.sdl package description.
+///
+/// Settings inside blocks (configurations, subpackages) are skipped.
+pub fn guess_from_dub_sdl(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut depth = 0;
+    let mut settings = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if depth == 0 && !line.starts_with("//") {
+            if let Some(key) = line.split_whitespace().next() {
+                let values = regex!(r#""((?:[^"\\]|\\.)*)""#)
+                    .captures_iter(line)
+                    .map(|c| c[1].replace("\\\"", "\""))
+                    .collect::<Vec<_>>();
+                settings.push((key.to_string(), values));
+            }
+        }
+        depth += line.matches('{').count();
+        depth -= line.matches('}').count().min(depth);
+    }
+
+    Ok(dub_datums(path, settings.into_iter()))
+}
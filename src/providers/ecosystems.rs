@@ -0,0 +1,128 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Return the value of a field if all packages that set it agree on it.
+fn agreed_value(packages: &[Value], key: &str) -> Option<String> {
+    let mut values = packages
+        .iter()
+        .filter_map(|p| p[key].as_str())
+        .map(str::trim)
+        .filter(|v| !v.is_empty());
+    let first = values.next()?;
+    if values.all(|v| v == first) {
+        Some(first.to_string())
+    } else {
+        log::debug!("Packages disagree on {}, ignoring", key);
+        None
+    }
+}
+
+/// Look up the packages built from a repository on ecosyste.ms.
+///
+/// The same repository may be published to several registries; only values that all
+/// of them agree on are returned.
+pub async fn guess_from_ecosystems(
+    repository_url: &str,
+) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let mut url = url::Url::parse("https://packages.ecosyste.ms/api/v1/packages/lookup").unwrap();
+    url.query_pairs_mut()
+        .append_pair("repository_url", repository_url.trim_end_matches(".git"));
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_packages(&data))
+}
+
+/// Extract the values that all packages in an ecosyste.ms lookup response agree on.
+fn parse_packages(data: &Value) -> Vec<UpstreamDatum> {
+    let packages = match data.as_array() {
+        Some(packages) => packages,
+        None => return vec![],
+    };
+
+    let mut datums = Vec::new();
+    if let Some(homepage) = agreed_value(packages, "homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(description) = agreed_value(packages, "description") {
+        datums.push(UpstreamDatum::Summary(description));
+    }
+    if let Some(licenses) = agreed_value(packages, "licenses") {
+        datums.push(UpstreamDatum::License(licenses));
+    }
+    if let Some(version) = agreed_value(packages, "latest_release_number") {
+        datums.push(UpstreamDatum::Version(
+            version.trim_start_matches('v').to_string(),
+        ));
+    }
+
+    datums
+}
+
+pub struct Ecosystems;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Ecosystems {
+    fn name(&self) -> &'static str {
+        "ecosyste.ms"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Likely
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Summary", "License", "Version"]
+    }
+
+    async fn guess_metadata(&self, repository: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("ecosyste.ms", guess_from_ecosystems(repository).await)
+    }
+}
+
+impl super::Registry for Ecosystems {
+    fn page_url(&self, _repo_url: &str) -> String {
+        "https://packages.ecosyste.ms/".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_packages() {
+        let data: Value = serde_json::from_str(
+            r#"[
+                {
+                    "name": "serde",
+                    "ecosystem": "cargo",
+                    "homepage": "https://serde.rs",
+                    "description": "A generic serialization/deserialization framework",
+                    "licenses": "MIT OR Apache-2.0",
+                    "latest_release_number": "1.0.215",
+                    "repository_url": "https://github.com/serde-rs/serde"
+                },
+                {
+                    "name": "serde",
+                    "ecosystem": "debian",
+                    "homepage": "https://serde.rs",
+                    "description": "Generic serialization framework for Rust",
+                    "licenses": "",
+                    "latest_release_number": "v1.0.215",
+                    "repository_url": "https://github.com/serde-rs/serde"
+                }
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_packages(&data),
+            vec![
+                UpstreamDatum::Homepage("https://serde.rs".to_string()),
+                UpstreamDatum::License("MIT OR Apache-2.0".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use lazy_regex::{regex, regex_captures};
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Resolve an Elixir expression that is either a string literal or a module attribute.
+fn resolve<'a>(value: &'a str, attributes: &HashMap<&str, &'a str>) -> Option<&'a str> {
+    let value = value.trim();
+    if let Some(name) = value.strip_prefix('@') {
+        attributes.get(name).copied()
+    } else {
+        value.strip_prefix('"')?.strip_suffix('"')
+    }
+}
+
+/// Guess upstream metadata from a mix.exs file.
+///
+/// The keyword lists returned by `project/0` and `package/0` are matched textually rather
+/// than evaluated; string literals and module attributes are supported as values.
+pub fn guess_from_mix_exs(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let attributes = regex!(r#"(?m)^\s*@(\w+)\s+"([^"]*)""#)
+        .captures_iter(&contents)
+        .map(|c| (c.get(1).unwrap().as_str(), c.get(2).unwrap().as_str()))
+        .collect::<HashMap<_, _>>();
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some((_, app)) = regex_captures!(r"\bapp:\s*:(\w+)", &contents) {
+        push(UpstreamDatum::Name(app.to_string()), Certainty::Likely);
+        // Only projects with package metadata are published to Hex
+        if regex!(r"\bpackage:").is_match(&contents) {
+            push(
+                UpstreamDatum::Registry(vec![("Hex".to_string(), app.to_string())]),
+                Certainty::Possible,
+            );
+        }
+    }
+
+    for captures in regex!(r#"\b(version|description|source_url|homepage_url):\s*("[^"]*"|@\w+)"#)
+        .captures_iter(&contents)
+    {
+        let value = match resolve(captures.get(2).unwrap().as_str(), &attributes) {
+            Some(value) => value.to_string(),
+            None => continue,
+        };
+        match &captures[1] {
+            "version" => push(UpstreamDatum::Version(value), Certainty::Certain),
+            "description" => push(UpstreamDatum::Summary(value), Certainty::Certain),
+            "source_url" => push(UpstreamDatum::Repository(value), Certainty::Certain),
+            "homepage_url" => push(UpstreamDatum::Homepage(value), Certainty::Certain),
+            _ => unreachable!(),
+        }
+    }
+
+    if let Some((_, licenses)) = regex_captures!(r"\blicenses:\s*\[([^\]]*)\]", &contents) {
+        let licenses = regex!(r#""([^"]+)""#)
+            .captures_iter(licenses)
+            .map(|c| c[1].to_string())
+            .collect::<Vec<_>>();
+        if !licenses.is_empty() {
+            push(
+                UpstreamDatum::License(licenses.join(" OR ")),
+                Certainty::Certain,
+            );
+        }
+    }
+
+    if let Some((_, links)) = regex_captures!(r"\blinks:\s*%\{([^}]*)\}", &contents) {
+        for captures in regex!(r#""([^"]+)"\s*=>\s*("[^"]*"|@\w+)"#).captures_iter(links) {
+            let url = match resolve(captures.get(2).unwrap().as_str(), &attributes) {
+                Some(url) => url.to_string(),
+                None => continue,
+            };
+            let datum = match super::hex_link_datum(&captures[1], url) {
+                Some(datum) => datum,
+                None => continue,
+            };
+            push(datum, Certainty::Likely);
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,99 @@
+use lazy_regex::{regex, regex_captures};
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from an OTP application resource file (src/*.app.src).
+///
+/// rebar3_hex takes the package metadata (licenses, links) from this file.
+pub fn guess_from_app_src(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some((_, name)) = regex_captures!(r"\{\s*application\s*,\s*'?(\w+)'?", &contents) {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+        // Only applications with package metadata are published to Hex
+        if regex!(r"\{\s*licenses\s*,").is_match(&contents) {
+            push(
+                UpstreamDatum::Registry(vec![("Hex".to_string(), name.to_string())]),
+                Certainty::Possible,
+            );
+        }
+    }
+    if let Some((_, description)) =
+        regex_captures!(r#"\{\s*description\s*,\s*"([^"]*)""#, &contents)
+    {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Certain,
+        );
+    }
+    // The version may also be an atom such as git, which is computed at build time
+    if let Some((_, version)) = regex_captures!(r#"\{\s*vsn\s*,\s*"([^"]*)""#, &contents) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some((_, licenses)) = regex_captures!(r"\{\s*licenses\s*,\s*\[([^\]]*)\]", &contents) {
+        let licenses = regex!(r#""([^"]+)""#)
+            .captures_iter(licenses)
+            .map(|c| c[1].to_string())
+            .collect::<Vec<_>>();
+        if !licenses.is_empty() {
+            push(
+                UpstreamDatum::License(licenses.join(" OR ")),
+                Certainty::Certain,
+            );
+        }
+    }
+    if let Some((_, links)) = regex_captures!(r"\{\s*links\s*,\s*\[([^\]]*)\]", &contents) {
+        for captures in regex!(r#"\{\s*"([^"]+)"\s*,\s*"([^"]+)"\s*\}"#).captures_iter(links) {
+            if let Some(datum) = super::hex_link_datum(&captures[1], captures[2].to_string()) {
+                push(datum, Certainty::Likely);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from a rebar.config file.
+///
+/// Only the ex_doc settings contain upstream metadata; the rest is build configuration.
+pub fn guess_from_rebar_config(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    for captures in
+        regex!(r#"\{\s*(source_url|homepage_url)\s*,\s*(?:<<)?"([^"]+)""#).captures_iter(&contents)
+    {
+        let url = captures[2].to_string();
+        let datum = match &captures[1] {
+            "source_url" => UpstreamDatum::Repository(url),
+            _ => UpstreamDatum::Homepage(url),
+        };
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
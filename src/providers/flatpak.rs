@@ -0,0 +1,93 @@
+use lazy_regex::regex_is_match;
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Check whether a filename looks like a Flatpak manifest, i.e. a reverse-DNS application
+/// ID such as org.example.App.json.
+pub fn is_flatpak_manifest_name(filename: &str) -> bool {
+    regex_is_match!(
+        r"^[a-z][a-z0-9-]*(\.[A-Za-z0-9_-]+){2,}\.(json|ya?ml)$",
+        filename
+    )
+}
+
+/// Derive a homepage from the application ID, e.g. io.github.jelmer.Example.
+fn homepage_from_app_id(app_id: &str) -> Option<String> {
+    let parts = app_id.split('.').collect::<Vec<_>>();
+    match parts.as_slice() {
+        ["io", "github", user, project, ..] => {
+            Some(format!("https://github.com/{}/{}", user, project))
+        }
+        ["io", "gitlab", user, project, ..] => {
+            Some(format!("https://gitlab.com/{}/{}", user, project))
+        }
+        [tld, domain, _, ..] => Some(format!("https://{}.{}/", domain, tld)),
+        _ => None,
+    }
+}
+
+/// Guess upstream metadata from a Flatpak manifest.
+///
+/// By convention the last module is the application itself; the others are bundled
+/// dependencies.
+pub fn guess_from_flatpak_manifest(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    // JSON manifests are valid YAML too
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let app_id = match data
+        .get("app-id")
+        .or_else(|| data.get("id"))
+        .and_then(Value::as_str)
+    {
+        Some(app_id) => app_id,
+        // Not a Flatpak manifest after all
+        None => return Ok(results),
+    };
+
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(homepage) = homepage_from_app_id(app_id) {
+        push(UpstreamDatum::Homepage(homepage), Certainty::Possible);
+    }
+
+    let primary_module = data
+        .get("modules")
+        .and_then(Value::as_sequence)
+        .and_then(|modules| modules.iter().rev().find(|m| m.is_mapping()));
+    if let Some(module) = primary_module {
+        if let Some(name) = module.get("name").and_then(Value::as_str) {
+            push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+        }
+        let git_sources = module
+            .get("sources")
+            .and_then(Value::as_sequence)
+            .into_iter()
+            .flatten()
+            .filter(|source| source.get("type").and_then(Value::as_str) == Some("git"));
+        for source in git_sources {
+            if let Some(url) = source.get("url").and_then(Value::as_str) {
+                push(
+                    UpstreamDatum::Repository(url.to_string()),
+                    Certainty::Likely,
+                );
+            }
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,70 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// URL templates for the platforms supported in FUNDING.yml.
+///
+/// See https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/displaying-a-sponsor-button-in-your-repository
+const PLATFORMS: &[(&str, &str)] = &[
+    ("github", "https://github.com/sponsors/"),
+    ("patreon", "https://www.patreon.com/"),
+    ("open_collective", "https://opencollective.com/"),
+    ("ko_fi", "https://ko-fi.com/"),
+    ("tidelift", "https://tidelift.com/funding/github/"),
+    (
+        "community_bridge",
+        "https://funding.communitybridge.org/projects/",
+    ),
+    ("liberapay", "https://liberapay.com/"),
+    ("issuehunt", "https://issuehunt.io/r/"),
+    (
+        "lfx_crowdfunding",
+        "https://crowdfunding.lfx.linuxfoundation.org/projects/",
+    ),
+    ("polar", "https://polar.sh/"),
+    ("buy_me_a_coffee", "https://buymeacoffee.com/"),
+    ("thanks_dev", "https://thanks.dev/"),
+];
+
+/// Guess upstream metadata from a GitHub FUNDING.yml file.
+///
+/// Sponsorship platforms are reported as Funding; custom links, which are usually plain
+/// donation pages, as Donation.
+pub fn guess_from_funding_yml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        })
+    };
+
+    for (platform, prefix) in PLATFORMS {
+        for account in data
+            .get(platform)
+            .map(super::yaml_strings)
+            .unwrap_or_default()
+        {
+            push(UpstreamDatum::Funding(format!("{}{}", prefix, account)));
+        }
+    }
+    for url in data
+        .get("custom")
+        .map(super::yaml_strings)
+        .unwrap_or_default()
+    {
+        push(UpstreamDatum::Donation(url.to_string()));
+    }
+
+    Ok(results)
+}
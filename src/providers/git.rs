@@ -0,0 +1,86 @@
+use lazy_regex::regex_captures;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess the upstream repository from the remotes in a git config file.
+///
+/// `path` may also point at a checkout, in which case its .git/config is used. RCP-style
+/// remotes (e.g. git@github.com:jelmer/example) are converted to URLs, as the scan does.
+pub fn guess_from_git_config(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let path = if path.is_dir() {
+        path.join(".git/config")
+    } else {
+        path.to_path_buf()
+    };
+    let mut results = upstream_ontologist::providers::git::guess_from_git_config(&path, settings)?;
+
+    for result in results.iter_mut() {
+        if let UpstreamDatum::Repository(url) = &mut result.datum {
+            if !url.contains("://") {
+                if let Some(fixed) = upstream_ontologist::vcs::fixup_rcp_style_git_repo_url(url) {
+                    *url = fixed.to_string();
+                }
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+/// Extract the version from a tag name, e.g. "v1.2.3", "example-1.2.3" or "release_1_2_3".
+fn version_from_tag(tag: &str) -> Option<String> {
+    let (_, version) = regex_captures!(
+        r"(?:^|[^\d])(\d+(?:[._]\d+)*(?:[-.~]?(?:alpha|beta|rc|a|b)\d*)?)$",
+        tag
+    )?;
+    if version.contains('.') {
+        Some(version.to_string())
+    } else {
+        Some(version.replace('_', "."))
+    }
+}
+
+/// Guess the version from the most recent tag that is reachable from HEAD.
+///
+/// `path` may be a checkout or its .git directory. If there are commits after the tag, its
+/// version is still used, as the most recent release.
+pub fn guess_version_from_git(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let workdir = if path.file_name().is_some_and(|n| n == ".git") {
+        path.parent().unwrap_or(path)
+    } else {
+        path
+    };
+    let output = match std::process::Command::new("git")
+        .arg("-C")
+        .arg(workdir)
+        .args(["describe", "--tags", "--long"])
+        .stderr(std::process::Stdio::null())
+        .output()
+    {
+        Ok(output) if output.status.success() => output,
+        // Not a git checkout, no tags, or git is not installed
+        _ => return Ok(vec![]),
+    };
+    let description = String::from_utf8_lossy(&output.stdout);
+    let tag = match regex_captures!(r"^(.*)-\d+-g[0-9a-f]+$", description.trim()) {
+        Some((_, tag)) => tag,
+        None => return Ok(vec![]),
+    };
+
+    Ok(version_from_tag(tag)
+        .map(|version| UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Version(version),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        })
+        .into_iter()
+        .collect())
+}
@@ -0,0 +1,195 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Hosts that are known to run Gitea or one of its forks, such as Forgejo.
+const KNOWN_GITEA_SITES: &[&str] = &["codeberg.org", "gitea.com", "git.disroot.org"];
+
+/// Split a repository URL into the host, owner and repository name.
+pub fn gitea_repo(url: &str) -> Option<(String, String, String)> {
+    let url = url::Url::parse(url.split_whitespace().next()?).ok()?;
+    let host = url.host_str()?.to_string();
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    Some((
+        host,
+        owner.to_string(),
+        repo.strip_suffix(".git").unwrap_or(repo).to_string(),
+    ))
+}
+
+/// Check whether a host runs Gitea, probing its version endpoint if it isn't well known
+/// and network access is allowed.
+pub async fn is_gitea_site(hostname: &str, net_access: Option<bool>) -> bool {
+    if KNOWN_GITEA_SITES.contains(&hostname) {
+        return true;
+    }
+    if !net_access.unwrap_or(false) {
+        return false;
+    }
+    let url = match format!("https://{}/api/v1/version", hostname).parse() {
+        Ok(url) => url,
+        Err(_) => return false,
+    };
+    match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data.get("version").and_then(Value::as_str).is_some(),
+        Err(_) => false,
+    }
+}
+
+/// Check whether a repository URL is hosted on a Gitea instance.
+pub async fn is_gitea_repository(url: &str) -> bool {
+    match gitea_repo(url) {
+        Some((host, _, _)) => is_gitea_site(&host, Some(true)).await,
+        None => false,
+    }
+}
+
+/// Query the Gitea API for the metadata of a repository.
+///
+/// This works for Codeberg and other Forgejo instances as well. Archived repositories no
+/// longer accept issues, so no Bug-Database is returned for them.
+pub async fn guess_from_gitea_api(repo_url: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let (host, owner, repo) = match gitea_repo(repo_url) {
+        Some(parts) => parts,
+        None => {
+            return Err(ProviderError::Other(format!(
+                "Not a Gitea repository: {}",
+                repo_url
+            )))
+        }
+    };
+    let url = format!("https://{}/api/v1/repos/{}/{}", host, owner, repo)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_gitea_repository(&data))
+}
+
+/// Extract metadata from a Gitea repository API response.
+fn parse_gitea_repository(data: &Value) -> Vec<UpstreamDatum> {
+    let get_str = |key| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(description) = get_str("description") {
+        datums.push(UpstreamDatum::Summary(description.to_string()));
+    }
+    if let Some(website) = get_str("website") {
+        datums.push(UpstreamDatum::Homepage(website.to_string()));
+    }
+    if let Some(html_url) = get_str("html_url") {
+        if let Some(branch) = get_str("default_branch") {
+            datums.push(UpstreamDatum::RepositoryBrowse(format!(
+                "{}/src/branch/{}",
+                html_url, branch
+            )));
+        }
+        let archived = data.get("archived").and_then(Value::as_bool) == Some(true);
+        if archived {
+            log::info!("Gitea repository {} is archived", html_url);
+        } else if data.get("has_issues").and_then(Value::as_bool) == Some(true) {
+            // Repositories can also use an external tracker instead of the built-in one
+            match data["external_tracker"]
+                .get("external_tracker_url")
+                .and_then(Value::as_str)
+            {
+                Some(tracker) => datums.push(UpstreamDatum::BugDatabase(tracker.to_string())),
+                None => datums.push(UpstreamDatum::BugDatabase(format!("{}/issues", html_url))),
+            }
+        }
+    }
+
+    datums
+}
+
+pub struct GiteaApi;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for GiteaApi {
+    fn name(&self) -> &'static str {
+        "Gitea"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Summary", "Homepage", "Bug-Database", "Repository-Browse"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the Gitea API", guess_from_gitea_api(name).await)
+    }
+}
+
+impl super::Registry for GiteaApi {
+    fn page_url(&self, repo_url: &str) -> String {
+        repo_url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gitea_repository() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "full_name": "forgejo/forgejo",
+                "description": "Beyond coding. We forge.",
+                "website": "https://forgejo.org",
+                "html_url": "https://codeberg.org/forgejo/forgejo",
+                "default_branch": "forgejo",
+                "archived": false,
+                "has_issues": true,
+                "external_tracker": null
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_gitea_repository(&data),
+            vec![
+                UpstreamDatum::Summary("Beyond coding. We forge.".to_string()),
+                UpstreamDatum::Homepage("https://forgejo.org".to_string()),
+                UpstreamDatum::RepositoryBrowse(
+                    "https://codeberg.org/forgejo/forgejo/src/branch/forgejo".to_string()
+                ),
+                UpstreamDatum::BugDatabase(
+                    "https://codeberg.org/forgejo/forgejo/issues".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_gitea_repository_external_tracker() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "html_url": "https://gitea.com/example/project",
+                "has_issues": true,
+                "external_tracker": {
+                    "external_tracker_url": "https://bugs.example.com/",
+                    "external_tracker_format": "https://bugs.example.com/{index}"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_gitea_repository(&data),
+            vec![UpstreamDatum::BugDatabase(
+                "https://bugs.example.com/".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,341 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, HTTPJSONError, Person, ProviderError, UpstreamDatum,
+    UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from the contact links in .github/ISSUE_TEMPLATE/config.yml.
+///
+/// Links are classified by their name and description.
+pub fn guess_from_issue_template_config(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let links = data.get("contact_links").and_then(Value::as_sequence);
+    for link in links.into_iter().flatten() {
+        let url = match link.get("url").and_then(Value::as_str) {
+            Some(url) => url.to_string(),
+            None => continue,
+        };
+        let text = ["name", "about"]
+            .iter()
+            .filter_map(|key| link.get(key).and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let (datum, certainty) = if text.contains("security") || text.contains("vulnerab") {
+            (UpstreamDatum::SecurityContact(url), Certainty::Likely)
+        } else if text.contains("bug") || text.contains("issue") {
+            (UpstreamDatum::BugSubmit(url), Certainty::Likely)
+        } else if text.contains("mailing list") {
+            (UpstreamDatum::MailingList(url), Certainty::Likely)
+        } else {
+            // Discussions, chat, forums and the like
+            (UpstreamDatum::Contact(url), Certainty::Possible)
+        };
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from a CODEOWNERS file.
+///
+/// The owners of the catch-all `*` pattern are considered maintainers of the project.
+pub fn guess_from_codeowners(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    // Later rules take precedence, so use the last catch-all rule
+    let owners = contents
+        .lines()
+        .map(|line| line.split('#').next().unwrap().split_whitespace())
+        .filter_map(|mut words| match words.next() {
+            Some("*") => Some(words.collect::<Vec<_>>()),
+            _ => None,
+        })
+        .next_back()
+        .unwrap_or_default();
+
+    Ok(owners
+        .into_iter()
+        .filter_map(|owner| {
+            let person = if let Some(user) = owner.strip_prefix('@') {
+                // Teams can't be maintainers
+                if user.contains('/') {
+                    return None;
+                }
+                Person {
+                    name: Some(user.to_string()),
+                    url: Some(format!("https://github.com/{}", user)),
+                    ..Default::default()
+                }
+            } else if owner.contains('@') {
+                Person {
+                    email: Some(owner.to_string()),
+                    ..Default::default()
+                }
+            } else {
+                return None;
+            };
+            Some(UpstreamDatumWithMetadata {
+                datum: UpstreamDatum::Maintainer(person),
+                certainty: Some(Certainty::Possible),
+                origin: Some(path.into()),
+            })
+        })
+        .collect())
+}
+
+/// Guess upstream metadata from the repository section of a .github/settings.yml file, as
+/// used by the Probot settings app.
+pub fn guess_from_github_settings(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let repository = match data.get("repository") {
+        Some(repository) => repository,
+        None => return Ok(vec![]),
+    };
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| repository.get(key).and_then(Value::as_str);
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+    }
+    if let Some(description) = get_str("description") {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(homepage) = get_str("homepage") {
+        push(
+            UpstreamDatum::Homepage(homepage.to_string()),
+            Certainty::Certain,
+        );
+    }
+    // Topics are a comma-separated string
+    if let Some(topics) = get_str("topics") {
+        let topics = topics
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !topics.is_empty() {
+            push(UpstreamDatum::Keywords(topics), Certainty::Certain);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Extract the owner and repository name from a GitHub repository URL.
+pub fn github_repo(url: &str) -> Option<(String, String)> {
+    let url = url::Url::parse(url.split_whitespace().next()?).ok()?;
+    if url.host_str() != Some("github.com") {
+        return None;
+    }
+    let mut segments = url.path_segments()?.filter(|s| !s.is_empty());
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    Some((
+        owner.to_string(),
+        repo.strip_suffix(".git").unwrap_or(repo).to_string(),
+    ))
+}
+
+/// Query the GitHub API for the metadata of a repository.
+///
+/// Archived repositories no longer accept issues, so no Bug-Database is returned for them.
+/// upstream_ontologist has no field for the archived status itself, so it isn't returned.
+pub async fn guess_from_github_api(repo_url: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let (owner, repo) = match github_repo(repo_url) {
+        Some(parts) => parts,
+        None => {
+            return Err(ProviderError::Other(format!(
+                "Not a GitHub repository: {}",
+                repo_url
+            )))
+        }
+    };
+    let url = format!("https://api.github.com/repos/{}/{}", owner, repo)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_github_repository(&data))
+}
+
+/// Extract metadata from a GitHub repository API response.
+fn parse_github_repository(data: &serde_json::Value) -> Vec<UpstreamDatum> {
+    let get_str = |key| {
+        data.get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(description) = get_str("description") {
+        datums.push(UpstreamDatum::Summary(description.to_string()));
+    }
+    if let Some(homepage) = get_str("homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage.to_string()));
+    }
+    // GitHub reports NOASSERTION for licenses it can't identify
+    if let Some(license) = data["license"]
+        .get("spdx_id")
+        .and_then(serde_json::Value::as_str)
+        .filter(|id| *id != "NOASSERTION")
+    {
+        datums.push(UpstreamDatum::License(license.to_string()));
+    }
+    let topics = data
+        .get("topics")
+        .and_then(serde_json::Value::as_array)
+        .map(|topics| {
+            topics
+                .iter()
+                .filter_map(|t| t.as_str().map(String::from))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !topics.is_empty() {
+        datums.push(UpstreamDatum::Keywords(topics));
+    }
+    // The repository may have been renamed or transferred, so use the canonical URL
+    if let Some(html_url) = get_str("html_url") {
+        if let Some(branch) = get_str("default_branch") {
+            datums.push(UpstreamDatum::RepositoryBrowse(format!(
+                "{}/tree/{}",
+                html_url, branch
+            )));
+        }
+        let archived = data.get("archived").and_then(serde_json::Value::as_bool) == Some(true);
+        if archived {
+            log::info!("GitHub repository {} is archived", html_url);
+        } else if data.get("has_issues").and_then(serde_json::Value::as_bool) == Some(true) {
+            datums.push(UpstreamDatum::BugDatabase(format!("{}/issues", html_url)));
+        }
+    }
+
+    datums
+}
+
+pub struct GitHubApi;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for GitHubApi {
+    fn name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Summary",
+            "Homepage",
+            "License",
+            "Keywords",
+            "Bug-Database",
+            "Repository-Browse",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the GitHub API", guess_from_github_api(name).await)
+    }
+}
+
+impl super::Registry for GitHubApi {
+    fn page_url(&self, repo_url: &str) -> String {
+        repo_url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_github_repository() {
+        let data: serde_json::Value = serde_json::from_str(
+            r#"{
+                "full_name": "jelmer/dulwich",
+                "html_url": "https://github.com/jelmer/dulwich",
+                "description": "Pure-Python Git implementation",
+                "homepage": "https://www.dulwich.io/",
+                "default_branch": "master",
+                "has_issues": true,
+                "archived": false,
+                "topics": ["git", "python"],
+                "license": {"key": "other", "spdx_id": "NOASSERTION"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_github_repository(&data),
+            vec![
+                UpstreamDatum::Summary("Pure-Python Git implementation".to_string()),
+                UpstreamDatum::Homepage("https://www.dulwich.io/".to_string()),
+                UpstreamDatum::Keywords(vec!["git".to_string(), "python".to_string()]),
+                UpstreamDatum::RepositoryBrowse(
+                    "https://github.com/jelmer/dulwich/tree/master".to_string()
+                ),
+                UpstreamDatum::BugDatabase("https://github.com/jelmer/dulwich/issues".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_github_repository_archived() {
+        let data: serde_json::Value = serde_json::from_str(
+            r#"{
+                "html_url": "https://github.com/example/old",
+                "description": "",
+                "homepage": null,
+                "has_issues": true,
+                "archived": true,
+                "license": {"key": "mit", "spdx_id": "MIT"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_github_repository(&data),
+            vec![UpstreamDatum::License("MIT".to_string())]
+        );
+    }
+}
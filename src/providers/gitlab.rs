@@ -0,0 +1,181 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Split a GitLab repository URL into the host and the full project path.
+///
+/// Project paths may include subgroups; anything after a `/-/` separator refers to a page
+/// within the project.
+pub fn gitlab_project(url: &str) -> Option<(String, String)> {
+    let url = url::Url::parse(url.split_whitespace().next()?).ok()?;
+    let host = url.host_str()?.to_string();
+    let path = url.path().trim_matches('/');
+    let path = path.split("/-/").next()?;
+    let path = path.strip_suffix(".git").unwrap_or(path);
+    if !path.contains('/') {
+        return None;
+    }
+    Some((host, path.to_string()))
+}
+
+/// Check whether a repository URL is hosted on GitLab, probing the host if necessary.
+pub async fn is_gitlab_repository(url: &str) -> bool {
+    match gitlab_project(url) {
+        Some((host, _)) => upstream_ontologist::vcs::is_gitlab_site(&host, Some(true)).await,
+        None => false,
+    }
+}
+
+/// Query the GitLab projects API for the metadata of a repository.
+///
+/// This works for gitlab.com as well as self-hosted instances. Archived projects no longer
+/// accept issues, so no Bug-Database is returned for them.
+pub async fn guess_from_gitlab_api(repo_url: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let (host, project) = match gitlab_project(repo_url) {
+        Some(parts) => parts,
+        None => {
+            return Err(ProviderError::Other(format!(
+                "Not a GitLab repository: {}",
+                repo_url
+            )))
+        }
+    };
+    let mut url = url::Url::parse(&format!("https://{}/api/v4/projects", host))
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    // The project path is passed as a single, escaped path segment
+    url.path_segments_mut().unwrap().push(&project);
+    url.query_pairs_mut().append_pair("license", "true");
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_gitlab_project(&data))
+}
+
+/// Extract metadata from a GitLab project API response.
+fn parse_gitlab_project(data: &Value) -> Vec<UpstreamDatum> {
+    let get_str = |key| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(description) = get_str("description") {
+        datums.push(UpstreamDatum::Summary(description.to_string()));
+    }
+    if let Some(license) = data["license"].get("name").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::License(license.to_string()));
+    }
+    // Older GitLab versions only provide tag_list
+    let topics = data
+        .get("topics")
+        .or_else(|| data.get("tag_list"))
+        .and_then(Value::as_array)
+        .map(|topics| {
+            topics
+                .iter()
+                .filter_map(|t| t.as_str().map(String::from))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !topics.is_empty() {
+        datums.push(UpstreamDatum::Keywords(topics));
+    }
+    if let Some(readme_url) = get_str("readme_url") {
+        datums.push(UpstreamDatum::RepositoryBrowse(
+            readme_url
+                .rsplit_once("/-/blob/")
+                .map(|(base, rest)| match rest.split_once('/') {
+                    Some((branch, _)) => format!("{}/-/tree/{}", base, branch),
+                    None => base.to_string(),
+                })
+                .unwrap_or_else(|| readme_url.to_string()),
+        ));
+    }
+    if let Some(web_url) = get_str("web_url") {
+        let archived = data.get("archived").and_then(Value::as_bool) == Some(true);
+        if archived {
+            log::info!("GitLab project {} is archived", web_url);
+        } else if data.get("issues_enabled").and_then(Value::as_bool) == Some(true) {
+            datums.push(UpstreamDatum::BugDatabase(format!("{}/-/issues", web_url)));
+        }
+    }
+
+    datums
+}
+
+pub struct GitLabApi;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for GitLabApi {
+    fn name(&self) -> &'static str {
+        "GitLab"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Summary",
+            "License",
+            "Keywords",
+            "Bug-Database",
+            "Repository-Browse",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the GitLab API", guess_from_gitlab_api(name).await)
+    }
+}
+
+impl super::Registry for GitLabApi {
+    fn page_url(&self, repo_url: &str) -> String {
+        repo_url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_gitlab_project() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "id": 278964,
+                "description": "GitLab is an open source end-to-end software development platform.",
+                "path_with_namespace": "gitlab-org/gitlab",
+                "web_url": "https://gitlab.com/gitlab-org/gitlab",
+                "readme_url": "https://gitlab.com/gitlab-org/gitlab/-/blob/master/README.md",
+                "tag_list": ["ruby"],
+                "topics": ["ruby", "devops"],
+                "archived": false,
+                "issues_enabled": true,
+                "license": {"key": "other", "name": "Other"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_gitlab_project(&data),
+            vec![
+                UpstreamDatum::Summary(
+                    "GitLab is an open source end-to-end software development platform."
+                        .to_string()
+                ),
+                UpstreamDatum::License("Other".to_string()),
+                UpstreamDatum::Keywords(vec!["ruby".to_string(), "devops".to_string()]),
+                UpstreamDatum::RepositoryBrowse(
+                    "https://gitlab.com/gitlab-org/gitlab/-/tree/master".to_string()
+                ),
+                UpstreamDatum::BugDatabase(
+                    "https://gitlab.com/gitlab-org/gitlab/-/issues".to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use lazy_regex::regex_captures;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a .gitmodules file.
+///
+/// Submodules are usually dependencies, so this only produces a result when there is a
+/// single submodule, as is the case for repositories that wrap an upstream project (e.g.
+/// packaging repositories). Relative submodule URLs are ignored.
+pub fn guess_from_gitmodules(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut submodules: Vec<(Option<String>, Option<String>)> = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            submodules.push((None, None));
+        } else if let Some((_, key, value)) = regex_captures!(r"^(\w+)\s*=\s*(.*)$", line) {
+            if let Some(submodule) = submodules.last_mut() {
+                match key {
+                    "path" => submodule.0 = Some(value.to_string()),
+                    "url" => submodule.1 = Some(value.to_string()),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let (subpath, url) = match submodules.as_slice() {
+        [(subpath, Some(url))] => (subpath, url),
+        _ => return Ok(vec![]),
+    };
+    if url.starts_with("./") || url.starts_with("../") {
+        return Ok(vec![]);
+    }
+
+    let certainty = match subpath.as_deref() {
+        Some("upstream") | Some("src") => Certainty::Likely,
+        _ => Certainty::Possible,
+    };
+    Ok(vec![UpstreamDatumWithMetadata {
+        datum: UpstreamDatum::Repository(url.to_string()),
+        certainty: Some(certainty),
+        origin: Some(path.into()),
+    }])
+}
@@ -0,0 +1,129 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the body of the first `name { ... }` block.
+fn find_block<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    let re = regex::Regex::new(&format!(r"\b{}\s*\{{", regex::escape(name))).unwrap();
+    let start = re.find(contents)?.end();
+    let mut depth = 1;
+    for (i, c) in contents[start..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&contents[start..start + i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Remove nested blocks, leaving only the statements directly in `contents`.
+fn strip_nested_blocks(contents: &str) -> String {
+    let mut depth = 0;
+    contents
+        .chars()
+        .filter(|c| {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => return depth == 0,
+            }
+            false
+        })
+        .collect()
+}
+
+/// Find a property assignment in the Groovy (`url 'x'`, `url = 'x'`) or Kotlin
+/// (`url = "x"`, `url.set("x")`) DSL.
+fn find_property(contents: &str, name: &str) -> Option<String> {
+    let re = regex::Regex::new(&format!(
+        r#"\b{}\s*(?:=\s*|\.set\(\s*|\s+)["']([^"']+)["']"#,
+        regex::escape(name)
+    ))
+    .unwrap();
+    re.captures(contents).map(|c| c[1].to_string())
+}
+
+/// Guess upstream metadata from the publishing block in build.gradle(.kts).
+pub fn guess_from_build_gradle(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let pom = match find_block(&contents, "pom") {
+        Some(pom) => pom,
+        None => return Ok(results),
+    };
+    let mut push = |datum| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        })
+    };
+
+    let toplevel = strip_nested_blocks(pom);
+    if let Some(name) = find_property(&toplevel, "name") {
+        push(UpstreamDatum::Name(name));
+    }
+    if let Some(description) = find_property(&toplevel, "description") {
+        push(UpstreamDatum::Summary(description));
+    }
+    if let Some(url) = find_property(&toplevel, "url") {
+        push(UpstreamDatum::Homepage(url));
+    }
+    if let Some(license) = find_block(pom, "license").and_then(|l| find_property(l, "name")) {
+        push(UpstreamDatum::License(license));
+    }
+    if let Some(url) = find_block(pom, "scm").and_then(|s| find_property(s, "url")) {
+        push(UpstreamDatum::Repository(url));
+    }
+    if let Some(url) = find_block(pom, "issueManagement").and_then(|i| find_property(i, "url")) {
+        push(UpstreamDatum::BugDatabase(url));
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from gradle.properties.
+///
+/// The POM_* properties are used by the widely used gradle-maven-publish-plugin.
+pub fn guess_from_gradle_properties(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    for captures in regex!(r"(?m)^\s*(\w+)\s*[=:]\s*(.*?)\s*$").captures_iter(&contents) {
+        let value = captures[2].to_string();
+        let (datum, certainty) = match &captures[1] {
+            "POM_NAME" => (UpstreamDatum::Name(value), Certainty::Likely),
+            "POM_DESCRIPTION" => (UpstreamDatum::Summary(value), Certainty::Certain),
+            "VERSION_NAME" => (UpstreamDatum::Version(value), Certainty::Likely),
+            "POM_URL" => (UpstreamDatum::Homepage(value), Certainty::Certain),
+            "POM_SCM_URL" => (UpstreamDatum::Repository(value), Certainty::Certain),
+            "POM_LICENSE_NAME" | "POM_LICENCE_NAME" | "POM_LICENSE" => {
+                (UpstreamDatum::License(value), Certainty::Certain)
+            }
+            "POM_ISSUE_URL" => (UpstreamDatum::BugDatabase(value), Certainty::Certain),
+            _ => continue,
+        };
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
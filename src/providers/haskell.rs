@@ -0,0 +1,105 @@
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a .cabal file.
+///
+/// Unlike the upstream_ontologist guesser, this records the file as origin and
+/// also emits the Haskell-Package datum for the package name.
+pub fn guess_from_cabal(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let mut results =
+        upstream_ontologist::providers::haskell::guess_from_cabal(path, settings.trust_package)?;
+
+    for datum in results.iter_mut() {
+        datum.origin.get_or_insert_with(|| path.into());
+    }
+
+    let package_names = results
+        .iter()
+        .filter_map(|d| match &d.datum {
+            UpstreamDatum::Name(name) => Some(name.clone()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    for name in package_names {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::HaskellPackage(name),
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Look up a package on Hackage.
+///
+/// This fetches the .cabal file of the latest release, which carries the same metadata
+/// as a local checkout.
+pub async fn remote_hackage_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!(
+        "https://hackage.haskell.org/package/{}/{}.cabal",
+        name, name
+    );
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+
+    match response.status() {
+        reqwest::StatusCode::NOT_FOUND => {
+            return Ok(vec![]);
+        }
+        status if !status.is_success() => {
+            return Err(ProviderError::Other(format!("HTTP error: {}", status)));
+        }
+        _ => {}
+    }
+
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let results = upstream_ontologist::providers::haskell::guess_from_cabal_lines(
+        text.lines().map(String::from),
+    )?;
+
+    Ok(results.into_iter().map(|datum| datum.datum).collect())
+}
+
+pub struct Hackage;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Hackage {
+    fn name(&self) -> &'static str {
+        "Hackage"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Bug-Database", "Repository", "Summary"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("Hackage", remote_hackage_data(name).await)
+    }
+}
+
+impl super::Registry for Hackage {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://hackage.haskell.org/package/{}", name)
+    }
+}
@@ -0,0 +1,131 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Helm Chart.yaml file.
+///
+/// A chart packages an application for Kubernetes, so the version used is appVersion rather
+/// than the version of the chart. The chart icon has no corresponding field.
+pub fn guess_from_chart_yaml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+    }
+    if let Some(version) = get_str("appVersion") {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(description) = get_str("description") {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(home) = get_str("home") {
+        push(
+            UpstreamDatum::Homepage(home.to_string()),
+            Certainty::Certain,
+        );
+    }
+    for source in data
+        .get("sources")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+    {
+        push(
+            UpstreamDatum::Repository(source.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(keywords) = data.get("keywords").and_then(Value::as_sequence) {
+        let keywords = keywords
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !keywords.is_empty() {
+            push(UpstreamDatum::Keywords(keywords), Certainty::Certain);
+        }
+    }
+    for maintainer in data
+        .get("maintainers")
+        .and_then(Value::as_sequence)
+        .into_iter()
+        .flatten()
+    {
+        let get = |key| {
+            maintainer
+                .get(key)
+                .and_then(Value::as_str)
+                .map(String::from)
+        };
+        push(
+            UpstreamDatum::Maintainer(Person {
+                name: get("name"),
+                email: get("email"),
+                url: get("url"),
+            }),
+            Certainty::Likely,
+        );
+    }
+
+    // Artifact Hub annotations; their values are embedded YAML documents
+    let annotation = |key| {
+        data.get("annotations")
+            .and_then(|a| a.get(key))
+            .and_then(Value::as_str)
+    };
+    if let Some(license) = annotation("artifacthub.io/license") {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(links) = annotation("artifacthub.io/links")
+        .and_then(|links| serde_yaml::from_str::<Vec<Value>>(links).ok())
+    {
+        for link in links {
+            let (name, url) = match (
+                link.get("name").and_then(Value::as_str),
+                link.get("url").and_then(Value::as_str),
+            ) {
+                (Some(name), Some(url)) => (name, url.to_string()),
+                _ => continue,
+            };
+            let datum = match name.to_lowercase().as_str() {
+                "source" | "repository" => UpstreamDatum::Repository(url),
+                "homepage" | "website" => UpstreamDatum::Homepage(url),
+                "documentation" | "docs" => UpstreamDatum::Documentation(url),
+                "changelog" => UpstreamDatum::Changelog(url),
+                "issues" | "bug tracker" => UpstreamDatum::BugDatabase(url),
+                _ => continue,
+            };
+            push(datum, Certainty::Likely);
+        }
+    }
+
+    Ok(results)
+}
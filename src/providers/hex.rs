@@ -0,0 +1,135 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Look up a package on hex.pm, the registry for Elixir and Erlang packages.
+pub async fn remote_hex_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://hex.pm/api/packages/{}", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_hex_data(&data))
+}
+
+/// Extract metadata from a hex.pm package API response.
+fn parse_hex_data(data: &Value) -> Vec<UpstreamDatum> {
+    let meta = &data["meta"];
+
+    let mut datums = Vec::new();
+    if let Some(name) = data.get("name").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::Name(name.to_string()));
+    }
+    if let Some(version) = data
+        .get("latest_stable_version")
+        .or_else(|| data.get("latest_version"))
+        .and_then(Value::as_str)
+    {
+        datums.push(UpstreamDatum::Version(version.to_string()));
+    }
+    if let Some(description) = meta.get("description").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::Summary(description.trim().to_string()));
+    }
+    let licenses = meta
+        .get("licenses")
+        .and_then(Value::as_array)
+        .map(|licenses| {
+            licenses
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" OR ")));
+    }
+    if let Some(links) = meta.get("links").and_then(Value::as_object) {
+        for (label, url) in links {
+            if let Some(datum) = url
+                .as_str()
+                .and_then(|url| super::hex_link_datum(label, url.to_string()))
+            {
+                datums.push(datum);
+            }
+        }
+    }
+    if let Some(docs) = data.get("docs_html_url").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::APIDocumentation(docs.to_string()));
+    }
+
+    datums
+}
+
+pub struct Hex;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Hex {
+    fn name(&self) -> &'static str {
+        "Hex"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Documentation",
+            "Changelog",
+            "Summary",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("hex.pm", remote_hex_data(name).await)
+    }
+}
+
+impl super::Registry for Hex {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://hex.pm/packages/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "name": "jason",
+                "latest_version": "1.5.0-alpha.2",
+                "latest_stable_version": "1.4.4",
+                "meta": {
+                    "description": "A blazing fast JSON parser and generator in pure Elixir.\n",
+                    "licenses": ["Apache-2.0"],
+                    "links": {"GitHub": "https://github.com/michalmuskala/jason"},
+                    "maintainers": []
+                },
+                "docs_html_url": "https://hexdocs.pm/jason/",
+                "html_url": "https://hex.pm/packages/jason"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_hex_data(&data),
+            vec![
+                UpstreamDatum::Name("jason".to_string()),
+                UpstreamDatum::Version("1.4.4".to_string()),
+                UpstreamDatum::Summary(
+                    "A blazing fast JSON parser and generator in pure Elixir.".to_string()
+                ),
+                UpstreamDatum::License("Apache-2.0".to_string()),
+                UpstreamDatum::Repository("https://github.com/michalmuskala/jason".to_string()),
+                UpstreamDatum::APIDocumentation("https://hexdocs.pm/jason/".to_string()),
+            ]
+        );
+    }
+}
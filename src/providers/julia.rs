@@ -0,0 +1,154 @@
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Julia Project.toml file.
+///
+/// Only files that declare a package uuid are considered, since environments use the same
+/// file name without describing a package.
+pub fn guess_from_project_toml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    let data: toml::Table =
+        toml::from_str(&contents).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    if !data.contains_key("uuid") {
+        return Ok(vec![]);
+    }
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(name) = data.get("name").and_then(|v| v.as_str()) {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+        push(
+            UpstreamDatum::Registry(vec![("Julia".to_string(), name.to_string())]),
+            Certainty::Likely,
+        );
+    }
+    if let Some(version) = data.get("version").and_then(|v| v.as_str()) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(authors) = data.get("authors").and_then(|v| v.as_array()) {
+        let authors = authors
+            .iter()
+            .filter_map(|a| a.as_str())
+            .map(Person::from)
+            .collect::<Vec<_>>();
+        if !authors.is_empty() {
+            push(UpstreamDatum::Author(authors), Certainty::Certain);
+        }
+    }
+
+    Ok(results)
+}
+
+/// Look up the repository of a package in the Julia General registry.
+pub async fn guess_from_julia_general(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let first = match name.chars().next() {
+        Some(c) => c.to_ascii_uppercase(),
+        None => return Ok(vec![]),
+    };
+    let url = format!(
+        "https://raw.githubusercontent.com/JuliaRegistries/General/master/{}/{}/Package.toml",
+        first, name
+    );
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+
+    match response.status() {
+        reqwest::StatusCode::NOT_FOUND => {
+            return Ok(vec![]);
+        }
+        status if !status.is_success() => {
+            return Err(ProviderError::Other(format!("HTTP error: {}", status)));
+        }
+        _ => {}
+    }
+
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    parse_package_toml(&text)
+}
+
+/// Extract metadata from a package's Package.toml in the General registry.
+fn parse_package_toml(text: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let data: toml::Table =
+        toml::from_str(text).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    Ok(data
+        .get("repo")
+        .and_then(|v| v.as_str())
+        .map(|repo| vec![UpstreamDatum::Repository(repo.to_string())])
+        .unwrap_or_default())
+}
+
+pub struct JuliaGeneral;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for JuliaGeneral {
+    fn name(&self) -> &'static str {
+        "Julia"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Repository"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error(
+            "the Julia General registry",
+            guess_from_julia_general(name).await,
+        )
+    }
+}
+
+impl super::Registry for JuliaGeneral {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://juliahub.com/ui/Packages/General/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_package_toml() {
+        let text = r#"name = "JSON"
+uuid = "682c06a0-de6a-54ab-a142-c8b1cf79cde6"
+repo = "https://github.com/JuliaIO/JSON.jl.git"
+"#;
+        assert_eq!(
+            parse_package_toml(text).unwrap(),
+            vec![UpstreamDatum::Repository(
+                "https://github.com/JuliaIO/JSON.jl.git".to_string()
+            )]
+        );
+    }
+}
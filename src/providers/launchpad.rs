@@ -0,0 +1,201 @@
+use serde_json::Value;
+use upstream_ontologist::{HTTPJSONError, ProviderError, UpstreamDatum};
+
+const API_ROOT: &str = "https://api.launchpad.net/devel";
+
+/// Fetch a Launchpad API resource, returning None if it doesn't exist.
+async fn load_resource(url: &str) -> Result<Option<Value>, ProviderError> {
+    let url = url
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => Ok(Some(data)),
+        Err(HTTPJSONError::Error { status: 404, .. }) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Follow a link field in a Launchpad API resource; unset links are null.
+async fn follow_link(resource: &Value, key: &str) -> Result<Option<Value>, ProviderError> {
+    match resource.get(key).and_then(Value::as_str) {
+        Some(link) => load_resource(link).await,
+        None => Ok(None),
+    }
+}
+
+/// Read a string field of a resource; unset fields are null or empty.
+fn get_str(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+/// Extract metadata from a Launchpad project resource.
+///
+/// Bug trackers other than Launchpad's own and repositories are separate resources, so
+/// they aren't included.
+fn parse_project(project: &Value) -> Vec<UpstreamDatum> {
+    let mut datums = Vec::new();
+    if let Some(name) = get_str(project, "display_name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(summary) = get_str(project, "summary") {
+        datums.push(UpstreamDatum::Summary(summary));
+    }
+    if let Some(homepage) = get_str(project, "homepage_url") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(wiki) = get_str(project, "wiki_url") {
+        datums.push(UpstreamDatum::Wiki(wiki));
+    }
+    if let Some(download) = get_str(project, "download_url") {
+        datums.push(UpstreamDatum::Download(download));
+    }
+    if let Some(sf_project) = get_str(project, "sourceforge_project") {
+        datums.push(UpstreamDatum::SourceForgeProject(sf_project));
+    }
+    if get_str(project, "bug_tracking_usage").as_deref() == Some("Launchpad") {
+        if let Some(name) = get_str(project, "name") {
+            datums.push(UpstreamDatum::BugDatabase(format!(
+                "https://bugs.launchpad.net/{}",
+                name
+            )));
+        }
+    }
+    datums
+}
+
+/// Query Launchpad for the upstream project of a source package.
+///
+/// The source package is looked up in `suite` of `distribution` (Ubuntu by default); when
+/// no suite is given, the development series is used. Packages that aren't linked to an
+/// upstream project on Launchpad yield no data.
+pub async fn guess_from_launchpad(
+    package: &str,
+    distribution: Option<&str>,
+    suite: Option<&str>,
+) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let distribution = distribution.unwrap_or("ubuntu");
+    let suite = match (suite, distribution) {
+        (Some(suite), _) => suite.to_string(),
+        (None, "debian") => "sid".to_string(),
+        (None, _) => {
+            let distro = match load_resource(&format!("{}/{}", API_ROOT, distribution)).await? {
+                Some(distro) => distro,
+                None => return Ok(vec![]),
+            };
+            match distro
+                .get("current_series_link")
+                .and_then(Value::as_str)
+                .and_then(|link| link.rsplit('/').next())
+            {
+                Some(series) => series.to_string(),
+                None => return Ok(vec![]),
+            }
+        }
+    };
+
+    let sourcepackage = match load_resource(&format!(
+        "{}/{}/{}/+source/{}",
+        API_ROOT, distribution, suite, package
+    ))
+    .await?
+    {
+        Some(sourcepackage) => sourcepackage,
+        None => return Ok(vec![]),
+    };
+    let productseries = match follow_link(&sourcepackage, "productseries_link").await? {
+        Some(productseries) => productseries,
+        None => return Ok(vec![]),
+    };
+    let project = match follow_link(&productseries, "project_link").await? {
+        Some(project) => project,
+        None => return Ok(vec![]),
+    };
+    let mut datums = parse_project(&project);
+    let lp_name = get_str(&project, "name");
+
+    // Projects either use Launchpad's own bug tracker, or link to an external one
+    if get_str(&project, "bug_tracking_usage").as_deref() != Some("Launchpad") {
+        if let Some(bug_tracker) = follow_link(&project, "bug_tracker_link").await? {
+            if let Some(base_url) = get_str(&bug_tracker, "base_url") {
+                datums.push(UpstreamDatum::BugDatabase(base_url));
+            }
+        }
+    }
+
+    match (get_str(&project, "vcs").as_deref(), lp_name.as_deref()) {
+        (Some("Git"), Some(name)) => {
+            let repository =
+                load_resource(&format!("{}/+git?ws.op=getByPath&path={}", API_ROOT, name)).await?;
+            if let Some(repository) = repository.filter(|r| !r.is_null()) {
+                // Imported repositories are mirrors; prefer the original location
+                if let Some(code_import) = follow_link(&repository, "code_import_link").await? {
+                    if let Some(url) = get_str(&code_import, "url") {
+                        datums.push(UpstreamDatum::Repository(url));
+                    }
+                } else {
+                    if let Some(url) = get_str(&repository, "git_https_url") {
+                        datums.push(UpstreamDatum::Repository(url));
+                    }
+                    if let Some(url) = get_str(&repository, "web_link") {
+                        datums.push(UpstreamDatum::RepositoryBrowse(url));
+                    }
+                }
+            }
+        }
+        (Some("Bazaar"), _) => {
+            if let Some(branch) = follow_link(&productseries, "branch_link").await? {
+                if let Some(url) = get_str(&branch, "bzr_identity") {
+                    datums.push(UpstreamDatum::Repository(url));
+                }
+                if let Some(url) = get_str(&branch, "web_link") {
+                    datums.push(UpstreamDatum::RepositoryBrowse(url));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    Ok(datums)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project() {
+        let project: Value = serde_json::from_str(
+            r#"{
+                "self_link": "https://api.launchpad.net/devel/bzr",
+                "name": "bzr",
+                "display_name": "Bazaar",
+                "summary": "Friendly powerful distributed version control system",
+                "homepage_url": "http://bazaar.canonical.com/",
+                "wiki_url": "http://wiki.bazaar.canonical.com/",
+                "download_url": null,
+                "sourceforge_project": "",
+                "bug_tracking_usage": "Launchpad",
+                "bug_tracker_link": null,
+                "vcs": "Bazaar"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_project(&project),
+            vec![
+                UpstreamDatum::Name("Bazaar".to_string()),
+                UpstreamDatum::Summary(
+                    "Friendly powerful distributed version control system".to_string()
+                ),
+                UpstreamDatum::Homepage("http://bazaar.canonical.com/".to_string()),
+                UpstreamDatum::Wiki("http://wiki.bazaar.canonical.com/".to_string()),
+                UpstreamDatum::BugDatabase("https://bugs.launchpad.net/bzr".to_string()),
+            ]
+        );
+    }
+}
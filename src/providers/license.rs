@@ -0,0 +1,111 @@
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Phrases that identify common license texts, as SPDX identifier and the phrases that
+/// must all occur in the (normalized) text.
+///
+/// More specific licenses come first; e.g. the ISC license grants permissions similar to
+/// the MIT license. The GNU licenses mention each other in their bodies (the GPLv3 refers
+/// to both the Affero and the Lesser GPL), so they are matched on their title lines.
+const LICENSE_PHRASES: &[(&str, &[&str])] = &[
+    (
+        "AGPL-3.0",
+        &["gnu affero general public license version 3, 19 november 2007"],
+    ),
+    (
+        "LGPL-3.0",
+        &["gnu lesser general public license version 3, 29 june 2007"],
+    ),
+    (
+        "LGPL-2.1",
+        &["gnu lesser general public license version 2.1, february 1999"],
+    ),
+    (
+        "GPL-3.0",
+        &["gnu general public license version 3, 29 june 2007"],
+    ),
+    (
+        "GPL-2.0",
+        &["gnu general public license version 2, june 1991"],
+    ),
+    (
+        "Apache-2.0",
+        &["apache license", "version 2.0, january 2004"],
+    ),
+    ("MPL-2.0", &["mozilla public license version 2.0"]),
+    ("EPL-2.0", &["eclipse public license - v 2.0"]),
+    ("BSL-1.0", &["boost software license - version 1.0"]),
+    ("Artistic-2.0", &["the artistic license 2.0"]),
+    ("CC0-1.0", &["cc0 1.0 universal"]),
+    (
+        "Unlicense",
+        &["this is free and unencumbered software released into the public domain"],
+    ),
+    (
+        "ISC",
+        &[
+            "permission to use, copy, modify, and",
+            "distribute this software for any purpose with or without fee is hereby granted",
+        ],
+    ),
+    (
+        "MIT",
+        &[
+            "permission is hereby granted, free of charge, to any person obtaining a copy",
+            "the above copyright notice and this permission notice shall be included",
+        ],
+    ),
+    (
+        "BSD-3-Clause",
+        &[
+            "redistribution and use in source and binary forms",
+            "neither the name of",
+        ],
+    ),
+    (
+        "BSD-2-Clause",
+        &["redistribution and use in source and binary forms"],
+    ),
+    (
+        "Zlib",
+        &[
+            "this software is provided 'as-is', without any express or implied warranty",
+            "altered source versions must be plainly marked",
+        ],
+    ),
+];
+
+/// Identify the license of a license text, returning its SPDX identifier.
+///
+/// The GPL family texts don't say whether later versions are allowed, so these are
+/// returned without the -only or -or-later suffix.
+pub fn detect_license(text: &str) -> Option<&'static str> {
+    let text = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    LICENSE_PHRASES
+        .iter()
+        .find(|(_, phrases)| phrases.iter().all(|phrase| text.contains(phrase)))
+        .map(|(spdx, _)| *spdx)
+}
+
+/// Guess the license from the contents of a LICENSE or COPYING file.
+pub fn guess_from_license_file(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    Ok(detect_license(&contents)
+        .map(|license| UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::License(license.to_string()),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        })
+        .into_iter()
+        .collect())
+}
@@ -0,0 +1,118 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the body of a `name = { ... }` table.
+fn lua_table<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    let start = regex!(r"(?m)^\s*(\w+)\s*=\s*\{")
+        .captures_iter(contents)
+        .find(|c| &c[1] == name)
+        .map(|c| c.get(0).unwrap().end())?;
+    let mut depth = 1;
+    for (i, ch) in contents[start..].char_indices() {
+        match ch {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(&contents[start..start + i]);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Find the string assigned to a key, either quoted or as a [[long string]].
+///
+/// Nested tables are not skipped, so this should only be used for keys that are unique
+/// within `contents`.
+fn lua_string(contents: &str, key: &str) -> Option<String> {
+    regex!(r#"(?s)\b(\w+)\s*=\s*(?:"((?:[^"\\]|\\.)*)"|'((?:[^'\\]|\\.)*)'|\[\[(.*?)\]\])"#)
+        .captures_iter(contents)
+        .find(|c| &c[1] == key)
+        .and_then(|c| c.get(2).or_else(|| c.get(3)).or_else(|| c.get(4)))
+        .map(|m| m.as_str().trim().to_string())
+}
+
+/// Guess upstream metadata from a LuaRocks rockspec file.
+pub fn guess_from_rockspec(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    // Only consider the top-level assignments for package and version
+    let mut toplevel = contents.clone();
+    while let Some(m) = regex!(r"\{[^{}]*\}").find(&toplevel) {
+        toplevel.replace_range(m.range(), "");
+    }
+    if let Some(name) = lua_string(&toplevel, "package") {
+        push(UpstreamDatum::Name(name), Certainty::Certain);
+    }
+    if let Some(version) = lua_string(&toplevel, "version") {
+        // Strip the rockspec revision, e.g. 1.0-1
+        let version = match version.rsplit_once('-') {
+            Some((version, revision)) if revision.chars().all(|c| c.is_ascii_digit()) => {
+                version.to_string()
+            }
+            _ => version,
+        };
+        if version != "scm" && version != "dev" {
+            push(UpstreamDatum::Version(version), Certainty::Certain);
+        }
+    }
+
+    if let Some(source) = lua_table(&contents, "source") {
+        if let Some(url) = lua_string(source, "url") {
+            if let Some(url) = url.strip_prefix("git+") {
+                push(
+                    UpstreamDatum::Repository(url.to_string()),
+                    Certainty::Certain,
+                );
+            } else if url.starts_with("git://") || url.ends_with(".git") {
+                push(UpstreamDatum::Repository(url), Certainty::Certain);
+            } else {
+                push(UpstreamDatum::Download(url), Certainty::Possible);
+            }
+        }
+    }
+
+    if let Some(description) = lua_table(&contents, "description") {
+        if let Some(summary) = lua_string(description, "summary") {
+            push(UpstreamDatum::Summary(summary), Certainty::Certain);
+        }
+        if let Some(detailed) = lua_string(description, "detailed") {
+            push(UpstreamDatum::Description(detailed), Certainty::Certain);
+        }
+        if let Some(homepage) = lua_string(description, "homepage") {
+            push(UpstreamDatum::Homepage(homepage), Certainty::Certain);
+        }
+        if let Some(license) = lua_string(description, "license") {
+            push(UpstreamDatum::License(license), Certainty::Certain);
+        }
+        if let Some(issues_url) = lua_string(description, "issues_url") {
+            push(UpstreamDatum::BugDatabase(issues_url), Certainty::Certain);
+        }
+        if let Some(maintainer) = lua_string(description, "maintainer") {
+            push(
+                UpstreamDatum::Maintainer(Person::from(maintainer.as_str())),
+                Certainty::Certain,
+            );
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,105 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{Person, UpstreamDatum};
+
+struct MailmapEntry {
+    proper_name: Option<String>,
+    proper_email: Option<String>,
+    commit_name: Option<String>,
+    commit_email: String,
+}
+
+/// The identity mappings from a .mailmap file, as used by git.
+pub struct Mailmap(Vec<MailmapEntry>);
+
+impl Mailmap {
+    pub fn parse(contents: &str) -> Self {
+        let mut entries = Vec::new();
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap();
+            // Each line is a sequence of optional names followed by an email address
+            let parts = regex!(r"\s*([^<]*?)\s*<([^>]*)>")
+                .captures_iter(line)
+                .map(|c| {
+                    let name = c.get(1).unwrap().as_str();
+                    (
+                        Some(name).filter(|n| !n.is_empty()).map(String::from),
+                        c.get(2).unwrap().as_str().to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let entry = match parts.as_slice() {
+                [(name, email)] => MailmapEntry {
+                    proper_name: name.clone(),
+                    proper_email: None,
+                    commit_name: None,
+                    commit_email: email.clone(),
+                },
+                [(proper_name, proper_email), (commit_name, commit_email)] => MailmapEntry {
+                    proper_name: proper_name.clone(),
+                    proper_email: Some(proper_email.clone()),
+                    commit_name: commit_name.clone(),
+                    commit_email: commit_email.clone(),
+                },
+                _ => continue,
+            };
+            entries.push(entry);
+        }
+        Mailmap(entries)
+    }
+
+    pub fn from_file(path: &Path) -> std::io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Return the canonical identity for a person.
+    ///
+    /// Email addresses match case-insensitively; entries that also list a commit name only
+    /// apply to people with that name.
+    pub fn canonicalize(&self, person: &Person) -> Person {
+        let email = match person.email.as_ref() {
+            Some(email) => email,
+            None => return person.clone(),
+        };
+        // Later entries take precedence
+        let entry = self.0.iter().rev().find(|entry| {
+            entry.commit_email.eq_ignore_ascii_case(email)
+                && (entry.commit_name.is_none() || entry.commit_name == person.name)
+        });
+        match entry {
+            Some(entry) => Person {
+                name: entry.proper_name.clone().or_else(|| person.name.clone()),
+                email: entry.proper_email.clone().or_else(|| person.email.clone()),
+                url: person.url.clone(),
+            },
+            None => person.clone(),
+        }
+    }
+}
+
+/// Canonicalize the authors and maintainer in the metadata using the .mailmap file in
+/// `path`, if there is one, removing authors that turn out to be duplicates.
+pub fn apply_mailmap(metadata: &mut upstream_ontologist::UpstreamMetadata, path: &Path) {
+    let mailmap = match Mailmap::from_file(&path.join(".mailmap")) {
+        Ok(mailmap) => mailmap,
+        Err(_) => return,
+    };
+    for item in metadata.mut_iter() {
+        match &mut item.datum {
+            UpstreamDatum::Author(authors) => {
+                let mut canonical: Vec<Person> = Vec::new();
+                for author in authors.iter() {
+                    let author = mailmap.canonicalize(author);
+                    if !canonical.contains(&author) {
+                        canonical.push(author);
+                    }
+                }
+                *authors = canonical;
+            }
+            UpstreamDatum::Maintainer(maintainer) => {
+                *maintainer = mailmap.canonicalize(maintainer);
+            }
+            _ => {}
+        }
+    }
+}
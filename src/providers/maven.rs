@@ -0,0 +1,254 @@
+use std::path::Path;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+use xmltree::Element;
+
+fn child_text(element: &Element, tag: &str) -> Option<String> {
+    element
+        .get_child(tag)
+        .and_then(|e| e.get_text())
+        .map(|text| text.trim().to_string())
+        .filter(|text| !text.is_empty() && !text.contains("${"))
+}
+
+/// Find the groupId:artifactId coordinates of the project in a directory.
+///
+/// The groupId may be inherited from the parent POM.
+pub fn coordinates(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path.join("pom.xml")).ok()?;
+    let root = Element::parse(file).ok()?;
+    let group_id = child_text(&root, "groupId").or_else(|| {
+        root.get_child("parent")
+            .and_then(|p| child_text(p, "groupId"))
+    })?;
+    let artifact_id = child_text(&root, "artifactId")?;
+    Some(format!("{}:{}", group_id, artifact_id))
+}
+
+/// Look up an artifact on Maven Central, given its groupId:artifactId coordinates.
+///
+/// The search API only knows the latest version, so the POM for that version is fetched
+/// for the actual metadata.
+pub async fn remote_maven_data(coordinates: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let (group_id, artifact_id) = match coordinates.split_once(':') {
+        Some(parts) => parts,
+        None => {
+            return Err(ProviderError::Other(format!(
+                "Invalid Maven coordinates: {}",
+                coordinates
+            )))
+        }
+    };
+    let mut url = url::Url::parse("https://search.maven.org/solrsearch/select").unwrap();
+    url.query_pairs_mut()
+        .append_pair(
+            "q",
+            &format!("g:\"{}\" AND a:\"{}\"", group_id, artifact_id),
+        )
+        .append_pair("rows", "1")
+        .append_pair("wt", "json");
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    let version = match latest_version(&data) {
+        Some(version) => version.to_string(),
+        None => return Ok(vec![]),
+    };
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(format!(
+            "https://repo1.maven.org/maven2/{}/{}/{}/{}-{}.pom",
+            group_id.replace('.', "/"),
+            artifact_id,
+            version,
+            artifact_id,
+            version
+        ))
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    if !response.status().is_success() {
+        return Err(ProviderError::Other(format!(
+            "HTTP error: {}",
+            response.status()
+        )));
+    }
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let mut datums = vec![UpstreamDatum::Version(version)];
+    datums.extend(parse_pom(&text)?);
+    Ok(datums)
+}
+
+/// Find the latest version of an artifact in a Maven Central search response.
+fn latest_version(data: &serde_json::Value) -> Option<&str> {
+    data["response"]["docs"]
+        .get(0)
+        .and_then(|doc| doc.get("latestVersion"))
+        .and_then(|v| v.as_str())
+}
+
+/// Extract metadata from a POM file.
+fn parse_pom(text: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let root =
+        Element::parse(text.as_bytes()).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut datums = Vec::new();
+    if let Some(name) = child_text(&root, "name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(description) = child_text(&root, "description") {
+        datums.push(UpstreamDatum::Summary(description));
+    }
+    if let Some(homepage) = child_text(&root, "url") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(scm) = root.get_child("scm") {
+        // Connections look like scm:git:https://...
+        if let Some(connection) = child_text(scm, "connection") {
+            if let Some(url) = connection.splitn(3, ':').nth(2) {
+                datums.push(UpstreamDatum::Repository(url.to_string()));
+            }
+        }
+        if let Some(browse) = child_text(scm, "url") {
+            datums.push(UpstreamDatum::RepositoryBrowse(browse));
+        }
+    }
+    if let Some(issues) = root
+        .get_child("issueManagement")
+        .and_then(|e| child_text(e, "url"))
+    {
+        datums.push(UpstreamDatum::BugDatabase(issues));
+    }
+    let licenses = root
+        .get_child("licenses")
+        .map(|licenses| {
+            licenses
+                .children
+                .iter()
+                .filter_map(|node| node.as_element())
+                .filter_map(|license| child_text(license, "name"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" OR ")));
+    }
+
+    Ok(datums)
+}
+
+pub struct MavenCentral;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for MavenCentral {
+    fn name(&self) -> &'static str {
+        "Maven Central"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Repository-Browse",
+            "Bug-Database",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("Maven Central", remote_maven_data(name).await)
+    }
+}
+
+impl super::Registry for MavenCentral {
+    fn page_url(&self, coordinates: &str) -> String {
+        format!(
+            "https://central.sonatype.com/artifact/{}",
+            coordinates.replacen(':', "/", 1)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_version() {
+        let data: serde_json::Value = serde_json::from_str(
+            r#"{
+                "responseHeader": {"status": 0},
+                "response": {
+                    "numFound": 1,
+                    "start": 0,
+                    "docs": [
+                        {
+                            "id": "com.google.guava:guava",
+                            "g": "com.google.guava",
+                            "a": "guava",
+                            "latestVersion": "33.3.1-jre",
+                            "p": "bundle"
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(latest_version(&data), Some("33.3.1-jre"));
+        let data: serde_json::Value =
+            serde_json::from_str(r#"{"response": {"numFound": 0, "docs": []}}"#).unwrap();
+        assert_eq!(latest_version(&data), None);
+    }
+
+    #[test]
+    fn test_parse_pom() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<project xmlns="http://maven.apache.org/POM/4.0.0">
+  <modelVersion>4.0.0</modelVersion>
+  <groupId>com.google.guava</groupId>
+  <artifactId>guava</artifactId>
+  <version>33.3.1-jre</version>
+  <name>Guava: Google Core Libraries for Java</name>
+  <url>https://github.com/google/guava</url>
+  <description>Guava is a suite of core and expanded libraries.</description>
+  <licenses>
+    <license>
+      <name>Apache License, Version 2.0</name>
+      <url>http://www.apache.org/licenses/LICENSE-2.0.txt</url>
+    </license>
+  </licenses>
+  <scm>
+    <connection>scm:git:https://github.com/google/guava.git</connection>
+    <url>https://github.com/google/guava/tree/${project.version}</url>
+  </scm>
+  <issueManagement>
+    <system>GitHub Issues</system>
+    <url>https://github.com/google/guava/issues</url>
+  </issueManagement>
+</project>"#;
+        assert_eq!(
+            parse_pom(text).unwrap(),
+            vec![
+                UpstreamDatum::Name("Guava: Google Core Libraries for Java".to_string()),
+                UpstreamDatum::Summary(
+                    "Guava is a suite of core and expanded libraries.".to_string()
+                ),
+                UpstreamDatum::Homepage("https://github.com/google/guava".to_string()),
+                UpstreamDatum::Repository("https://github.com/google/guava.git".to_string()),
+                UpstreamDatum::BugDatabase("https://github.com/google/guava/issues".to_string()),
+                UpstreamDatum::License("Apache License, Version 2.0".to_string()),
+            ]
+        );
+    }
+}
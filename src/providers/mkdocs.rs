@@ -0,0 +1,57 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a mkdocs.yml file.
+pub fn guess_from_mkdocs_yml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    // The site name is often a more descriptive title, e.g. "Example Documentation"
+    if let Some(name) = get_str("site_name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Possible);
+    }
+    if let Some(description) = get_str("site_description") {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(url) = get_str("site_url") {
+        push(
+            UpstreamDatum::Documentation(url.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(url) = get_str("repo_url") {
+        push(
+            UpstreamDatum::Repository(url.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(copyright) = get_str("copyright") {
+        push(
+            UpstreamDatum::Copyright(copyright.to_string()),
+            Certainty::Likely,
+        );
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,1916 @@
+//! Bindings for the individual upstream metadata guessers.
+// Guessers return upstream_ontologist's ProviderError, like the ones built into it, so the
+// size of the error isn't ours to change.
+#![allow(clippy::result_large_err)]
+mod arch;
+mod authors;
+mod bazel;
+pub(crate) mod bitbucket;
+mod bower;
+mod changelog;
+mod citation;
+mod clojure;
+mod cocoapods;
+mod codemeta;
+mod conan;
+mod conda;
+mod cran;
+mod crates_io;
+mod debian_archive;
+mod desktop;
+mod docker;
+mod dotnet;
+mod dub;
+mod ecosystems;
+mod elixir;
+mod erlang;
+mod flatpak;
+mod funding;
+mod git;
+pub(crate) mod gitea;
+pub(crate) mod github;
+mod gitlab;
+mod gitmodules;
+mod gradle;
+mod haskell;
+mod helm;
+mod hex;
+mod julia;
+mod launchpad;
+mod license;
+mod lua;
+mod mailmap;
+mod maven;
+mod mkdocs;
+mod nim;
+mod nix;
+mod npm;
+mod ocaml;
+mod openhub;
+mod package_xml;
+mod packagist;
+mod pecl;
+mod perl;
+mod pub_dev;
+mod pypi;
+mod readthedocs;
+mod repology;
+mod rpm;
+mod ruby;
+mod security_md;
+mod shards;
+mod snapcraft;
+mod sphinx;
+mod swift;
+mod vcpkg;
+mod waf;
+mod wikidata;
+mod zenodo;
+
+use crate::UpstreamDatum;
+use futures::Stream;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use std::path::{Path, PathBuf};
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Origin, ProviderError, UpstreamDatumWithMetadata,
+};
+
+fn guesser_settings(trust_package: Option<bool>) -> GuesserSettings {
+    GuesserSettings {
+        trust_package: trust_package.unwrap_or(false),
+    }
+}
+
+fn into_datums(
+    items: Result<Vec<UpstreamDatumWithMetadata>, ProviderError>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    Ok(items?.into_iter().map(UpstreamDatum).collect())
+}
+
+/// A third-party repository with a page for each project it describes.
+pub(crate) trait Registry: upstream_ontologist::ThirdPartyRepository + Sync {
+    /// The page for the project that `key` refers to, which datums are attributed to.
+    fn page_url(&self, key: &str) -> String;
+}
+
+/// Attribute datums from a registry lookup to the registry page.
+fn registry_datums(
+    items: Vec<upstream_ontologist::UpstreamDatum>,
+    url: &str,
+    certainty: Certainty,
+) -> Vec<UpstreamDatumWithMetadata> {
+    let origin = match url.parse() {
+        Ok(url) => Origin::Url(url),
+        Err(_) => Origin::Other(url.to_string()),
+    };
+    items
+        .into_iter()
+        .map(|datum| UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(origin.clone()),
+        })
+        .collect()
+}
+
+/// Convert the result of a registry lookup, attributing each datum to the registry page.
+fn into_registry_datums(
+    items: Result<Vec<upstream_ontologist::UpstreamDatum>, ProviderError>,
+    url: &str,
+) -> PyResult<Vec<UpstreamDatum>> {
+    Ok(registry_datums(items?, url, Certainty::Certain)
+        .into_iter()
+        .map(UpstreamDatum)
+        .collect())
+}
+
+/// Log and discard an error from consulting a third-party repository.
+///
+/// ThirdPartyRepository::extend_metadata unwraps the result of guess_metadata, so
+/// implementations use this to avoid failing on e.g. network errors.
+pub(crate) fn warn_on_error(
+    source: &str,
+    result: Result<Vec<upstream_ontologist::UpstreamDatum>, ProviderError>,
+) -> Result<Vec<upstream_ontologist::UpstreamDatum>, ProviderError> {
+    match result {
+        Ok(data) => Ok(data),
+        Err(e) => {
+            log::warn!("Failed to consult {}: {}", source, e);
+            Ok(vec![])
+        }
+    }
+}
+
+/// Read a YAML field that accepts either a single string or a list of strings.
+pub(crate) fn yaml_strings(value: &serde_yaml::Value) -> Vec<&str> {
+    match value {
+        serde_yaml::Value::String(s) => vec![s.as_str()],
+        serde_yaml::Value::Sequence(items) => {
+            items.iter().filter_map(serde_yaml::Value::as_str).collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Read a JSON field that accepts either a single string or a list of strings.
+pub(crate) fn json_strings(value: &serde_json::Value) -> Vec<&str> {
+    match value {
+        serde_json::Value::String(s) => vec![s.as_str()],
+        serde_json::Value::Array(items) => {
+            items.iter().filter_map(serde_json::Value::as_str).collect()
+        }
+        _ => vec![],
+    }
+}
+
+/// Map the name of a link in Hex package metadata to the datum it describes.
+pub(crate) fn hex_link_datum(
+    name: &str,
+    url: String,
+) -> Option<upstream_ontologist::UpstreamDatum> {
+    match name.to_lowercase().as_str() {
+        "github" | "gitlab" | "source" | "repository" => {
+            Some(upstream_ontologist::UpstreamDatum::Repository(url))
+        }
+        "homepage" | "website" => Some(upstream_ontologist::UpstreamDatum::Homepage(url)),
+        "changelog" => Some(upstream_ontologist::UpstreamDatum::Changelog(url)),
+        "docs" | "documentation" => Some(upstream_ontologist::UpstreamDatum::Documentation(url)),
+        "issues" | "bug tracker" => Some(upstream_ontologist::UpstreamDatum::BugDatabase(url)),
+        _ => None,
+    }
+}
+
+/// Derive a repository URL from a homepage or documentation URL hosted on a code forge.
+///
+/// Handles project pages such as https://github.com/owner/project and GitHub Pages sites
+/// such as https://owner.github.io/project/.
+pub(crate) fn forge_repository_url(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    let host = url.host_str()?;
+    let segments = url
+        .path_segments()?
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>();
+    match (host, segments.as_slice()) {
+        ("github.com" | "gitlab.com" | "codeberg.org", [owner, project]) => Some(format!(
+            "https://{}/{}/{}",
+            host,
+            owner,
+            project.trim_end_matches(".git")
+        )),
+        (host, [project, ..]) if host.ends_with(".github.io") => Some(format!(
+            "https://github.com/{}/{}",
+            host.trim_end_matches(".github.io"),
+            project
+        )),
+        _ => None,
+    }
+}
+
+type Guesser = fn(&Path, &GuesserSettings) -> Result<Vec<UpstreamDatumWithMetadata>, ProviderError>;
+
+/// Guessers for files that upstream_ontologist does not scan for itself.
+///
+/// A final path component starting with '*' matches any file in that directory with that
+/// suffix, e.g. "src/*.app.src".
+const STATIC_GUESSERS: &[(&str, Guesser)] = &[
+    ("opam", ocaml::guess_from_opam),
+    ("*.csproj", dotnet::guess_from_csproj),
+    ("Directory.Build.props", dotnet::guess_from_csproj),
+    ("CITATION.cff", citation::guess_from_citation_cff),
+    ("codemeta.json", codemeta::guess_from_codemeta_json),
+    (".zenodo.json", zenodo::guess_from_zenodo_json),
+    (".github/FUNDING.yml", funding::guess_from_funding_yml),
+    ("snapcraft.yaml", snapcraft::guess_from_snapcraft_yaml),
+    ("snap/snapcraft.yaml", snapcraft::guess_from_snapcraft_yaml),
+    (".snapcraft.yaml", snapcraft::guess_from_snapcraft_yaml),
+    ("*.desktop", desktop::guess_from_desktop_file),
+    (
+        "AppImageBuilder.yml",
+        desktop::guess_from_appimage_builder_yml,
+    ),
+    ("rebar.config", erlang::guess_from_rebar_config),
+    ("src/*.app.src", erlang::guess_from_app_src),
+    ("meta.yaml", conda::guess_from_conda_meta),
+    ("recipe/meta.yaml", conda::guess_from_conda_meta),
+    ("conda/meta.yaml", conda::guess_from_conda_meta),
+    ("conda.recipe/meta.yaml", conda::guess_from_conda_meta),
+    ("*.spec", rpm::guess_from_rpm_spec),
+    ("PKGBUILD", arch::guess_from_pkgbuild),
+    ("flake.nix", nix::guess_from_nix),
+    ("default.nix", nix::guess_from_nix),
+    ("build.gradle", gradle::guess_from_build_gradle),
+    ("build.gradle.kts", gradle::guess_from_build_gradle),
+    ("gradle.properties", gradle::guess_from_gradle_properties),
+    ("mix.exs", elixir::guess_from_mix_exs),
+    ("Package.swift", swift::guess_from_package_swift),
+    (".spi.yml", swift::guess_from_spi_yml),
+    ("*.podspec", cocoapods::guess_from_podspec),
+    ("*.podspec.json", cocoapods::guess_from_podspec),
+    ("dub.json", dub::guess_from_dub_json),
+    ("dub.sdl", dub::guess_from_dub_sdl),
+    ("shard.yml", shards::guess_from_shard_yml),
+    ("project.clj", clojure::guess_from_project_clj),
+    ("deps.edn", clojure::guess_from_deps_edn),
+    ("*.nimble", nim::guess_from_nimble),
+    ("bower.json", bower::guess_from_bower_json),
+    ("wscript", waf::guess_extra_from_wscript),
+    ("vcpkg.json", vcpkg::guess_from_vcpkg_json),
+    ("conanfile.py", conan::guess_from_conanfile),
+    ("MODULE.bazel", bazel::guess_from_module_bazel),
+    ("Gemfile", ruby::guess_from_gemfile),
+    ("*.rockspec", lua::guess_from_rockspec),
+    ("Project.toml", julia::guess_from_project_toml),
+    ("Dockerfile", docker::guess_from_dockerfile),
+    ("Containerfile", docker::guess_from_dockerfile),
+    ("docker-compose.yml", docker::guess_from_docker_compose),
+    ("docker-compose.yaml", docker::guess_from_docker_compose),
+    ("compose.yaml", docker::guess_from_docker_compose),
+    ("compose.yml", docker::guess_from_docker_compose),
+    ("Chart.yaml", helm::guess_from_chart_yaml),
+    (".gitmodules", gitmodules::guess_from_gitmodules),
+    (
+        ".github/ISSUE_TEMPLATE/config.yml",
+        github::guess_from_issue_template_config,
+    ),
+    (".github/settings.yml", github::guess_from_github_settings),
+    ("CODEOWNERS", github::guess_from_codeowners),
+    (".github/CODEOWNERS", github::guess_from_codeowners),
+    ("docs/CODEOWNERS", github::guess_from_codeowners),
+    ("docs/conf.py", sphinx::guess_from_sphinx_conf),
+    ("doc/conf.py", sphinx::guess_from_sphinx_conf),
+    ("docs/source/conf.py", sphinx::guess_from_sphinx_conf),
+    ("doc/source/conf.py", sphinx::guess_from_sphinx_conf),
+    ("mkdocs.yml", mkdocs::guess_from_mkdocs_yml),
+    ("mkdocs.yaml", mkdocs::guess_from_mkdocs_yml),
+    ("AUTHORS.md", authors::guess_from_authors_file),
+    ("AUTHORS.txt", authors::guess_from_authors_file),
+    ("MAINTAINERS", authors::guess_from_authors_file),
+    ("MAINTAINERS.md", authors::guess_from_authors_file),
+    ("CONTRIBUTORS", authors::guess_from_authors_file),
+    ("CONTRIBUTORS.md", authors::guess_from_authors_file),
+    ("LICENSE", license::guess_from_license_file),
+    ("LICENSE.md", license::guess_from_license_file),
+    ("LICENSE.txt", license::guess_from_license_file),
+    ("LICENCE", license::guess_from_license_file),
+    ("COPYING", license::guess_from_license_file),
+    (".git", git::guess_version_from_git),
+];
+
+/// List the files in a directory, sorted so that the order of guesses is stable.
+fn sorted_filenames(path: &Path) -> Vec<String> {
+    let mut filenames = match std::fs::read_dir(path) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().to_string())
+            .collect::<Vec<_>>(),
+        Err(_) => return Vec::new(),
+    };
+    filenames.sort();
+    filenames
+}
+
+fn find_guessers(path: &Path) -> Vec<(PathBuf, Guesser)> {
+    let mut candidates: Vec<(PathBuf, Guesser)> = Vec::new();
+
+    for (name, guesser) in STATIC_GUESSERS {
+        let (dir, filename) = match name.rsplit_once('/') {
+            Some((dir, filename)) => (path.join(dir), filename),
+            None => (path.to_path_buf(), *name),
+        };
+        if let Some(suffix) = filename.strip_prefix('*') {
+            for filename in sorted_filenames(&dir)
+                .into_iter()
+                .filter(|f| f.ends_with(suffix))
+            {
+                candidates.push((dir.join(filename), *guesser));
+            }
+        } else if dir.join(filename).exists() {
+            candidates.push((dir.join(filename), *guesser));
+        }
+    }
+
+    for filename in sorted_filenames(path)
+        .into_iter()
+        .filter(|f| flatpak::is_flatpak_manifest_name(f))
+    {
+        candidates.push((path.join(filename), flatpak::guess_from_flatpak_manifest));
+    }
+
+    // Only the preferred changelog, since projects sometimes keep an outdated one around
+    if let Some(filename) = changelog::CHANGELOG_FILES
+        .iter()
+        .find(|f| path.join(f).exists())
+    {
+        candidates.push((path.join(filename), changelog::guess_from_changelog_file));
+    }
+
+    candidates
+}
+
+fn rewrite_origin(datum: &mut UpstreamDatumWithMetadata, abspath: &Path) {
+    if let Some(Origin::Path(p)) = datum.origin.as_ref() {
+        if let Ok(suffix) = p.strip_prefix(abspath) {
+            datum.origin = Some(Origin::Path(Path::new(".").join(suffix)));
+        }
+    }
+}
+
+/// Run the guessers for files that upstream_ontologist does not scan for itself.
+fn guess_local_metadata_items(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> Vec<Result<UpstreamDatumWithMetadata, ProviderError>> {
+    let abspath = match path.canonicalize() {
+        Ok(p) => p,
+        Err(e) => return vec![Err(e.into())],
+    };
+
+    let mut ret = Vec::new();
+    for (subpath, guesser) in find_guessers(&abspath) {
+        match guesser(&subpath, settings) {
+            Ok(items) => ret.extend(items.into_iter().map(|mut datum| {
+                rewrite_origin(&mut datum, &abspath);
+                Ok(datum)
+            })),
+            Err(e) => ret.push(Err(e)),
+        }
+    }
+    ret
+}
+
+/// Guess upstream metadata items, combining upstream_ontologist's guessers with our own.
+pub fn guess_upstream_metadata_items(
+    path: &Path,
+    trust_package: Option<bool>,
+    minimum_certainty: Option<Certainty>,
+) -> impl Stream<Item = Result<UpstreamDatumWithMetadata, ProviderError>> {
+    use futures::StreamExt;
+    let local = guess_local_metadata_items(path, &guesser_settings(trust_package))
+        .into_iter()
+        .filter(move |item| match item {
+            Ok(datum) => minimum_certainty.is_none() || datum.certainty >= minimum_certainty,
+            Err(_) => true,
+        });
+
+    upstream_ontologist::guess_upstream_metadata_items(path, trust_package, minimum_certainty)
+        .chain(futures::stream::iter(local))
+}
+
+/// Merge the result of looking up `key` in a registry into metadata.
+///
+/// Like ThirdPartyRepository::extend_metadata, this only consults the registry if some of
+/// the fields it supports aren't certain yet, and only replaces less certain datums. Unlike
+/// it, the datums are attributed to the registry page, and the lookup is skipped if
+/// `certainty` is below `minimum_certainty`. `sources` are the origins of the findings
+/// that `key` is based on.
+async fn extend_from_lookup(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    registry: &dyn Registry,
+    key: &str,
+    certainty: Certainty,
+    minimum_certainty: Option<Certainty>,
+    sources: &[Origin],
+) {
+    if minimum_certainty.is_some_and(|minimum| certainty < minimum) {
+        return;
+    }
+    if registry.supported_fields().iter().all(|field| {
+        metadata
+            .get(field)
+            .is_some_and(|datum| datum.certainty == Some(Certainty::Certain))
+    }) {
+        return;
+    }
+    let items = match registry.guess_metadata(key).await {
+        Ok(items) => items,
+        Err(e) => {
+            log::warn!("Failed to consult {}: {}", registry.name(), e);
+            return;
+        }
+    };
+    let changed = upstream_ontologist::update_from_guesses(
+        metadata.mut_items(),
+        registry_datums(items, &registry.page_url(key), certainty).into_iter(),
+    );
+    for datum in changed {
+        crate::provenance::record(&datum, sources.to_vec());
+    }
+}
+
+/// A registry and the key to look up in it, along with the certainty and the origins of the
+/// findings the key is based on.
+type Lookup<'a> = (&'a dyn Registry, String, Option<Certainty>, Vec<Origin>);
+
+/// Consult the package registries that upstream_ontologist does not know about.
+///
+/// Registries are found through the Registry entries in the metadata, through fields
+/// that name a package in a specific registry, or through the packaging files in `path`.
+async fn extend_from_registries(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    path: &Path,
+    minimum_certainty: Option<Certainty>,
+) {
+    let mut lookups: Vec<Lookup> = Vec::new();
+    if let Some(registry) = metadata.get("Registry") {
+        if let upstream_ontologist::UpstreamDatum::Registry(entries) = &registry.datum {
+            for (name, entry) in entries {
+                let repository: &dyn Registry = match name.as_str() {
+                    "Hex" => &hex::Hex,
+                    "Julia" => &julia::JuliaGeneral,
+                    "PyPI" => &pypi::PyPI,
+                    _ => continue,
+                };
+                lookups.push((
+                    repository,
+                    entry.clone(),
+                    registry.certainty,
+                    crate::provenance::origins(registry),
+                ));
+            }
+        }
+    }
+    if let (Some(archive), Some(name)) = (metadata.get("Archive"), metadata.get("Name")) {
+        let repository: Option<&dyn Registry> = match archive.datum.as_str() {
+            Some("PyPI") => Some(&pypi::PyPI),
+            Some("CRAN") | Some("Bioconductor") => Some(&cran::Cran),
+            _ => None,
+        };
+        if let (Some(repository), Some(key)) = (repository, name.datum.as_str()) {
+            lookups.push((
+                repository,
+                key.to_string(),
+                std::cmp::min(archive.certainty, name.certainty),
+                crate::provenance::origins(name),
+            ));
+        }
+    }
+    if let Some(datum) = metadata.get("Cargo-Crate") {
+        if let Some(name) = datum.datum.as_str() {
+            lookups.push((
+                &crates_io::CratesIo,
+                name.to_string(),
+                datum.certainty,
+                crate::provenance::origins(datum),
+            ));
+        }
+    }
+    if let Some(datum) = metadata.get("Haskell-Package") {
+        if let Some(name) = datum.datum.as_str() {
+            lookups.push((
+                &haskell::Hackage,
+                name.to_string(),
+                datum.certainty,
+                crate::provenance::origins(datum),
+            ));
+        }
+    }
+    if let Some(datum) = metadata.get("Pecl-Package") {
+        if let Some(name) = datum.datum.as_str() {
+            lookups.push((
+                &pecl::Pecl,
+                name.to_string(),
+                datum.certainty,
+                crate::provenance::origins(datum),
+            ));
+        }
+    }
+    if let Some(datum) = metadata.get("Repository") {
+        if let Some(url) = datum.datum.as_str() {
+            let forge: Option<&dyn Registry> = if github::github_repo(url).is_some() {
+                Some(&github::GitHubApi)
+            } else if bitbucket::bitbucket_repo(url).is_some() {
+                Some(&bitbucket::BitbucketApi)
+            } else if gitlab::is_gitlab_repository(url).await {
+                Some(&gitlab::GitLabApi)
+            } else if gitea::is_gitea_repository(url).await {
+                Some(&gitea::GiteaApi)
+            } else {
+                None
+            };
+            if let Some(forge) = forge {
+                lookups.push((
+                    forge,
+                    url.to_string(),
+                    datum.certainty,
+                    crate::provenance::origins(datum),
+                ));
+            }
+        }
+    }
+    // The NuGet ID and gem name may come from one of several files, so those lookups are
+    // attributed to the directory
+    if let Some(id) = dotnet::package_id(path) {
+        lookups.push((
+            &dotnet::NuGet,
+            id,
+            Some(Certainty::Certain),
+            vec![Origin::Path(path.to_path_buf())],
+        ));
+    }
+    if let Some(coordinates) = maven::coordinates(path) {
+        lookups.push((
+            &maven::MavenCentral,
+            coordinates,
+            Some(Certainty::Certain),
+            vec![Origin::Path(path.join("pom.xml"))],
+        ));
+    }
+    if let Some(name) = npm::package_name(path) {
+        lookups.push((
+            &npm::Npm,
+            name,
+            Some(Certainty::Certain),
+            vec![Origin::Path(path.join("package.json"))],
+        ));
+    }
+    if let Some(name) = packagist::package_name(path) {
+        lookups.push((
+            &packagist::Packagist,
+            name,
+            Some(Certainty::Certain),
+            vec![Origin::Path(path.join("composer.json"))],
+        ));
+    }
+    if let Some(name) = pub_dev::package_name(path) {
+        lookups.push((
+            &pub_dev::PubDev,
+            name,
+            Some(Certainty::Certain),
+            vec![Origin::Path(path.join("pubspec.yaml"))],
+        ));
+    }
+    if let Some(name) = ruby::gem_name(path) {
+        // The gem name is only inferred from the name of the gemspec
+        lookups.push((
+            &ruby::RubyGems,
+            name,
+            Some(Certainty::Likely),
+            vec![Origin::Path(path.to_path_buf())],
+        ));
+    }
+    for (repository, entry, certainty, sources) in lookups {
+        // Datums of unknown certainty could be anything, so treat them as mere possibilities
+        let certainty = std::cmp::min(
+            certainty.unwrap_or(Certainty::Possible),
+            repository.max_supported_certainty(),
+        );
+        extend_from_lookup(
+            metadata,
+            repository,
+            &entry,
+            certainty,
+            minimum_certainty,
+            &sources,
+        )
+        .await;
+    }
+}
+
+/// Consult external directories that describe projects across ecosystems.
+///
+/// These are looked up by project name or repository URL, and may describe a different
+/// project with the same name, so they are only consulted when explicitly requested.
+async fn extend_from_external_directories(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    minimum_certainty: Option<Certainty>,
+) {
+    let wikidata = wikidata::Wikidata {
+        homepage: metadata.homepage().map(String::from),
+    };
+    // Open Hub requires an API key, so it is only consulted if one is configured
+    let openhub = std::env::var(openhub::API_KEY_ENV)
+        .ok()
+        .map(|api_key| openhub::OpenHub { api_key });
+    let mut lookups: Vec<(&dyn Registry, &UpstreamDatumWithMetadata)> = Vec::new();
+    if let Some(name) = metadata.get("Name") {
+        lookups.push((&repology::Repology, name));
+        lookups.push((&wikidata, name));
+        lookups.push((&debian_archive::DebianArchive, name));
+        if let Some(openhub) = openhub.as_ref() {
+            lookups.push((openhub, name));
+        }
+    }
+    if let Some(repository) = metadata.get("Repository") {
+        lookups.push((&ecosystems::Ecosystems, repository));
+    }
+    let lookups = lookups
+        .into_iter()
+        .filter_map(|(directory, datum)| {
+            let key = datum.datum.as_str()?.to_string();
+            Some((directory, key, crate::provenance::origins(datum)))
+        })
+        .collect::<Vec<_>>();
+    for (directory, key, sources) in lookups {
+        extend_from_lookup(
+            metadata,
+            directory,
+            &key,
+            directory.max_supported_certainty(),
+            minimum_certainty,
+            &sources,
+        )
+        .await;
+    }
+}
+
+/// Extend upstream metadata, consulting the registries in this module as well as those
+/// known to upstream_ontologist.
+pub async fn extend_upstream_metadata(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    path: &Path,
+    minimum_certainty: Option<Certainty>,
+    net_access: Option<bool>,
+    consult_external_directory: Option<bool>,
+) -> Result<(), ProviderError> {
+    upstream_ontologist::extend_upstream_metadata(
+        metadata,
+        path,
+        minimum_certainty,
+        net_access,
+        consult_external_directory,
+    )
+    .await?;
+    readthedocs::extend_from_readthedocs(metadata, path, net_access.unwrap_or(false)).await;
+    changelog::extend_changelog_url(metadata, path);
+    mailmap::apply_mailmap(metadata, path);
+    if net_access.unwrap_or(false) {
+        extend_from_registries(metadata, path, minimum_certainty).await;
+        if consult_external_directory.unwrap_or(false) {
+            extend_from_external_directories(metadata, minimum_certainty).await;
+        }
+    }
+    // Also derive fields from those found above, and run any rules registered from Python
+    crate::extrapolate::extrapolate_fields(metadata, net_access.unwrap_or(false), None).await?;
+    Ok(())
+}
+
+/// Guess and summarize upstream metadata, including the guessers in this module.
+///
+/// This mirrors upstream_ontologist::summarize_upstream_metadata, but uses
+/// [`extend_upstream_metadata`] so that our registries are consulted as well.
+pub async fn guess_upstream_metadata(
+    path: &Path,
+    trust_package: Option<bool>,
+    net_access: Option<bool>,
+    consult_external_directory: Option<bool>,
+    check: Option<bool>,
+) -> Result<upstream_ontologist::UpstreamMetadata, ProviderError> {
+    use futures::StreamExt;
+    let metadata_items = guess_upstream_metadata_items(path, trust_package, None)
+        .filter_map(|x| async {
+            match x {
+                Ok(x) => Some(x),
+                Err(e) => {
+                    log::error!("{}", e);
+                    None
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .await;
+
+    let mut metadata = upstream_ontologist::UpstreamMetadata::new();
+    metadata.update(
+        metadata_items
+            .into_iter()
+            .filter(|item| !crate::bad_guesses::known_bad_guess(&item.datum)),
+    );
+
+    extend_upstream_metadata(
+        &mut metadata,
+        path,
+        None,
+        net_access,
+        consult_external_directory,
+    )
+    .await?;
+
+    if check.unwrap_or(false) {
+        upstream_ontologist::check_upstream_metadata(&mut metadata, None).await;
+    }
+    upstream_ontologist::fix_upstream_metadata(&mut metadata).await;
+    metadata.sort();
+
+    Ok(metadata)
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_debian_watch(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_datums(rt.block_on(
+        upstream_ontologist::providers::debian::guess_from_debian_watch(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        ),
+    ))
+}
+
+/// Find the source package name in the first paragraph of a debian/control file.
+fn debian_control_source_name(path: &Path) -> std::io::Result<Option<String>> {
+    let contents = std::fs::read_to_string(path)?;
+    Ok(contents
+        .lines()
+        .take_while(|line| !line.trim().is_empty())
+        .find_map(|line| line.strip_prefix("Source:"))
+        .map(|name| name.trim().to_string()))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_debian_control(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let mut ret = upstream_ontologist::providers::debian::guess_from_debian_control(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    )?;
+    if let Some(name) = debian_control_source_name(path.as_path())? {
+        ret.push(UpstreamDatumWithMetadata {
+            datum: upstream_ontologist::UpstreamDatum::Name(name),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.as_path().into()),
+        });
+    }
+    Ok(ret.into_iter().map(UpstreamDatum).collect())
+}
+
+/// Load an existing debian/upstream/metadata file.
+///
+/// The fields in the file were presumably checked by a human, so they are all
+/// considered certain.
+#[pyfunction]
+pub fn guess_from_debian_upstream_metadata(
+    py: Python,
+    path: PathBuf,
+) -> PyResult<crate::UpstreamMetadata> {
+    let text = std::fs::read_to_string(&path)?;
+    Ok(crate::UpstreamMetadata(crate::debian_metadata::from_yaml(
+        py,
+        &text,
+        Some(Certainty::Certain),
+        Some(path.as_path().into()),
+    )?))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_debian_copyright(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_datums(rt.block_on(
+        upstream_ontologist::providers::debian::guess_from_debian_copyright(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        ),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_debian_changelog(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_datums(rt.block_on(
+        upstream_ontologist::providers::debian::guess_from_debian_changelog(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        ),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_meta_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(upstream_ontologist::providers::perl::guess_from_meta_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_meta_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(upstream_ontologist::providers::perl::guess_from_meta_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_makefile_pl(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(perl::guess_from_makefile_pl(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_dist_ini(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(upstream_ontologist::providers::perl::guess_from_dist_ini(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_r_description(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_datums(
+        rt.block_on(upstream_ontologist::providers::r::guess_from_r_description(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        )),
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_cabal(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(haskell::guess_from_cabal(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_package_yaml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(
+        upstream_ontologist::providers::package_yaml::guess_from_package_yaml(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        ),
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_opam(path: PathBuf, trust_package: Option<bool>) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(ocaml::guess_from_opam(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_pubspec_yaml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(
+        upstream_ontologist::providers::pubspec::guess_from_pubspec_yaml(
+            path.as_path(),
+            &guesser_settings(trust_package),
+        ),
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_package_xml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(package_xml::guess_from_package_xml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (contents, origin=None))]
+pub fn guess_from_pod(contents: &str, origin: Option<PathBuf>) -> PyResult<Vec<UpstreamDatum>> {
+    let origin = origin.map_or_else(|| Origin::Other("POD".to_string()), Origin::Path);
+    into_datums(perl::guess_from_pod(contents, &origin))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_security_md(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(security_md::guess_from_security_md(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_git_config(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(git::guess_from_git_config(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_csproj(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(dotnet::guess_from_csproj(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_citation_cff(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(citation::guess_from_citation_cff(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_codemeta_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(codemeta::guess_from_codemeta_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_zenodo_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(zenodo::guess_from_zenodo_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_funding_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(funding::guess_from_funding_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_snapcraft_yaml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(snapcraft::guess_from_snapcraft_yaml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_flatpak_manifest(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(flatpak::guess_from_flatpak_manifest(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_desktop_file(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(desktop::guess_from_desktop_file(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_appimage_builder_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(desktop::guess_from_appimage_builder_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_conda_meta(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(conda::guess_from_conda_meta(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_rpm_spec(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(rpm::guess_from_rpm_spec(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_pkgbuild(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(arch::guess_from_pkgbuild(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_nix(path: PathBuf, trust_package: Option<bool>) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(nix::guess_from_nix(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_build_gradle(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(gradle::guess_from_build_gradle(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_gradle_properties(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(gradle::guess_from_gradle_properties(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_mix_exs(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(elixir::guess_from_mix_exs(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_rebar_config(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(erlang::guess_from_rebar_config(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_app_src(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(erlang::guess_from_app_src(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_package_swift(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(swift::guess_from_package_swift(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_spi_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(swift::guess_from_spi_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_podspec(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(cocoapods::guess_from_podspec(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_dub_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(dub::guess_from_dub_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_dub_sdl(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(dub::guess_from_dub_sdl(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_shard_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(shards::guess_from_shard_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_project_clj(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(clojure::guess_from_project_clj(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_deps_edn(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(clojure::guess_from_deps_edn(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_nimble(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(nim::guess_from_nimble(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_bower_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(bower::guess_from_bower_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_wscript(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(waf::guess_from_wscript(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_vcpkg_json(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(vcpkg::guess_from_vcpkg_json(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_conanfile(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(conan::guess_from_conanfile(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_module_bazel(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(bazel::guess_from_module_bazel(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_gemfile(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(ruby::guess_from_gemfile(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_rockspec(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(lua::guess_from_rockspec(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_project_toml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(julia::guess_from_project_toml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_dockerfile(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(docker::guess_from_dockerfile(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_docker_compose(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(docker::guess_from_docker_compose(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_chart_yaml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(helm::guess_from_chart_yaml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_gitmodules(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(gitmodules::guess_from_gitmodules(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_issue_template_config(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(github::guess_from_issue_template_config(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_codeowners(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(github::guess_from_codeowners(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_github_settings(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(github::guess_from_github_settings(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_sphinx_conf(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(sphinx::guess_from_sphinx_conf(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_mkdocs_yml(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(mkdocs::guess_from_mkdocs_yml(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_authors_file(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(authors::guess_from_authors_file(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_license_file(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(license::guess_from_license_file(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_from_changelog_file(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(changelog::guess_from_changelog_file(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+#[pyo3(signature = (path, trust_package=None))]
+pub fn guess_version_from_git(
+    path: PathBuf,
+    trust_package: Option<bool>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    into_datums(git::guess_version_from_git(
+        path.as_path(),
+        &guesser_settings(trust_package),
+    ))
+}
+
+#[pyfunction]
+pub fn remote_crate_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(crates_io::remote_crate_data(name)),
+        &crates_io::CratesIo.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_pypi_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(pypi::remote_pypi_data(name)),
+        &pypi::PyPI.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_npm_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(npm::remote_npm_data(name)),
+        &npm::Npm.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_rubygem_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(ruby::remote_rubygem_data(name)),
+        &ruby::RubyGems.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_hackage_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(haskell::remote_hackage_data(name)),
+        &haskell::Hackage.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_cran_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(cran::remote_cran_data(name)),
+        &cran::Cran.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_packagist_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(packagist::remote_packagist_data(name)),
+        &packagist::Packagist.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_nuget_data(id: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(dotnet::remote_nuget_data(id)),
+        &dotnet::NuGet.page_url(id),
+    )
+}
+
+#[pyfunction]
+pub fn remote_maven_data(coordinates: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(maven::remote_maven_data(coordinates)),
+        &maven::MavenCentral.page_url(coordinates),
+    )
+}
+
+#[pyfunction]
+pub fn remote_pecl_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(pecl::remote_pecl_data(name)),
+        &pecl::Pecl.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_hex_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(hex::remote_hex_data(name)),
+        &hex::Hex.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn remote_pub_data(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(pub_dev::remote_pub_data(name)),
+        &pub_dev::PubDev.page_url(name),
+    )
+}
+
+#[pyfunction]
+pub fn guess_from_github_api(repo_url: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(github::guess_from_github_api(repo_url)),
+        repo_url,
+    )
+}
+
+#[pyfunction]
+pub fn guess_from_gitlab_api(repo_url: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(gitlab::guess_from_gitlab_api(repo_url)),
+        repo_url,
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (project, distribution=None, suite=None))]
+pub fn guess_from_launchpad(
+    project: &str,
+    distribution: Option<&str>,
+    suite: Option<&str>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(launchpad::guess_from_launchpad(
+            project,
+            distribution,
+            suite,
+        )),
+        &format!(
+            "https://launchpad.net/{}/+source/{}",
+            distribution.unwrap_or("ubuntu"),
+            project
+        ),
+    )
+}
+
+#[pyfunction]
+pub fn guess_from_gitea_api(repo_url: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(rt.block_on(gitea::guess_from_gitea_api(repo_url)), repo_url)
+}
+
+#[pyfunction]
+pub fn guess_from_bitbucket_api(repo_url: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    into_registry_datums(
+        rt.block_on(bitbucket::guess_from_bitbucket_api(repo_url)),
+        repo_url,
+    )
+}
+
+#[pyfunction]
+pub fn consult_repology(project_name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut datums = into_registry_datums(
+        rt.block_on(repology::consult_repology(project_name)),
+        &repology::Repology.page_url(project_name),
+    )?;
+    for datum in datums.iter_mut() {
+        datum.0.certainty = Some(Certainty::Possible);
+    }
+    Ok(datums)
+}
+
+#[pyfunction]
+#[pyo3(signature = (name=None, homepage=None))]
+pub fn guess_from_wikidata(
+    name: Option<&str>,
+    homepage: Option<&str>,
+) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut datums = into_registry_datums(
+        rt.block_on(wikidata::guess_from_wikidata(name, homepage)),
+        "https://www.wikidata.org/",
+    )?;
+    for datum in datums.iter_mut() {
+        datum.0.certainty = Some(Certainty::Possible);
+    }
+    Ok(datums)
+}
+
+#[pyfunction]
+pub fn guess_from_ecosystems(repo_url: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut datums = into_registry_datums(
+        rt.block_on(ecosystems::guess_from_ecosystems(repo_url)),
+        "https://packages.ecosyste.ms/",
+    )?;
+    for datum in datums.iter_mut() {
+        datum.0.certainty = Some(Certainty::Likely);
+    }
+    Ok(datums)
+}
+
+#[pyfunction]
+#[pyo3(signature = (name, api_key=None))]
+pub fn guess_from_openhub(name: &str, api_key: Option<String>) -> PyResult<Vec<UpstreamDatum>> {
+    let api_key = match api_key.or_else(|| std::env::var(openhub::API_KEY_ENV).ok()) {
+        Some(api_key) => api_key,
+        None => {
+            return Err(PyValueError::new_err(format!(
+                "no Open Hub API key given and {} is not set",
+                openhub::API_KEY_ENV
+            )))
+        }
+    };
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut datums = into_registry_datums(
+        rt.block_on(openhub::guess_from_openhub(name, &api_key)),
+        &format!("https://www.openhub.net/p/{}", name.to_lowercase()),
+    )?;
+    for datum in datums.iter_mut() {
+        datum.0.certainty = Some(Certainty::Possible);
+    }
+    Ok(datums)
+}
+
+#[pyfunction]
+pub fn guess_from_debian_archive(name: &str) -> PyResult<Vec<UpstreamDatum>> {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut datums = into_registry_datums(
+        rt.block_on(debian_archive::guess_from_debian_archive(name)),
+        &debian_archive::DebianArchive.page_url(name),
+    )?;
+    for datum in datums.iter_mut() {
+        datum.0.certainty = Some(Certainty::Possible);
+    }
+    Ok(datums)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeRegistry;
+
+    #[async_trait::async_trait]
+    impl upstream_ontologist::ThirdPartyRepository for FakeRegistry {
+        fn name(&self) -> &'static str {
+            "Fake"
+        }
+
+        fn max_supported_certainty(&self) -> Certainty {
+            Certainty::Certain
+        }
+
+        fn supported_fields(&self) -> &'static [&'static str] {
+            &["Homepage", "Summary"]
+        }
+
+        async fn guess_metadata(
+            &self,
+            _name: &str,
+        ) -> Result<Vec<upstream_ontologist::UpstreamDatum>, ProviderError> {
+            Ok(vec![
+                upstream_ontologist::UpstreamDatum::Homepage("https://example.com/".to_string()),
+                upstream_ontologist::UpstreamDatum::Summary("A fake package".to_string()),
+            ])
+        }
+    }
+
+    impl Registry for FakeRegistry {
+        fn page_url(&self, name: &str) -> String {
+            format!("https://registry.example.com/{}", name)
+        }
+    }
+
+    fn lookup(
+        metadata: &mut upstream_ontologist::UpstreamMetadata,
+        certainty: Certainty,
+        minimum_certainty: Option<Certainty>,
+    ) {
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(extend_from_lookup(
+                metadata,
+                &FakeRegistry,
+                "fake",
+                certainty,
+                minimum_certainty,
+                &[Origin::Path("Cargo.toml".into())],
+            ));
+    }
+
+    #[test]
+    fn test_extend_from_lookup() {
+        let mut metadata = upstream_ontologist::UpstreamMetadata::new();
+        metadata.insert(UpstreamDatumWithMetadata {
+            datum: upstream_ontologist::UpstreamDatum::Homepage(
+                "https://old.example.com/".to_string(),
+            ),
+            certainty: Some(Certainty::Possible),
+            origin: None,
+        });
+        lookup(&mut metadata, Certainty::Likely, None);
+        let origin = Origin::Url("https://registry.example.com/fake".parse().unwrap());
+        let homepage = metadata.get("Homepage").unwrap();
+        assert_eq!(Some("https://example.com/"), homepage.datum.as_str());
+        assert_eq!(Some(Certainty::Likely), homepage.certainty);
+        assert_eq!(Some(&origin), homepage.origin.as_ref());
+        let summary = metadata.get("Summary").unwrap();
+        assert_eq!(Some("A fake package"), summary.datum.as_str());
+        assert_eq!(Some(&origin), summary.origin.as_ref());
+        assert_eq!(
+            vec![Origin::Path("Cargo.toml".into()), origin],
+            crate::provenance::origins(summary)
+        );
+    }
+
+    #[test]
+    fn test_extend_from_registries_source_certainty() {
+        let mut metadata = upstream_ontologist::UpstreamMetadata::new();
+        metadata.insert(UpstreamDatumWithMetadata {
+            datum: upstream_ontologist::UpstreamDatum::Repository(
+                "https://github.com/example/fork".to_string(),
+            ),
+            certainty: Some(Certainty::Possible),
+            origin: None,
+        });
+        metadata.insert(UpstreamDatumWithMetadata {
+            datum: upstream_ontologist::UpstreamDatum::Registry(vec![(
+                "PyPI".to_string(),
+                "fake".to_string(),
+            )]),
+            certainty: Some(Certainty::Possible),
+            origin: None,
+        });
+        // Possible lookups can't meet the minimum, so no registry is consulted
+        tokio::runtime::Runtime::new()
+            .unwrap()
+            .block_on(extend_from_registries(
+                &mut metadata,
+                Path::new("/nonexistent"),
+                Some(Certainty::Likely),
+            ));
+        assert_eq!(2, metadata.iter().count());
+    }
+
+    #[test]
+    fn test_extend_from_lookup_minimum_certainty() {
+        let mut metadata = upstream_ontologist::UpstreamMetadata::new();
+        lookup(&mut metadata, Certainty::Possible, Some(Certainty::Likely));
+        assert!(metadata.get("Homepage").is_none());
+    }
+}
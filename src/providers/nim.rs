@@ -0,0 +1,61 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Nim .nimble package file.
+///
+/// Nimble requires the package file to be named after the package, and packages are
+/// published in the Nimble package directory under that name.
+pub fn guess_from_nimble(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(name) = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .and_then(|n| n.strip_suffix(".nimble"))
+    {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+        push(
+            UpstreamDatum::Registry(vec![("Nimble".to_string(), name.to_string())]),
+            Certainty::Possible,
+        );
+    }
+
+    for c in regex!(r#"(?ms)^(\w+)\s*=\s*(?:"""(.*?)"""|"((?:[^"\\\n]|\\.)*)")"#)
+        .captures_iter(&contents)
+    {
+        let value = c
+            .get(2)
+            .or_else(|| c.get(3))
+            .unwrap()
+            .as_str()
+            .trim()
+            .to_string();
+        match &c[1] {
+            "version" => push(UpstreamDatum::Version(value), Certainty::Certain),
+            "description" => push(UpstreamDatum::Summary(value), Certainty::Certain),
+            "license" => push(UpstreamDatum::License(value), Certainty::Certain),
+            "author" => push(
+                UpstreamDatum::Author(value.split(',').map(|a| Person::from(a.trim())).collect()),
+                Certainty::Certain,
+            ),
+            _ => {}
+        }
+    }
+
+    Ok(results)
+}
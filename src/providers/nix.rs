@@ -0,0 +1,135 @@
+use lazy_regex::{regex, regex_captures};
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the string value of a `name = "value";` attribute.
+fn string_attribute<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    regex!(r#"(?m)(?:^|[\s{;])([\w.]+)\s*=\s*"([^"]*)"\s*;"#)
+        .captures_iter(contents)
+        .find(|c| &c[1] == name)
+        .map(|c| c.get(2).unwrap().as_str())
+}
+
+/// Find an attribute that is set to another attribute, as in `repo = pname;`.
+fn reference_attribute<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    regex!(r"(?m)(?:^|[\s{;])([\w.]+)\s*=\s*([A-Za-z_][\w]*)\s*;")
+        .captures_iter(contents)
+        .find(|c| &c[1] == name)
+        .map(|c| c.get(2).unwrap().as_str())
+}
+
+/// Guess upstream metadata from a Nix expression (flake.nix, default.nix).
+///
+/// Nix is not evaluated; this looks for the attributes that derivations conventionally use.
+pub fn guess_from_nix(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let pname = string_attribute(&contents, "pname");
+    let version = string_attribute(&contents, "version");
+    let expand = |value: &str| {
+        let mut value = value.to_string();
+        if let Some(pname) = pname {
+            value = value.replace("${pname}", pname);
+        }
+        if let Some(version) = version {
+            value = value.replace("${version}", version);
+        }
+        value
+    };
+
+    let fetcher_argument = |args: &str, name: &str| {
+        string_attribute(args, name)
+            .map(expand)
+            .or_else(|| match reference_attribute(args, name) {
+                Some("pname") => pname.map(|p| p.to_string()),
+                _ => None,
+            })
+    };
+
+    if let Some(pname) = pname {
+        push(UpstreamDatum::Name(pname.to_string()), Certainty::Likely);
+    }
+    if let Some(version) = version.filter(|v| !v.contains("${")) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(homepage) = string_attribute(&contents, "homepage") {
+        push(
+            UpstreamDatum::Homepage(expand(homepage)),
+            Certainty::Certain,
+        );
+    }
+    if let Some(description) = string_attribute(&contents, "description") {
+        push(
+            UpstreamDatum::Summary(description.to_string()),
+            Certainty::Likely,
+        );
+    }
+    // Only a single license is picked up; lists need evaluation to resolve `with`.
+    if let Some((_, license)) =
+        regex_captures!(r"license\s*=\s*(?:lib\.)?licenses\.([\w-]+)", &contents)
+    {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Likely,
+        );
+    }
+
+    for captures in
+        regex!(r"(fetchFromGitHub|fetchFromGitLab|fetchFromCodeberg|fetchgit)\s*\{([^}]*)\}")
+            .captures_iter(&contents)
+    {
+        let fetcher = captures.get(1).unwrap().as_str();
+        let args = captures.get(2).unwrap().as_str();
+        let repository = match fetcher {
+            "fetchgit" => string_attribute(args, "url").map(expand),
+            _ => {
+                let host = match fetcher {
+                    "fetchFromGitHub" => "github.com",
+                    "fetchFromGitLab" => "gitlab.com",
+                    _ => "codeberg.org",
+                };
+                match (
+                    fetcher_argument(args, "owner"),
+                    fetcher_argument(args, "repo"),
+                ) {
+                    (Some(owner), Some(repo)) => Some(format!(
+                        "https://{}/{}/{}",
+                        string_attribute(args, "domain").unwrap_or(host),
+                        owner,
+                        repo
+                    )),
+                    _ => None,
+                }
+            }
+        };
+        if let Some(repository) = repository {
+            push(UpstreamDatum::Repository(repository), Certainty::Likely);
+        }
+    }
+
+    for captures in regex!(r"fetchurl\s*\{([^}]*)\}").captures_iter(&contents) {
+        let args = captures.get(1).unwrap().as_str();
+        if let Some(url) = string_attribute(args, "url") {
+            push(UpstreamDatum::Download(expand(url)), Certainty::Possible);
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,130 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Find the name under which the package in a directory is published on npm.
+///
+/// Packages marked as private are never published, so there is nothing to look up.
+pub fn package_name(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path.join("package.json")).ok()?;
+    let data: Value = serde_json::from_reader(file).ok()?;
+    if data.get("private").and_then(Value::as_bool) == Some(true) {
+        return None;
+    }
+    data.get("name").and_then(Value::as_str).map(String::from)
+}
+
+/// The repository and bugs fields can either be a plain string or an object with a url.
+fn url_field(value: &Value) -> Option<&str> {
+    match value {
+        Value::String(s) => Some(s.as_str()),
+        Value::Object(o) => o.get("url").and_then(Value::as_str),
+        _ => None,
+    }
+}
+
+/// Look up a package in the npm registry.
+pub async fn remote_npm_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    // Scoped packages keep the @, but the slash has to be escaped
+    let url = format!("https://registry.npmjs.org/{}", name.replace('/', "%2F"))
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_npm_data(&data))
+}
+
+/// Extract metadata from an npm registry packument.
+fn parse_npm_data(data: &Value) -> Vec<UpstreamDatum> {
+    let mut datums = Vec::new();
+    if let Some(name) = data.get("name").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::Name(name.to_string()));
+    }
+    if let Some(repository) = data.get("repository").and_then(url_field) {
+        let repository = repository.strip_prefix("git+").unwrap_or(repository);
+        datums.push(UpstreamDatum::Repository(repository.to_string()));
+    }
+    if let Some(bugs) = data.get("bugs").and_then(url_field) {
+        datums.push(UpstreamDatum::BugDatabase(bugs.to_string()));
+    }
+    if let Some(homepage) = data.get("homepage").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::Homepage(homepage.to_string()));
+    }
+    if let Some(license) = data.get("license").and_then(Value::as_str) {
+        datums.push(UpstreamDatum::License(license.to_string()));
+    }
+    if let Some(version) = data
+        .get("dist-tags")
+        .and_then(|tags| tags.get("latest"))
+        .and_then(Value::as_str)
+    {
+        datums.push(UpstreamDatum::Version(version.to_string()));
+    }
+
+    datums
+}
+
+pub struct Npm;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Npm {
+    fn name(&self) -> &'static str {
+        "npm"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Bug-Database", "License"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("the npm registry", remote_npm_data(name).await)
+    }
+}
+
+impl super::Registry for Npm {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://www.npmjs.com/package/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_npm_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "name": "@babel/core",
+                "dist-tags": {"latest": "7.26.0", "next": "8.0.0-alpha.13"},
+                "repository": {
+                    "type": "git",
+                    "url": "git+https://github.com/babel/babel.git",
+                    "directory": "packages/babel-core"
+                },
+                "bugs": "https://github.com/babel/babel/issues",
+                "homepage": "https://babel.dev/docs/en/next/babel-core",
+                "license": "MIT"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_npm_data(&data),
+            vec![
+                UpstreamDatum::Name("@babel/core".to_string()),
+                UpstreamDatum::Repository("https://github.com/babel/babel.git".to_string()),
+                UpstreamDatum::BugDatabase("https://github.com/babel/babel/issues".to_string()),
+                UpstreamDatum::Homepage("https://babel.dev/docs/en/next/babel-core".to_string()),
+                UpstreamDatum::License("MIT".to_string()),
+                UpstreamDatum::Version("7.26.0".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use lazy_regex::regex_captures;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from an opam file.
+///
+/// upstream_ontologist only understands dev-repo when it is written as a section, so
+/// also pick it up when it is a plain variable - which is how most opam files spell it.
+pub fn guess_from_opam(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let mut results =
+        upstream_ontologist::providers::ocaml::guess_from_opam(path, settings.trust_package)?;
+
+    if !results
+        .iter()
+        .any(|d| matches!(d.datum, UpstreamDatum::Repository(_)))
+    {
+        let contents = std::fs::read_to_string(path)?;
+        if let Some((_, url)) = regex_captures!(r#"(?m)^dev-repo:\s*"([^"]+)""#, &contents) {
+            results.push(UpstreamDatumWithMetadata {
+                datum: UpstreamDatum::Repository(
+                    url.strip_prefix("git+").unwrap_or(url).to_string(),
+                ),
+                certainty: Some(Certainty::Certain),
+                origin: Some(path.into()),
+            });
+        }
+    }
+
+    Ok(results)
+}
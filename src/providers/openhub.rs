@@ -0,0 +1,174 @@
+use upstream_ontologist::{Certainty, ProviderError, UpstreamDatum};
+use xmltree::Element;
+
+/// Environment variable that holds the Open Hub API key.
+pub const API_KEY_ENV: &str = "OPENHUB_API_KEY";
+
+/// Look up a project on Open Hub.
+///
+/// Projects are identified by their URL name, which is usually the lowercased project name.
+pub async fn guess_from_openhub(
+    name: &str,
+    api_key: &str,
+) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let mut url: url::Url = format!("https://www.openhub.net/p/{}.xml", name.to_lowercase())
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    url.query_pairs_mut().append_pair("api_key", api_key);
+
+    let client = upstream_ontologist::http::build_client()
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    match response.status() {
+        reqwest::StatusCode::NOT_FOUND => {
+            return Ok(vec![]);
+        }
+        status if !status.is_success() => {
+            return Err(ProviderError::Other(format!("HTTP error: {}", status)));
+        }
+        _ => {}
+    }
+    let text = response
+        .text()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    parse_project(&text)
+}
+
+/// Extract metadata from an Open Hub project API response.
+fn parse_project(text: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let root =
+        Element::parse(text.as_bytes()).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+    let get_text = |element: &Element, tag| {
+        element
+            .get_child(tag)
+            .and_then(|e| e.get_text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+    // Errors, such as an invalid API key, are reported in the body
+    if get_text(&root, "status").as_deref() != Some("success") {
+        return Err(ProviderError::Other(
+            get_text(&root, "error").unwrap_or_else(|| "unknown error".to_string()),
+        ));
+    }
+    let project = match root
+        .get_child("result")
+        .and_then(|r| r.get_child("project"))
+    {
+        Some(project) => project,
+        None => return Ok(vec![]),
+    };
+
+    let mut datums = Vec::new();
+    if let Some(homepage) = get_text(project, "homepage_url") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(download) = get_text(project, "download_url") {
+        datums.push(UpstreamDatum::Download(download));
+    }
+    // These are the licenses found in the code, so all of them apply
+    let licenses = project
+        .get_child("licenses")
+        .map(|licenses| {
+            licenses
+                .children
+                .iter()
+                .filter_map(|c| c.as_element())
+                .filter_map(|license| get_text(license, "name"))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" AND ")));
+    }
+
+    Ok(datums)
+}
+
+pub struct OpenHub {
+    pub api_key: String,
+}
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for OpenHub {
+    fn name(&self) -> &'static str {
+        "Open Hub"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Possible
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Download", "License"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("Open Hub", guess_from_openhub(name, &self.api_key).await)
+    }
+}
+
+impl super::Registry for OpenHub {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://www.openhub.net/p/{}", name.to_lowercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+  <status>success</status>
+  <result>
+    <project>
+      <id>1</id>
+      <name>Subversion</name>
+      <url_name>subversion</url_name>
+      <homepage_url>https://subversion.apache.org/</homepage_url>
+      <download_url>https://subversion.apache.org/download.cgi</download_url>
+      <licenses>
+        <license>
+          <name>Apache-2.0</name>
+          <nice_name>Apache License 2.0</nice_name>
+        </license>
+        <license>
+          <name>BSD-3-Clause</name>
+          <nice_name>BSD 3-clause "New" or "Revised" License</nice_name>
+        </license>
+      </licenses>
+    </project>
+  </result>
+</response>"#;
+        assert_eq!(
+            parse_project(text).unwrap(),
+            vec![
+                UpstreamDatum::Homepage("https://subversion.apache.org/".to_string()),
+                UpstreamDatum::Download("https://subversion.apache.org/download.cgi".to_string()),
+                UpstreamDatum::License("Apache-2.0 AND BSD-3-Clause".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_project_error() {
+        let text = r#"<?xml version="1.0" encoding="UTF-8"?>
+<response>
+  <status>failed</status>
+  <error>Invalid API key</error>
+</response>"#;
+        match parse_project(text) {
+            Err(ProviderError::Other(msg)) => assert_eq!(msg, "Invalid API key"),
+            r => panic!("unexpected result: {:?}", r),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a PEAR/PECL or ROS package.xml file.
+///
+/// On top of what upstream_ontologist extracts, this records the Pecl-Package for PECL
+/// extensions, and the plain version and `<url type="website">` elements used by ROS.
+pub fn guess_from_package_xml(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let mut results =
+        upstream_ontologist::providers::package_xml::guess_from_package_xml(path, settings)?;
+    let contents = std::fs::read_to_string(path)?;
+
+    let is_pecl = regex!(r"<channel>\s*pecl\.php\.net\s*</channel>").is_match(&contents)
+        || regex!(r"<providesextension>").is_match(&contents);
+    if is_pecl {
+        let name = results.iter().find_map(|d| match &d.datum {
+            UpstreamDatum::Name(name) => Some(name.clone()),
+            _ => None,
+        });
+        if let Some(name) = name {
+            results.push(UpstreamDatumWithMetadata {
+                datum: UpstreamDatum::PeclPackage(name),
+                certainty: Some(Certainty::Certain),
+                origin: Some(path.into()),
+            });
+        }
+    }
+
+    if !results
+        .iter()
+        .any(|d| matches!(d.datum, UpstreamDatum::Version(_)))
+    {
+        if let Some(captures) = regex!(r"<version>([^<]+)</version>").captures(&contents) {
+            results.push(UpstreamDatumWithMetadata {
+                datum: UpstreamDatum::Version(captures[1].trim().to_string()),
+                certainty: Some(Certainty::Certain),
+                origin: Some(path.into()),
+            });
+        }
+    }
+
+    for captures in regex!(r#"<url(\s+type="([^"]*)")?\s*>([^<]+)</url>"#).captures_iter(&contents)
+    {
+        // ROS defaults to "website" when no type is given
+        let url_type = captures.get(2).map_or("website", |m| m.as_str());
+        if url_type == "website" {
+            results.push(UpstreamDatumWithMetadata {
+                datum: UpstreamDatum::Homepage(captures[3].trim().to_string()),
+                certainty: Some(Certainty::Certain),
+                origin: Some(path.into()),
+            });
+        }
+    }
+
+    Ok(results)
+}
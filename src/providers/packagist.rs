@@ -0,0 +1,160 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Find the vendor/package name under which a composer package is published.
+pub fn package_name(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path.join("composer.json")).ok()?;
+    let data: Value = serde_json::from_reader(file).ok()?;
+    data.get("name")
+        .and_then(Value::as_str)
+        .filter(|name| name.contains('/'))
+        .map(String::from)
+}
+
+/// Look up a composer package on packagist.org.
+pub async fn remote_packagist_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://repo.packagist.org/p2/{}.json", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_packagist_data(name, &data))
+}
+
+/// Extract metadata for a package from a packagist.org metadata response.
+fn parse_packagist_data(name: &str, data: &Value) -> Vec<UpstreamDatum> {
+    // Releases are listed newest first; later entries only record what changed, so only
+    // the first one is complete.
+    let release = match data["packages"].get(name).and_then(|v| v.get(0)) {
+        Some(release) => release,
+        None => return vec![],
+    };
+    let get_str = |value: &Value, key| {
+        value
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    };
+
+    let mut datums = vec![UpstreamDatum::Name(name.to_string())];
+    if let Some(version) = get_str(release, "version") {
+        datums.push(UpstreamDatum::Version(
+            version.trim_start_matches('v').to_string(),
+        ));
+    }
+    if let Some(description) = get_str(release, "description") {
+        datums.push(UpstreamDatum::Summary(description));
+    }
+    if let Some(homepage) = get_str(release, "homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(repository) = get_str(&release["source"], "url") {
+        datums.push(UpstreamDatum::Repository(repository));
+    }
+    if let Some(issues) = get_str(&release["support"], "issues") {
+        datums.push(UpstreamDatum::BugDatabase(issues));
+    }
+    let licenses = release
+        .get("license")
+        .and_then(Value::as_array)
+        .map(|licenses| {
+            licenses
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" OR ")));
+    }
+
+    datums
+}
+
+pub struct Packagist;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Packagist {
+    fn name(&self) -> &'static str {
+        "Packagist"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Bug-Database",
+            "Summary",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("packagist.org", remote_packagist_data(name).await)
+    }
+}
+
+impl super::Registry for Packagist {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://packagist.org/packages/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_packagist_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "packages": {
+                    "monolog/monolog": [
+                        {
+                            "name": "monolog/monolog",
+                            "description": "Sends your logs to files, sockets, inboxes, databases and various web services",
+                            "homepage": "https://github.com/Seldaek/monolog",
+                            "version": "v3.8.0",
+                            "license": ["MIT"],
+                            "source": {"url": "https://github.com/Seldaek/monolog.git", "type": "git"},
+                            "support": {"issues": "https://github.com/Seldaek/monolog/issues"}
+                        },
+                        {"version": "3.7.0"}
+                    ]
+                },
+                "minified": "composer/2.0"
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_packagist_data("monolog/monolog", &data),
+            vec![
+                UpstreamDatum::Name("monolog/monolog".to_string()),
+                UpstreamDatum::Version("3.8.0".to_string()),
+                UpstreamDatum::Summary(
+                    "Sends your logs to files, sockets, inboxes, databases and various web services"
+                        .to_string()
+                ),
+                UpstreamDatum::Homepage("https://github.com/Seldaek/monolog".to_string()),
+                UpstreamDatum::Repository("https://github.com/Seldaek/monolog.git".to_string()),
+                UpstreamDatum::BugDatabase("https://github.com/Seldaek/monolog/issues".to_string()),
+                UpstreamDatum::License("MIT".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_packagist_data_no_releases() {
+        let data: Value = serde_json::from_str(r#"{"packages": {"foo/bar": []}}"#).unwrap();
+        assert_eq!(parse_packagist_data("foo/bar", &data), vec![]);
+    }
+}
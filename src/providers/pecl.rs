@@ -0,0 +1,149 @@
+use upstream_ontologist::{Certainty, ProviderError, UpstreamDatum};
+use xmltree::Element;
+
+/// Fetch a document from the PECL REST interface, returning None if it doesn't exist.
+async fn fetch_rest(client: &reqwest::Client, path: &str) -> Result<Option<String>, ProviderError> {
+    let response = client
+        .get(format!("https://pecl.php.net/rest/{}", path))
+        .send()
+        .await
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+
+    match response.status() {
+        reqwest::StatusCode::NOT_FOUND => {
+            return Ok(None);
+        }
+        status if !status.is_success() => {
+            return Err(ProviderError::Other(format!("HTTP error: {}", status)));
+        }
+        _ => {}
+    }
+
+    response
+        .text()
+        .await
+        .map(Some)
+        .map_err(|e| ProviderError::Other(e.to_string()))
+}
+
+/// Look up a package on PECL.
+///
+/// The REST interface provides the license, summary and releases; it doesn't include the
+/// homepage, so that is taken from the package page.
+pub async fn remote_pecl_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let client = upstream_ontologist::http::build_client()
+        // PECL is slow
+        .timeout(std::time::Duration::from_secs(15))
+        .build()
+        .map_err(|e| ProviderError::Other(e.to_string()))?;
+    let lower = name.to_lowercase();
+
+    let info = match fetch_rest(&client, &format!("p/{}/info.xml", lower)).await? {
+        Some(info) => info,
+        None => return Ok(vec![]),
+    };
+    let mut datums = parse_package_info(&info)?;
+    if let Some(version) = fetch_rest(&client, &format!("r/{}/latest.txt", lower)).await? {
+        let version = version.trim();
+        if !version.is_empty() {
+            datums.push(UpstreamDatum::Version(version.to_string()));
+        }
+    }
+    datums.extend(upstream_ontologist::providers::php::guess_from_pecl_package(name).await?);
+
+    Ok(datums)
+}
+
+/// Extract metadata from a package's info.xml in the PECL REST interface.
+fn parse_package_info(info: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let root =
+        Element::parse(info.as_bytes()).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+    let get_text = |tag| {
+        root.get_child(tag)
+            .and_then(|e| e.get_text())
+            .map(|text| text.trim().to_string())
+            .filter(|text| !text.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_text("n") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(license) = get_text("l") {
+        datums.push(UpstreamDatum::License(license));
+    }
+    if let Some(summary) = get_text("s") {
+        datums.push(UpstreamDatum::Summary(summary));
+    }
+    if let Some(description) = get_text("d") {
+        datums.push(UpstreamDatum::Description(description));
+    }
+
+    Ok(datums)
+}
+
+pub struct Pecl;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Pecl {
+    fn name(&self) -> &'static str {
+        "PECL"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Bug-Database",
+            "License",
+            "Summary",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("PECL", remote_pecl_data(name).await)
+    }
+}
+
+impl super::Registry for Pecl {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://pecl.php.net/package/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_package_info() {
+        let info = r#"<?xml version="1.0" encoding="UTF-8" ?>
+<p xmlns="http://pear.php.net/dtd/rest.package" xsi:schemaLocation="http://pear.php.net/dtd/rest.package http://pear.php.net/dtd/rest.package.xsd" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:xlink="http://www.w3.org/1999/xlink">
+ <n>redis</n>
+ <c>pecl.php.net</c>
+ <ca xlink:href="/rest/c/Database">Database</ca>
+ <l>PHP</l>
+ <s>PHP extension for interfacing with key-value stores</s>
+ <d>This extension provides an API for communicating with Redis servers.</d>
+ <r xlink:href="/rest/r/redis"/>
+</p>"#;
+        assert_eq!(
+            parse_package_info(info).unwrap(),
+            vec![
+                UpstreamDatum::Name("redis".to_string()),
+                UpstreamDatum::License("PHP".to_string()),
+                UpstreamDatum::Summary(
+                    "PHP extension for interfacing with key-value stores".to_string()
+                ),
+                UpstreamDatum::Description(
+                    "This extension provides an API for communicating with Redis servers."
+                        .to_string()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Origin, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Makefile.PL file.
+///
+/// upstream_ontologist only understands the Module::Install syntax; this also picks up the
+/// arguments passed to ExtUtils::MakeMaker's WriteMakefile, including META_MERGE resources.
+pub fn guess_from_makefile_pl(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let mut results = upstream_ontologist::providers::perl::guess_from_makefile_pl(path, settings)?;
+    let contents = std::fs::read_to_string(path)?;
+    results.extend(guess_from_makemaker_args(&contents, &path.into()));
+    Ok(results)
+}
+
+fn guess_from_makemaker_args(contents: &str, origin: &Origin) -> Vec<UpstreamDatumWithMetadata> {
+    let mut results = Vec::new();
+    let mut push = |datum| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Confident),
+            origin: Some(origin.clone()),
+        })
+    };
+
+    for captures in regex!(r#"['"]?\b(\w+)['"]?\s*=>\s*['"]([^'"]+)['"]"#).captures_iter(contents) {
+        let key = captures.get(1).unwrap().as_str();
+        let value = captures.get(2).unwrap().as_str();
+        match key {
+            "NAME" => push(UpstreamDatum::Name(value.replace("::", "-"))),
+            "ABSTRACT" => push(UpstreamDatum::Summary(value.to_string())),
+            "LICENSE" | "license" => push(UpstreamDatum::License(value.to_string())),
+            "repository" => push(UpstreamDatum::Repository(value.to_string())),
+            "bugtracker" => push(UpstreamDatum::BugDatabase(value.to_string())),
+            "homepage" => push(UpstreamDatum::Homepage(value.to_string())),
+            _ => {}
+        }
+    }
+
+    // CPAN::Meta::Spec version 2 style resources, e.g.
+    // repository => { type => 'git', url => '...', web => '...' }
+    for captures in regex!(r"\b(repository|bugtracker)\s*=>\s*\{([^}]*)\}").captures_iter(contents)
+    {
+        let kind = captures.get(1).unwrap().as_str();
+        for entry in regex!(r#"\b(url|web)\s*=>\s*['"]([^'"]+)['"]"#)
+            .captures_iter(captures.get(2).unwrap().as_str())
+        {
+            let value = entry.get(2).unwrap().as_str().to_string();
+            match (kind, entry.get(1).unwrap().as_str()) {
+                ("repository", "url") => push(UpstreamDatum::Repository(value)),
+                ("repository", "web") => push(UpstreamDatum::RepositoryBrowse(value)),
+                ("bugtracker", "web") => push(UpstreamDatum::BugDatabase(value)),
+                _ => {}
+            }
+        }
+    }
+
+    results
+}
+
+/// Guess upstream metadata from POD documentation.
+///
+/// In addition to NAME and DESCRIPTION, this picks up links listed under SEE ALSO as
+/// candidate homepages.
+pub fn guess_from_pod(
+    contents: &str,
+    origin: &Origin,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let mut results = upstream_ontologist::providers::perl::guess_from_pod(contents, origin)?;
+
+    let see_also = contents
+        .lines()
+        .skip_while(|line| !line.eq_ignore_ascii_case("=head1 SEE ALSO"))
+        .skip(1)
+        .take_while(|line| !line.starts_with("=head1 "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    for captures in regex!(r"L<(?:[^|>]*\|)?(https?://[^>]+)>").captures_iter(&see_also) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Homepage(captures[1].to_string()),
+            certainty: Some(Certainty::Likely),
+            origin: Some(origin.clone()),
+        });
+    }
+
+    Ok(results)
+}
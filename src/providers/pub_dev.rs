@@ -0,0 +1,136 @@
+use serde_yaml::Value as YamlValue;
+use std::path::Path;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Find the name under which the Dart package in a directory is published on pub.dev.
+///
+/// Packages with `publish_to: none` are never published, so there is nothing to look up.
+pub fn package_name(path: &Path) -> Option<String> {
+    let file = std::fs::File::open(path.join("pubspec.yaml")).ok()?;
+    let data: YamlValue = serde_yaml::from_reader(file).ok()?;
+    if data.get("publish_to").and_then(YamlValue::as_str) == Some("none") {
+        return None;
+    }
+    data.get("name")
+        .and_then(YamlValue::as_str)
+        .map(String::from)
+}
+
+/// Look up a Dart package on pub.dev.
+pub async fn remote_pub_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://pub.dev/api/packages/{}", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_pub_data(&data))
+}
+
+/// Extract metadata from a pub.dev package API response.
+fn parse_pub_data(data: &serde_json::Value) -> Vec<UpstreamDatum> {
+    let latest = &data["latest"];
+    let pubspec = &latest["pubspec"];
+    let get_str = |key| {
+        pubspec
+            .get(key)
+            .and_then(serde_json::Value::as_str)
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+    };
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_str("name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(version) = latest.get("version").and_then(serde_json::Value::as_str) {
+        datums.push(UpstreamDatum::Version(version.to_string()));
+    }
+    if let Some(description) = get_str("description") {
+        datums.push(UpstreamDatum::Summary(description));
+    }
+    if let Some(homepage) = get_str("homepage") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(repository) = get_str("repository") {
+        datums.push(UpstreamDatum::Repository(repository));
+    }
+    if let Some(issue_tracker) = get_str("issue_tracker") {
+        datums.push(UpstreamDatum::BugDatabase(issue_tracker));
+    }
+    if let Some(documentation) = get_str("documentation") {
+        datums.push(UpstreamDatum::Documentation(documentation));
+    }
+
+    datums
+}
+
+pub struct PubDev;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for PubDev {
+    fn name(&self) -> &'static str {
+        "pub.dev"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Bug-Database", "Summary"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("pub.dev", remote_pub_data(name).await)
+    }
+}
+
+impl super::Registry for PubDev {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://pub.dev/packages/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pub_data() {
+        let data: serde_json::Value = serde_json::from_str(
+            r#"{
+                "name": "http",
+                "latest": {
+                    "version": "1.2.2",
+                    "pubspec": {
+                        "name": "http",
+                        "version": "1.2.2",
+                        "description": "A composable, multi-platform, Future-based API for HTTP requests.",
+                        "repository": "https://github.com/dart-lang/http/tree/master/pkgs/http",
+                        "issue_tracker": "https://github.com/dart-lang/http/issues",
+                        "homepage": ""
+                    }
+                },
+                "versions": []
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_pub_data(&data),
+            vec![
+                UpstreamDatum::Name("http".to_string()),
+                UpstreamDatum::Version("1.2.2".to_string()),
+                UpstreamDatum::Summary(
+                    "A composable, multi-platform, Future-based API for HTTP requests.".to_string()
+                ),
+                UpstreamDatum::Repository(
+                    "https://github.com/dart-lang/http/tree/master/pkgs/http".to_string()
+                ),
+                UpstreamDatum::BugDatabase("https://github.com/dart-lang/http/issues".to_string()),
+            ]
+        );
+    }
+}
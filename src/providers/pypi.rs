@@ -0,0 +1,180 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Map the label of a PyPI project URL to the datum it describes.
+///
+/// Labels are free-form, but most projects use one of a handful of conventional names.
+fn project_url_datum(label: &str, url: String) -> Option<UpstreamDatum> {
+    match label.to_lowercase().replace(['-', '_'], " ").as_str() {
+        "source" | "source code" | "code" | "repository" | "github" | "gitlab" => {
+            Some(UpstreamDatum::Repository(url))
+        }
+        "homepage" | "home" | "website" => Some(UpstreamDatum::Homepage(url)),
+        "documentation" | "docs" => Some(UpstreamDatum::Documentation(url)),
+        "bug tracker" | "bug reports" | "issues" | "issue tracker" | "tracker" => {
+            Some(UpstreamDatum::BugDatabase(url))
+        }
+        "changelog" | "changes" | "release notes" => Some(UpstreamDatum::Changelog(url)),
+        "funding" | "donate" | "sponsor" => Some(UpstreamDatum::Donation(url)),
+        _ => None,
+    }
+}
+
+/// Look up a project on PyPI.
+pub async fn remote_pypi_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://pypi.org/pypi/{}/json", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_pypi_data(&data))
+}
+
+/// Extract metadata from a PyPI JSON API response.
+fn parse_pypi_data(data: &Value) -> Vec<UpstreamDatum> {
+    let info = &data["info"];
+    // Unset fields are either null, empty or (for older uploads) "UNKNOWN"
+    let get_str = |key| {
+        info.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty() && *s != "UNKNOWN")
+            .map(String::from)
+    };
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_str("name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(summary) = get_str("summary") {
+        datums.push(UpstreamDatum::Summary(summary));
+    }
+    if let Some(version) = get_str("version") {
+        datums.push(UpstreamDatum::Version(version));
+    }
+    if let Some(home_page) = get_str("home_page") {
+        datums.push(UpstreamDatum::Homepage(home_page));
+    }
+    // The license field often contains the full license text rather than an identifier
+    if let Some(license) = get_str("license_expression")
+        .or_else(|| get_str("license").filter(|license| !license.contains('\n')))
+    {
+        datums.push(UpstreamDatum::License(license));
+    }
+    if let Some(urls) = info.get("project_urls").and_then(Value::as_object) {
+        for (label, url) in urls {
+            if let Some(datum) = url
+                .as_str()
+                .and_then(|url| project_url_datum(label, url.to_string()))
+            {
+                datums.push(datum);
+            }
+        }
+    }
+
+    datums
+}
+
+pub struct PyPI;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for PyPI {
+    fn name(&self) -> &'static str {
+        "PyPI"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Documentation",
+            "Bug-Database",
+            "Changelog",
+            "Donation",
+            "Summary",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("PyPI", remote_pypi_data(name).await)
+    }
+}
+
+impl super::Registry for PyPI {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://pypi.org/project/{}/", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pypi_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "info": {
+                    "name": "dulwich",
+                    "summary": "Python Git Library",
+                    "version": "0.22.1",
+                    "home_page": "",
+                    "license": "Apache License, Version 2.0 or GNU General Public License v2 or later",
+                    "license_expression": null,
+                    "project_urls": {
+                        "Homepage": "https://www.dulwich.io/",
+                        "Repository": "https://github.com/dulwich/dulwich",
+                        "GitHub": "https://github.com/dulwich/dulwich",
+                        "Bug Tracker": "https://github.com/dulwich/dulwich/issues",
+                        "Twitter": "https://twitter.com/dulwich"
+                    }
+                },
+                "releases": {}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_pypi_data(&data),
+            vec![
+                UpstreamDatum::Name("dulwich".to_string()),
+                UpstreamDatum::Summary("Python Git Library".to_string()),
+                UpstreamDatum::Version("0.22.1".to_string()),
+                UpstreamDatum::License(
+                    "Apache License, Version 2.0 or GNU General Public License v2 or later"
+                        .to_string()
+                ),
+                UpstreamDatum::BugDatabase("https://github.com/dulwich/dulwich/issues".to_string()),
+                UpstreamDatum::Repository("https://github.com/dulwich/dulwich".to_string()),
+                UpstreamDatum::Homepage("https://www.dulwich.io/".to_string()),
+                UpstreamDatum::Repository("https://github.com/dulwich/dulwich".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_pypi_data_skips_unknown_and_license_text() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "info": {
+                    "name": "foo",
+                    "summary": "UNKNOWN",
+                    "license": "Copyright (c) 2010\n\nPermission is hereby granted...",
+                    "project_urls": null
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_pypi_data(&data),
+            vec![UpstreamDatum::Name("foo".to_string())]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use std::path::Path;
+use upstream_ontologist::{Certainty, UpstreamDatum, UpstreamDatumWithMetadata};
+
+/// Read the Docs configuration files, in order of preference.
+const CONFIG_FILES: &[&str] = &[".readthedocs.yaml", ".readthedocs.yml", "readthedocs.yml"];
+
+/// Derive the Read the Docs URL for a project from its name.
+///
+/// Project slugs are lowercase, with dashes instead of underscores and dots.
+pub fn readthedocs_url(name: &str) -> String {
+    format!(
+        "https://{}.readthedocs.io/",
+        name.to_lowercase().replace(['_', '.', ' '], "-")
+    )
+}
+
+async fn url_exists(url: &str) -> Option<bool> {
+    let client = upstream_ontologist::http::build_client().build().ok()?;
+    let response = client.head(url).send().await.ok()?;
+    Some(response.status().is_success())
+}
+
+/// Add a Documentation entry for projects that are built on Read the Docs.
+///
+/// The configuration file doesn't name the project, so the URL is derived from the
+/// project name. With network access, the URL is checked to exist.
+pub async fn extend_from_readthedocs(
+    metadata: &mut upstream_ontologist::UpstreamMetadata,
+    path: &Path,
+    net_access: bool,
+) {
+    if metadata.contains_key("Documentation") {
+        return;
+    }
+    let config = match CONFIG_FILES.iter().find(|f| path.join(f).exists()) {
+        Some(config) => config,
+        None => return,
+    };
+    let url = match metadata.name() {
+        Some(name) => readthedocs_url(name),
+        None => return,
+    };
+
+    let certainty = if net_access {
+        match url_exists(&url).await {
+            Some(true) => Certainty::Likely,
+            Some(false) => return,
+            None => Certainty::Possible,
+        }
+    } else {
+        Certainty::Possible
+    };
+    metadata.insert(UpstreamDatumWithMetadata {
+        datum: UpstreamDatum::Documentation(url),
+        certainty: Some(certainty),
+        origin: Some(path.join(config).as_path().into()),
+    });
+}
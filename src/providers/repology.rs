@@ -0,0 +1,181 @@
+use serde_json::Value;
+use std::collections::HashMap;
+use upstream_ontologist::{Certainty, HTTPJSONError, ProviderError, UpstreamDatum};
+
+/// Query repology.org for what distributions know about a project.
+///
+/// Every package of the project contributes its homepage, licenses and summary; values
+/// from up-to-date packages count more than those from outdated ones, and the most popular
+/// value wins. Homepages on code forges also give the repository, and the version of the
+/// newest packages is returned as the latest version.
+pub async fn consult_repology(project_name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!(
+        "https://repology.org/api/v1/project/{}",
+        project_name.to_lowercase()
+    )
+    .parse()
+    .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_repology_data(&data))
+}
+
+/// Pick the most popular values from the packages in a repology.org project response.
+fn parse_repology_data(data: &Value) -> Vec<UpstreamDatum> {
+    let packages = match data {
+        Value::Array(packages) => packages,
+        _ => return vec![],
+    };
+
+    let mut scores: HashMap<&str, HashMap<String, usize>> = HashMap::new();
+    let mut add = |field, value: &str, score| {
+        *scores
+            .entry(field)
+            .or_default()
+            .entry(value.to_string())
+            .or_default() += score;
+    };
+    let mut newest_version = None;
+    for package in packages {
+        let status = package.get("status").and_then(Value::as_str);
+        let score = match status {
+            Some("newest") | Some("unique") | Some("devel") => 10,
+            _ => 1,
+        };
+        let strings = |key| {
+            package
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|values| values.iter().filter_map(Value::as_str).collect::<Vec<_>>())
+                .unwrap_or_default()
+        };
+        for www in strings("www") {
+            add("Homepage", www, score);
+        }
+        for license in strings("licenses") {
+            add("License", license, score);
+        }
+        if let Some(summary) = package.get("summary").and_then(Value::as_str) {
+            add("Summary", summary, score);
+        }
+        if matches!(status, Some("newest") | Some("unique")) && newest_version.is_none() {
+            newest_version = package.get("version").and_then(Value::as_str);
+        }
+    }
+
+    let best = |field| {
+        scores.get(field).and_then(|values| {
+            values
+                .iter()
+                // Break ties deterministically
+                .max_by(|a, b| a.1.cmp(b.1).then_with(|| b.0.cmp(a.0)))
+                .map(|(value, _)| value.clone())
+        })
+    };
+
+    let mut datums = Vec::new();
+    if let Some(homepage) = best("Homepage") {
+        if let Some(repository) = super::forge_repository_url(&homepage) {
+            datums.push(UpstreamDatum::Repository(repository));
+        }
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(license) = best("License") {
+        datums.push(UpstreamDatum::License(license));
+    }
+    if let Some(summary) = best("Summary") {
+        datums.push(UpstreamDatum::Summary(summary));
+    }
+    if let Some(version) = newest_version {
+        datums.push(UpstreamDatum::Version(version.to_string()));
+    }
+
+    datums
+}
+
+pub struct Repology;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Repology {
+    fn name(&self) -> &'static str {
+        "Repology"
+    }
+
+    // Projects are matched by name, which may well be a different project
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Possible
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "License", "Summary"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("Repology", consult_repology(name).await)
+    }
+}
+
+impl super::Registry for Repology {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://repology.org/project/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_repology_data() {
+        let data: Value = serde_json::from_str(
+            r#"[
+                {
+                    "repo": "debian_unstable",
+                    "srcname": "dulwich",
+                    "visiblename": "dulwich",
+                    "version": "0.22.1",
+                    "summary": "Python Git library",
+                    "licenses": ["GPL-2+", "Apache-2.0"],
+                    "www": ["https://www.dulwich.io/"],
+                    "status": "newest"
+                },
+                {
+                    "repo": "fedora_rawhide",
+                    "srcname": "python-dulwich",
+                    "version": "0.22.1",
+                    "summary": "Python implementation of the Git file formats and protocols",
+                    "licenses": ["GPL-2.0-or-later OR Apache-2.0"],
+                    "www": ["https://www.dulwich.io/"],
+                    "status": "newest"
+                },
+                {
+                    "repo": "ubuntu_20_04",
+                    "srcname": "dulwich",
+                    "version": "0.19.15",
+                    "summary": "Python Git library",
+                    "www": ["https://github.com/dulwich/dulwich"],
+                    "status": "outdated"
+                }
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_repology_data(&data),
+            vec![
+                UpstreamDatum::Homepage("https://www.dulwich.io/".to_string()),
+                UpstreamDatum::License("Apache-2.0".to_string()),
+                UpstreamDatum::Summary("Python Git library".to_string()),
+                UpstreamDatum::Version("0.22.1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_repology_data_not_a_list() {
+        let data: Value = serde_json::from_str(r#"{"error": "not found"}"#).unwrap();
+        assert_eq!(parse_repology_data(&data), vec![]);
+    }
+}
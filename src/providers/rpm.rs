@@ -0,0 +1,119 @@
+use lazy_regex::{regex, regex_captures};
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Expand %{macro}, %{?macro} and %macro references to known macros.
+///
+/// Returns None if the value refers to macros that are not defined in the spec file.
+fn expand_macros(value: &str, macros: &HashMap<String, String>) -> Option<String> {
+    let mut missing = false;
+    let expanded =
+        regex!(r"%(\{(\??)(\w+)\}|(\w+))").replace_all(value, |c: &lazy_regex::Captures| {
+            let name = c.get(3).or_else(|| c.get(4)).unwrap().as_str();
+            match macros.get(name) {
+                Some(v) => v.clone(),
+                // Conditional macros expand to nothing when undefined, e.g. %{?dist}
+                None if c.get(2).is_some_and(|m| m.as_str() == "?") => String::new(),
+                None => {
+                    missing = true;
+                    String::new()
+                }
+            }
+        });
+    if missing {
+        None
+    } else {
+        Some(expanded.into_owned())
+    }
+}
+
+/// Guess upstream metadata from an RPM spec file.
+pub fn guess_from_rpm_spec(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut macros = HashMap::new();
+    let mut tags = Vec::new();
+    let mut description: Option<Vec<&str>> = None;
+    // Only the preamble and description of the main package are used, not those of
+    // subpackages.
+    let mut in_preamble = true;
+    let mut in_description = false;
+
+    for line in contents.lines() {
+        if let Some((_, name, value)) =
+            regex_captures!(r"^%(?:define|global)\s+(\w+)\s+(.*)$", line)
+        {
+            macros.insert(name.to_string(), value.trim().to_string());
+        } else if let Some((_, section)) = regex_captures!(
+            r"^%(package|description|prep|build|install|check|files|changelog|pre|post|preun|postun)\b",
+            line
+        ) {
+            in_description =
+                section == "description" && line.trim() == "%description" && description.is_none();
+            if in_description {
+                description = Some(Vec::new());
+            }
+            in_preamble = false;
+        } else if in_description {
+            description.as_mut().unwrap().push(line);
+        } else if !in_preamble {
+            continue;
+        } else if let Some((_, tag, value)) = regex_captures!(r"^(\w+):\s*(.*)$", line) {
+            let tag = tag.to_lowercase();
+            if tag == "name" || tag == "version" {
+                macros.insert(tag.clone(), value.trim().to_string());
+            }
+            tags.push((tag, value.trim().to_string()));
+        }
+    }
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    for (tag, value) in tags {
+        let value = match expand_macros(&value, &macros) {
+            Some(value) => value,
+            None => continue,
+        };
+        match tag.as_str() {
+            "name" => push(UpstreamDatum::Name(value), Certainty::Likely),
+            "version" => push(UpstreamDatum::Version(value), Certainty::Likely),
+            "summary" => push(UpstreamDatum::Summary(value), Certainty::Certain),
+            "license" => push(UpstreamDatum::License(value), Certainty::Certain),
+            "url" => push(UpstreamDatum::Homepage(value), Certainty::Certain),
+            "vcs" => {
+                // The VCS tag may be prefixed with the type of VCS, e.g. git:https://...
+                let url = match regex_captures!(r"^\w+:(\w+://.*)$", &value) {
+                    Some((_, url)) => url.to_string(),
+                    None => value,
+                };
+                push(UpstreamDatum::Repository(url), Certainty::Certain)
+            }
+            "source" | "source0" if value.contains("://") => {
+                push(UpstreamDatum::Download(value), Certainty::Possible)
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(description) = description {
+        let description = description.join("\n").trim().to_string();
+        if let Some(description) = expand_macros(&description, &macros) {
+            push(UpstreamDatum::Description(description), Certainty::Certain);
+        }
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,250 @@
+use lazy_regex::{regex, regex_captures};
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, HTTPJSONError, ProviderError, UpstreamDatum,
+    UpstreamDatumWithMetadata,
+};
+
+/// Find the value of a `key: '...'` (or `:key => '...'`) option in a Gemfile directive.
+fn gemfile_option<'a>(args: &'a str, key: &str) -> Option<&'a str> {
+    regex!(r#"(?::(\w+)\s*=>|\b(\w+):)\s*(?:'([^']*)'|"([^"]*)")"#)
+        .captures_iter(args)
+        .find(|c| c.get(1).or_else(|| c.get(2)).unwrap().as_str() == key)
+        .and_then(|c| c.get(3).or_else(|| c.get(4)))
+        .map(|m| m.as_str())
+}
+
+/// Guess upstream metadata from a Gemfile.
+///
+/// Gemfiles of gems refer to their gemspec with a `gemspec` directive, which may point
+/// at another directory. Gems from git sources whose name matches such a gemspec provide
+/// its repository.
+pub fn guess_from_gemfile(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+    let basedir = path.parent().unwrap_or_else(|| Path::new("."));
+
+    let mut results = Vec::new();
+    let mut names = Vec::new();
+    for c in regex!(r"(?m)^\s*gemspec\b(.*)$").captures_iter(&contents) {
+        let args = c.get(1).unwrap().as_str();
+        let dir = basedir.join(gemfile_option(args, "path").unwrap_or("."));
+        let gemspecs = match gemfile_option(args, "name") {
+            Some(name) => vec![format!("{}.gemspec", name)],
+            None => super::sorted_filenames(&dir)
+                .into_iter()
+                .filter(|f| f.ends_with(".gemspec"))
+                .collect(),
+        };
+        for gemspec in gemspecs {
+            let gemspec_path = dir.join(&gemspec);
+            if !gemspec_path.exists() {
+                continue;
+            }
+            names.push(gemspec.trim_end_matches(".gemspec").to_string());
+            // Gemspecs next to the Gemfile are already scanned by upstream_ontologist
+            if dir != basedir {
+                results.extend(futures::executor::block_on(
+                    upstream_ontologist::providers::ruby::guess_from_gemspec(
+                        &gemspec_path,
+                        settings,
+                    ),
+                )?);
+            }
+        }
+    }
+
+    for line in contents.lines() {
+        let (_, gem, args) = match regex_captures!(r#"^\s*gem\s+['"]([^'"]+)['"](.*)$"#, line) {
+            Some(c) => c,
+            None => continue,
+        };
+        if !names.iter().any(|n| n == gem) {
+            continue;
+        }
+        let repository = if let Some(url) = gemfile_option(args, "git") {
+            url.to_string()
+        } else if let Some(repo) = gemfile_option(args, "github") {
+            format!("https://github.com/{}.git", repo)
+        } else {
+            continue;
+        };
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Repository(repository),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Find the name of the gem built from a directory.
+///
+/// By convention, the gemspec is named after the gem. Directories with several gemspecs
+/// are ambiguous, so no name is returned for them.
+pub fn gem_name(path: &Path) -> Option<String> {
+    let mut gemspecs = std::fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "gemspec"));
+    let gemspec = gemspecs.next()?;
+    if gemspecs.next().is_some() {
+        return None;
+    }
+    gemspec
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(String::from)
+}
+
+/// Look up a gem on rubygems.org.
+pub async fn remote_rubygem_data(name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let url = format!("https://rubygems.org/api/v1/gems/{}.json", name)
+        .parse()
+        .map_err(|e: url::ParseError| ProviderError::Other(e.to_string()))?;
+    let data = match upstream_ontologist::load_json_url(&url, None).await {
+        Ok(data) => data,
+        Err(HTTPJSONError::Error { status: 404, .. }) => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+    Ok(parse_rubygem_data(&data))
+}
+
+/// Extract metadata from a rubygems.org API response.
+fn parse_rubygem_data(data: &Value) -> Vec<UpstreamDatum> {
+    let get_str = |key| {
+        data.get(key)
+            .and_then(Value::as_str)
+            .filter(|s| !s.is_empty())
+            .map(String::from)
+    };
+
+    let mut datums = Vec::new();
+    if let Some(name) = get_str("name") {
+        datums.push(UpstreamDatum::Name(name));
+    }
+    if let Some(version) = get_str("version") {
+        datums.push(UpstreamDatum::Version(version));
+    }
+    if let Some(summary) = get_str("info") {
+        datums.push(UpstreamDatum::Summary(summary.trim().to_string()));
+    }
+    if let Some(homepage) = get_str("homepage_uri") {
+        datums.push(UpstreamDatum::Homepage(homepage));
+    }
+    if let Some(repository) = get_str("source_code_uri") {
+        datums.push(UpstreamDatum::Repository(repository));
+    }
+    if let Some(bug_database) = get_str("bug_tracker_uri") {
+        datums.push(UpstreamDatum::BugDatabase(bug_database));
+    }
+    if let Some(documentation) = get_str("documentation_uri") {
+        datums.push(UpstreamDatum::Documentation(documentation));
+    }
+    let licenses = data
+        .get("licenses")
+        .and_then(Value::as_array)
+        .map(|licenses| {
+            licenses
+                .iter()
+                .filter_map(Value::as_str)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" OR ")));
+    }
+
+    datums
+}
+
+pub struct RubyGems;
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for RubyGems {
+    fn name(&self) -> &'static str {
+        "RubyGems"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Certain
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &[
+            "Homepage",
+            "Repository",
+            "Bug-Database",
+            "Documentation",
+            "License",
+        ]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error("rubygems.org", remote_rubygem_data(name).await)
+    }
+}
+
+impl super::Registry for RubyGems {
+    fn page_url(&self, name: &str) -> String {
+        format!("https://rubygems.org/gems/{}", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rubygem_data() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "name": "rake",
+                "version": "13.2.1",
+                "info": "Rake is a Make-like program implemented in Ruby.\n",
+                "licenses": ["MIT"],
+                "homepage_uri": "https://github.com/ruby/rake",
+                "source_code_uri": "https://github.com/ruby/rake/tree/v13.2.1",
+                "bug_tracker_uri": "https://github.com/ruby/rake/issues",
+                "documentation_uri": "https://ruby.github.io/rake",
+                "wiki_uri": null,
+                "mailing_list_uri": ""
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_rubygem_data(&data),
+            vec![
+                UpstreamDatum::Name("rake".to_string()),
+                UpstreamDatum::Version("13.2.1".to_string()),
+                UpstreamDatum::Summary(
+                    "Rake is a Make-like program implemented in Ruby.".to_string()
+                ),
+                UpstreamDatum::Homepage("https://github.com/ruby/rake".to_string()),
+                UpstreamDatum::Repository("https://github.com/ruby/rake/tree/v13.2.1".to_string()),
+                UpstreamDatum::BugDatabase("https://github.com/ruby/rake/issues".to_string()),
+                UpstreamDatum::Documentation("https://ruby.github.io/rake".to_string()),
+                UpstreamDatum::License("MIT".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rubygem_data_multiple_licenses() {
+        let data: Value =
+            serde_json::from_str(r#"{"name": "json", "licenses": ["Ruby", "BSD-2-Clause"]}"#)
+                .unwrap();
+        assert_eq!(
+            parse_rubygem_data(&data),
+            vec![
+                UpstreamDatum::Name("json".to_string()),
+                UpstreamDatum::License("Ruby OR BSD-2-Clause".to_string()),
+            ]
+        );
+    }
+}
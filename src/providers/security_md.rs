@@ -0,0 +1,54 @@
+use lazy_regex::regex_captures;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a SECURITY.md file.
+///
+/// upstream_ontologist only records the presence of the file; this also looks for the
+/// address or URL that vulnerabilities should be reported to.
+pub fn guess_from_security_md(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    // GitHub also looks for the policy in .github/ and docs/
+    let name = match path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+    {
+        Some(parent @ (".github" | "docs")) => format!("{}/SECURITY.md", parent),
+        _ => "SECURITY.md".to_string(),
+    };
+    let mut results =
+        upstream_ontologist::providers::security_md::guess_from_security_md(&name, path, settings)?;
+
+    let contents = std::fs::read_to_string(path)?;
+    if let Some(contact) = find_security_contact(&contents) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::SecurityContact(contact),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Find the contact in the first line that talks about reporting something.
+fn find_security_contact(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .filter(|line| line.to_lowercase().contains("report"))
+        .find_map(|line| {
+            if let Some((_, email)) =
+                regex_captures!(r"(?:mailto:)?([\w.+-]+@[\w-]+(?:\.[\w-]+)+)", line)
+            {
+                Some(format!("mailto:{}", email))
+            } else {
+                regex_captures!(r"(https?://[^\s)>\]]+)", line)
+                    .map(|(_, url)| url.trim_end_matches(['.', ',']).to_string())
+            }
+        })
+}
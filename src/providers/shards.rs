@@ -0,0 +1,90 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Crystal shard.yml file.
+///
+/// If the shard does not list its repository, it is derived from the homepage or
+/// documentation URL when those are hosted on a code forge.
+pub fn guess_from_shard_yml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+    }
+    if let Some(version) = get_str("version") {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(description) = get_str("description") {
+        push(
+            UpstreamDatum::Summary(description.trim().to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(license) = get_str("license") {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(authors) = data.get("authors").and_then(Value::as_sequence) {
+        let authors = authors
+            .iter()
+            .filter_map(Value::as_str)
+            .map(Person::from)
+            .collect::<Vec<_>>();
+        if !authors.is_empty() {
+            push(UpstreamDatum::Author(authors), Certainty::Certain);
+        }
+    }
+    let homepage = get_str("homepage");
+    if let Some(homepage) = homepage {
+        push(
+            UpstreamDatum::Homepage(homepage.to_string()),
+            Certainty::Certain,
+        );
+    }
+    let documentation = get_str("documentation");
+    if let Some(documentation) = documentation {
+        push(
+            UpstreamDatum::Documentation(documentation.to_string()),
+            Certainty::Certain,
+        );
+    }
+
+    if let Some(repository) = get_str("repository") {
+        push(
+            UpstreamDatum::Repository(repository.to_string()),
+            Certainty::Certain,
+        );
+    } else if let Some(repository) = homepage
+        .into_iter()
+        .chain(documentation)
+        .find_map(super::forge_repository_url)
+    {
+        push(UpstreamDatum::Repository(repository), Certainty::Likely);
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,71 @@
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a snapcraft.yaml file.
+///
+/// Only the top-level metadata is used; parts describe how to build the snap, and their
+/// sources are not necessarily the upstream project.
+pub fn guess_from_snapcraft_yaml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    // The snap name is often, but not always, the upstream name
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Likely);
+    }
+    if let Some(summary) = get_str("summary") {
+        push(
+            UpstreamDatum::Summary(summary.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(description) = get_str("description") {
+        push(
+            UpstreamDatum::Description(description.trim().to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(version) = get_str("version") {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Likely,
+        );
+    }
+    if let Some(license) = get_str("license") {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        );
+    }
+
+    let mut push_all = |key, datum: fn(String) -> UpstreamDatum| {
+        for value in data.get(key).map(super::yaml_strings).unwrap_or_default() {
+            push(datum(value.to_string()), Certainty::Certain);
+        }
+    };
+    push_all("website", UpstreamDatum::Homepage);
+    push_all("contact", UpstreamDatum::Contact);
+    push_all("issues", UpstreamDatum::BugDatabase);
+    push_all("source-code", UpstreamDatum::Repository);
+    push_all("donation", UpstreamDatum::Donation);
+
+    Ok(results)
+}
@@ -0,0 +1,115 @@
+use lazy_regex::regex;
+use std::collections::HashMap;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Find the value of a top-level `name = '...'` assignment, if it's a plain string literal.
+fn python_string_assignment<'a>(contents: &'a str, name: &str) -> Option<&'a str> {
+    regex!(r#"(?m)^(\w+)\s*=\s*[uU]?(?:'([^'\n]*)'|"([^"\n]*)")\s*(?:#.*)?$"#)
+        .captures_iter(contents)
+        .find(|c| &c[1] == name)
+        .and_then(|c| c.get(2).or_else(|| c.get(3)))
+        .map(|m| m.as_str())
+}
+
+/// Find the string entries of a top-level `name = { ... }` dictionary assignment.
+fn python_dict_assignment<'a>(contents: &'a str, name: &str) -> HashMap<&'a str, &'a str> {
+    let start = match regex!(r"(?m)^(\w+)\s*=\s*(?:dict\(|\{)")
+        .captures_iter(contents)
+        .find(|c| &c[1] == name)
+    {
+        Some(c) => c.get(0).unwrap().end(),
+        None => return HashMap::new(),
+    };
+    let mut depth = 1;
+    let end = contents[start..]
+        .char_indices()
+        .find(|(_, c)| {
+            match c {
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                _ => {}
+            }
+            depth == 0
+        })
+        .map(|(i, _)| start + i)
+        .unwrap_or(contents.len());
+    regex!(r#"(?:['"](\w+)['"]\s*:|\b(\w+)\s*=)\s*(?:'([^']*)'|"([^"]*)")"#)
+        .captures_iter(&contents[start..end])
+        .map(|c| {
+            (
+                c.get(1).or_else(|| c.get(2)).unwrap().as_str(),
+                c.get(3).or_else(|| c.get(4)).unwrap().as_str(),
+            )
+        })
+        .collect()
+}
+
+/// Guess upstream metadata from a Sphinx conf.py file.
+///
+/// The file is not executed; only assignments of literal values are used.
+pub fn guess_from_sphinx_conf(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(project) = python_string_assignment(&contents, "project") {
+        push(UpstreamDatum::Name(project.to_string()), Certainty::Likely);
+    }
+    if let Some(author) = python_string_assignment(&contents, "author") {
+        push(
+            UpstreamDatum::Author(vec![Person::from(author)]),
+            Certainty::Likely,
+        );
+    }
+    if let Some(copyright) = python_string_assignment(&contents, "copyright") {
+        push(
+            UpstreamDatum::Copyright(copyright.to_string()),
+            Certainty::Likely,
+        );
+    }
+
+    // Themes that link to the repository, e.g. the GitHub "Edit on" links of
+    // sphinx_rtd_theme, or the repository settings of the book and furo themes.
+    let context = python_dict_assignment(&contents, "html_context");
+    for (forge, host) in [("github", "github.com"), ("gitlab", "gitlab.com")].iter() {
+        if let (Some(user), Some(repo)) = (
+            context.get(format!("{}_user", forge).as_str()),
+            context.get(format!("{}_repo", forge).as_str()),
+        ) {
+            push(
+                UpstreamDatum::Repository(format!("https://{}/{}/{}", host, user, repo)),
+                Certainty::Likely,
+            );
+        }
+    }
+    let theme_options = python_dict_assignment(&contents, "html_theme_options");
+    if let Some(url) = [
+        "repository_url",
+        "source_repository",
+        "github_url",
+        "repo_url",
+    ]
+    .iter()
+    .find_map(|key| theme_options.get(key))
+    {
+        push(
+            UpstreamDatum::Repository(url.to_string()),
+            Certainty::Likely,
+        );
+    }
+
+    Ok(results)
+}
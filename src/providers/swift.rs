@@ -0,0 +1,53 @@
+use lazy_regex::regex_captures;
+use serde_yaml::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a Swift Package.swift manifest.
+///
+/// The manifest doesn't say where the package itself lives; the URLs in it (and the
+/// origins in Package.resolved) are those of dependencies, so they are not used.
+pub fn guess_from_package_swift(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    if let Some((_, name)) = regex_captures!(r#"Package\s*\(\s*name:\s*"([^"]+)""#, &contents) {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Name(name.to_string()),
+            certainty: Some(Certainty::Likely),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Guess upstream metadata from a Swift Package Index configuration file (.spi.yml).
+pub fn guess_from_spi_yml(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_yaml::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    if let Some(url) = data
+        .get("external_links")
+        .and_then(|l| l.get("documentation"))
+        .and_then(Value::as_str)
+    {
+        results.push(UpstreamDatumWithMetadata {
+            datum: UpstreamDatum::Documentation(url.to_string()),
+            certainty: Some(Certainty::Certain),
+            origin: Some(path.into()),
+        });
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,91 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a vcpkg.json manifest.
+pub fn guess_from_vcpkg_json(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_json::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    let get_str = |key| data.get(key).and_then(Value::as_str);
+
+    if let Some(name) = get_str("name") {
+        push(UpstreamDatum::Name(name.to_string()), Certainty::Certain);
+    }
+    if let Some(version) = [
+        "version",
+        "version-semver",
+        "version-date",
+        "version-string",
+    ]
+    .iter()
+    .find_map(|key| get_str(key))
+    {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    // The first line of the description is a summary; any further lines are details
+    let description = data
+        .get("description")
+        .map(super::json_strings)
+        .unwrap_or_default();
+    if let Some((summary, rest)) = description.split_first() {
+        push(
+            UpstreamDatum::Summary(summary.to_string()),
+            Certainty::Certain,
+        );
+        if !rest.is_empty() {
+            push(
+                UpstreamDatum::Description(rest.join("\n")),
+                Certainty::Certain,
+            );
+        }
+    }
+    if let Some(homepage) = get_str("homepage") {
+        push(
+            UpstreamDatum::Homepage(homepage.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(documentation) = get_str("documentation") {
+        push(
+            UpstreamDatum::Documentation(documentation.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(license) = get_str("license") {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        );
+    }
+    for maintainer in data
+        .get("maintainers")
+        .map(super::json_strings)
+        .unwrap_or_default()
+    {
+        push(
+            UpstreamDatum::Maintainer(Person::from(maintainer)),
+            Certainty::Certain,
+        );
+    }
+
+    Ok(results)
+}
@@ -0,0 +1,52 @@
+use lazy_regex::regex;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Guess upstream metadata from a waf wscript file.
+///
+/// This reads the top-level APPNAME and VERSION variables, as well as variables that are
+/// conventionally used for the project homepage.
+pub fn guess_from_wscript(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let contents = std::fs::read_to_string(path)?;
+
+    let mut results = Vec::new();
+    let mut push = |datum| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(Certainty::Confident),
+            origin: Some(path.into()),
+        })
+    };
+
+    for c in regex!(r#"(?m)^([A-Z_]+)\s*=\s*(?:'([^']*)'|"([^"]*)")"#).captures_iter(&contents) {
+        let value = c.get(2).or_else(|| c.get(3)).unwrap().as_str().to_string();
+        match &c[1] {
+            "APPNAME" => push(UpstreamDatum::Name(value)),
+            "VERSION" => push(UpstreamDatum::Version(value)),
+            "HOMEPAGE" | "WEBSITE" | "URL" if value.contains("://") => {
+                push(UpstreamDatum::Homepage(value))
+            }
+            _ => {}
+        }
+    }
+
+    Ok(results)
+}
+
+/// Guess the upstream metadata from a wscript file that upstream_ontologist does not find.
+///
+/// upstream_ontologist already picks up APPNAME and VERSION when it scans a tree.
+pub fn guess_extra_from_wscript(
+    path: &Path,
+    settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    Ok(guess_from_wscript(path, settings)?
+        .into_iter()
+        .filter(|d| !matches!(d.datum, UpstreamDatum::Name(_) | UpstreamDatum::Version(_)))
+        .collect())
+}
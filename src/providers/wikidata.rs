@@ -0,0 +1,183 @@
+use serde_json::Value;
+use upstream_ontologist::{Certainty, ProviderError, UpstreamDatum};
+
+const SPARQL_ENDPOINT: &str = "https://query.wikidata.org/sparql";
+
+/// Build the SPARQL pattern that selects the item for a project.
+///
+/// The official website identifies an item much more reliably than its label, so it is
+/// preferred when known. Labels are only matched against items that are software.
+fn item_pattern(name: Option<&str>, homepage: Option<&str>) -> Option<String> {
+    if let Some(homepage) = homepage.filter(|h| !h.contains(['<', '>', '"', ' '])) {
+        return Some(format!("?item wdt:P856 <{}> .", homepage));
+    }
+    let name = name?.replace('\\', "\\\\").replace('"', "\\\"");
+    Some(format!(
+        "?item rdfs:label \"{}\"@en ; wdt:P31/wdt:P279* wd:Q7397 .",
+        name
+    ))
+}
+
+/// Look up a project on Wikidata, by its homepage or else by its name.
+///
+/// Nothing is returned if the project can't be resolved to a single item.
+pub async fn guess_from_wikidata(
+    name: Option<&str>,
+    homepage: Option<&str>,
+) -> Result<Vec<UpstreamDatum>, ProviderError> {
+    let pattern = match item_pattern(name, homepage) {
+        Some(pattern) => pattern,
+        None => return Ok(vec![]),
+    };
+    let query = format!(
+        "SELECT ?item ?website ?repository ?bugs ?license WHERE {{ {} \
+         OPTIONAL {{ ?item wdt:P856 ?website }} \
+         OPTIONAL {{ ?item wdt:P1324 ?repository }} \
+         OPTIONAL {{ ?item wdt:P1401 ?bugs }} \
+         OPTIONAL {{ ?item wdt:P275/wdt:P2479 ?license }} }}",
+        pattern
+    );
+    let mut url = url::Url::parse(SPARQL_ENDPOINT).unwrap();
+    url.query_pairs_mut()
+        .append_pair("query", &query)
+        .append_pair("format", "json");
+    let data = upstream_ontologist::load_json_url(&url, None).await?;
+    Ok(parse_sparql_results(&data))
+}
+
+/// Extract metadata from the results of the query built by guess_from_wikidata.
+///
+/// Nothing is returned if the results describe more than one item.
+fn parse_sparql_results(data: &Value) -> Vec<UpstreamDatum> {
+    let bindings = data["results"]["bindings"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+
+    let value = |binding: &Value, key: &str| {
+        binding[key]
+            .get("value")
+            .and_then(Value::as_str)
+            .map(String::from)
+    };
+    let mut items = bindings.iter().filter_map(|b| value(b, "item"));
+    let item = match items.next() {
+        Some(item) => item,
+        None => return vec![],
+    };
+    if let Some(other) = items.find(|other| *other != item) {
+        log::debug!("Ambiguous Wikidata lookup: {} and {}", item, other);
+        return vec![];
+    }
+
+    // Each combination of values is a separate row; keep the first of each
+    let first = |key| bindings.iter().find_map(|b| value(b, key));
+    let mut datums = Vec::new();
+    if let Some(website) = first("website") {
+        datums.push(UpstreamDatum::Homepage(website));
+    }
+    if let Some(repository) = first("repository") {
+        datums.push(UpstreamDatum::Repository(repository));
+    }
+    if let Some(bugs) = first("bugs") {
+        datums.push(UpstreamDatum::BugDatabase(bugs));
+    }
+    let mut licenses = bindings
+        .iter()
+        .filter_map(|b| value(b, "license"))
+        .collect::<Vec<_>>();
+    licenses.sort();
+    licenses.dedup();
+    if !licenses.is_empty() {
+        datums.push(UpstreamDatum::License(licenses.join(" OR ")));
+    }
+
+    datums
+}
+
+pub struct Wikidata {
+    pub homepage: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl upstream_ontologist::ThirdPartyRepository for Wikidata {
+    fn name(&self) -> &'static str {
+        "Wikidata"
+    }
+
+    fn max_supported_certainty(&self) -> Certainty {
+        Certainty::Possible
+    }
+
+    fn supported_fields(&self) -> &'static [&'static str] {
+        &["Homepage", "Repository", "Bug-Database", "License"]
+    }
+
+    async fn guess_metadata(&self, name: &str) -> Result<Vec<UpstreamDatum>, ProviderError> {
+        super::warn_on_error(
+            "Wikidata",
+            guess_from_wikidata(Some(name), self.homepage.as_deref()).await,
+        )
+    }
+}
+
+impl super::Registry for Wikidata {
+    fn page_url(&self, _name: &str) -> String {
+        "https://www.wikidata.org/".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sparql_results() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "head": {"vars": ["item", "website", "repository", "bugs", "license"]},
+                "results": {
+                    "bindings": [
+                        {
+                            "item": {"type": "uri", "value": "http://www.wikidata.org/entity/Q186055"},
+                            "website": {"type": "uri", "value": "https://git-scm.com/"},
+                            "repository": {"type": "uri", "value": "https://github.com/git/git"},
+                            "license": {"type": "literal", "value": "GPL-2.0-only"}
+                        },
+                        {
+                            "item": {"type": "uri", "value": "http://www.wikidata.org/entity/Q186055"},
+                            "website": {"type": "uri", "value": "https://git-scm.com/"},
+                            "repository": {"type": "uri", "value": "https://git.kernel.org/pub/scm/git/git.git"},
+                            "license": {"type": "literal", "value": "LGPL-2.1-or-later"}
+                        }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            parse_sparql_results(&data),
+            vec![
+                UpstreamDatum::Homepage("https://git-scm.com/".to_string()),
+                UpstreamDatum::Repository("https://github.com/git/git".to_string()),
+                UpstreamDatum::License("GPL-2.0-only OR LGPL-2.1-or-later".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_sparql_results_ambiguous() {
+        let data: Value = serde_json::from_str(
+            r#"{
+                "results": {
+                    "bindings": [
+                        {"item": {"type": "uri", "value": "http://www.wikidata.org/entity/Q1"}},
+                        {"item": {"type": "uri", "value": "http://www.wikidata.org/entity/Q2"}}
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(parse_sparql_results(&data), vec![]);
+    }
+}
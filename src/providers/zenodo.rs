@@ -0,0 +1,107 @@
+use serde_json::Value;
+use std::path::Path;
+use upstream_ontologist::{
+    Certainty, GuesserSettings, Person, ProviderError, UpstreamDatum, UpstreamDatumWithMetadata,
+};
+
+/// Zenodo lists creators as "Family, Given".
+fn zenodo_person(creator: &Value) -> Option<Person> {
+    let name = creator.get("name").and_then(Value::as_str)?;
+    let name = match name.split_once(", ") {
+        Some((family, given)) => format!("{} {}", given, family),
+        None => name.to_string(),
+    };
+    Some(Person {
+        name: Some(name),
+        email: None,
+        url: creator
+            .get("orcid")
+            .and_then(Value::as_str)
+            .map(|orcid| format!("https://orcid.org/{}", orcid)),
+    })
+}
+
+/// Guess upstream metadata from a .zenodo.json deposition metadata file.
+///
+/// See https://developers.zenodo.org/#representation for the format.
+pub fn guess_from_zenodo_json(
+    path: &Path,
+    _settings: &GuesserSettings,
+) -> std::result::Result<Vec<UpstreamDatumWithMetadata>, ProviderError> {
+    let file = std::fs::File::open(path)?;
+    let data: Value =
+        serde_json::from_reader(file).map_err(|e| ProviderError::ParseError(e.to_string()))?;
+
+    let mut results = Vec::new();
+    let mut push = |datum, certainty| {
+        results.push(UpstreamDatumWithMetadata {
+            datum,
+            certainty: Some(certainty),
+            origin: Some(path.into()),
+        })
+    };
+
+    if let Some(title) = data.get("title").and_then(Value::as_str) {
+        push(UpstreamDatum::Name(title.to_string()), Certainty::Likely);
+    }
+    if let Some(version) = data.get("version").and_then(Value::as_str) {
+        push(
+            UpstreamDatum::Version(version.to_string()),
+            Certainty::Certain,
+        );
+    }
+    let creators = data
+        .get("creators")
+        .and_then(Value::as_array)
+        .map(|creators| {
+            creators
+                .iter()
+                .filter_map(zenodo_person)
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if !creators.is_empty() {
+        push(UpstreamDatum::Author(creators), Certainty::Certain);
+    }
+    // The license is either a plain identifier or an object with an "id" key
+    if let Some(license) = data
+        .get("license")
+        .and_then(|l| l.as_str().or_else(|| l.get("id").and_then(Value::as_str)))
+    {
+        push(
+            UpstreamDatum::License(license.to_string()),
+            Certainty::Certain,
+        );
+    }
+    if let Some(keywords) = data.get("keywords").and_then(Value::as_array) {
+        push(
+            UpstreamDatum::Keywords(
+                keywords
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|s| s.to_string())
+                    .collect(),
+            ),
+            Certainty::Certain,
+        );
+    }
+    for related in data
+        .get("related_identifiers")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        let relation = related.get("relation").and_then(Value::as_str);
+        let identifier = related.get("identifier").and_then(Value::as_str);
+        if let (Some("isSupplementTo"), Some(identifier)) = (relation, identifier) {
+            if identifier.starts_with("https://") || identifier.starts_with("http://") {
+                push(
+                    UpstreamDatum::Repository(identifier.to_string()),
+                    Certainty::Likely,
+                );
+            }
+        }
+    }
+
+    Ok(results)
+}
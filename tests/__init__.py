@@ -20,7 +20,9 @@ import unittest
 
 def test_suite():
     names = [
+        "bugs",
         "data",
+        "guess",
         "vcs",
     ]
     module_names = [__name__ + ".test_" + name for name in names]
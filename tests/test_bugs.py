@@ -0,0 +1,156 @@
+#!/usr/bin/python3
+# Copyright (C) 2026 Jelmer Vernooij <jelmer@debian.org>
+#
+# This program is free software; you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation; either version 2 of the License, or
+# (at your option) any later version.
+#
+# This program is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with this program; if not, write to the Free Software
+# Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA
+
+
+from unittest import TestCase
+
+from upstream_ontologist import (
+    UrlUnverifiable,
+    bug_database_url_from_bug_submit_url,
+    bug_submit_url_from_bug_database_url,
+    check_bug_database_canonical,
+    check_bug_submit_url_canonical,
+)
+
+
+class BugDatabaseUrlFromBugSubmitUrlTests(TestCase):
+    def test_github(self):
+        self.assertEqual(
+            "https://github.com/jelmer/upstream-ontologist/issues",
+            bug_database_url_from_bug_submit_url(
+                "https://github.com/jelmer/upstream-ontologist/issues/new",
+                net_access=False,
+            ),
+        )
+
+    def test_gitlab(self):
+        self.assertEqual(
+            "https://gitlab.com/foo/bar/issues",
+            bug_database_url_from_bug_submit_url(
+                "https://gitlab.com/foo/bar/issues/new", net_access=False
+            ),
+        )
+
+    def test_unknown(self):
+        self.assertIsNone(
+            bug_database_url_from_bug_submit_url(
+                "https://example.com/bugs/new", net_access=False
+            )
+        )
+
+    def test_gitlab_separator(self):
+        self.assertEqual(
+            "https://salsa.debian.org/foo/bar/-/issues",
+            bug_database_url_from_bug_submit_url(
+                "https://salsa.debian.org/foo/bar/-/issues/new", net_access=False
+            ),
+        )
+
+    def test_launchpad(self):
+        self.assertEqual(
+            "https://bugs.launchpad.net/bzr",
+            bug_database_url_from_bug_submit_url(
+                "https://bugs.launchpad.net/bzr/+filebug", net_access=False
+            ),
+        )
+
+    def test_bugzilla(self):
+        self.assertEqual(
+            "https://bugzilla.gnome.org/buglist.cgi?product=gnome-shell",
+            bug_database_url_from_bug_submit_url(
+                "https://bugzilla.gnome.org/enter_bug.cgi?product=gnome-shell",
+                net_access=False,
+            ),
+        )
+
+
+class BugSubmitUrlFromBugDatabaseUrlTests(TestCase):
+    def test_github(self):
+        self.assertEqual(
+            "https://github.com/jelmer/upstream-ontologist/issues/new",
+            bug_submit_url_from_bug_database_url(
+                "https://github.com/jelmer/upstream-ontologist/issues",
+                net_access=False,
+            ),
+        )
+
+    def test_gitlab(self):
+        self.assertEqual(
+            "https://gitlab.com/foo/bar/issues/new",
+            bug_submit_url_from_bug_database_url(
+                "https://gitlab.com/foo/bar/issues", net_access=False
+            ),
+        )
+
+    def test_gitea(self):
+        self.assertEqual(
+            "https://codeberg.org/foo/bar/issues/new",
+            bug_submit_url_from_bug_database_url(
+                "https://codeberg.org/foo/bar/issues", net_access=False
+            ),
+        )
+
+    def test_launchpad(self):
+        self.assertEqual(
+            "https://bugs.launchpad.net/bzr/+filebug",
+            bug_submit_url_from_bug_database_url(
+                "https://bugs.launchpad.net/bzr", net_access=False
+            ),
+        )
+
+    def test_bugzilla(self):
+        self.assertEqual(
+            "https://bugzilla.gnome.org/enter_bug.cgi?product=gnome-shell",
+            bug_submit_url_from_bug_database_url(
+                "https://bugzilla.gnome.org/buglist.cgi?product=gnome-shell&bug_status=NEW",
+                net_access=False,
+            ),
+        )
+
+    def test_unknown(self):
+        self.assertIsNone(
+            bug_submit_url_from_bug_database_url(
+                "https://example.com/issues", net_access=False
+            )
+        )
+
+
+class CheckBugDatabaseCanonicalTests(TestCase):
+    def test_no_net_access(self):
+        with self.assertRaises(UrlUnverifiable) as cm:
+            check_bug_database_canonical(
+                "https://github.com/jelmer/upstream-ontologist/issues",
+                net_access=False,
+            )
+        self.assertEqual(
+            "https://github.com/jelmer/upstream-ontologist/issues", cm.exception.url
+        )
+
+
+class CheckBugSubmitUrlCanonicalTests(TestCase):
+    def test_no_net_access(self):
+        with self.assertRaises(UrlUnverifiable):
+            check_bug_submit_url_canonical(
+                "https://github.com/jelmer/upstream-ontologist/issues/new",
+                net_access=False,
+            )
+
+    def test_unknown_no_net_access(self):
+        with self.assertRaises(UrlUnverifiable):
+            check_bug_submit_url_canonical(
+                "https://example.com/report-bug", net_access=False
+            )
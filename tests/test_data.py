@@ -16,10 +16,18 @@
 # Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA
 
 
+import copy
+import pickle
 from typing import Any
 from unittest import TestCase
 
-from upstream_ontologist import UpstreamMetadata
+from upstream_ontologist import (
+    Certainty,
+    Origin,
+    Person,
+    UpstreamDatum,
+    UpstreamMetadata,
+)
 
 
 class UpstreamMetadataFromDictTests(TestCase):
@@ -84,3 +92,452 @@ Homepage: https://example.com
             metadata["Registry"].value,
             [{"Name": "conda:conda-forge", "Entry": "r-tsne"}],
         )
+
+
+class PersonValueTests(TestCase):
+    def test_author_list(self):
+        datum = UpstreamDatum(
+            "Author",
+            [
+                Person("Jelmer Vernooij", "jelmer@jelmer.uk"),
+                "Somebody Else <somebody@example.com>",
+            ],
+        )
+        self.assertEqual(
+            [
+                Person("Jelmer Vernooij", "jelmer@jelmer.uk"),
+                Person("Somebody Else", "somebody@example.com"),
+            ],
+            datum.value,
+        )
+        self.assertEqual("jelmer@jelmer.uk", datum.value[0].email)
+
+    def test_single_author(self):
+        datum = UpstreamDatum("Author", Person("Jelmer Vernooij"))
+        self.assertEqual([Person("Jelmer Vernooij")], datum.value)
+
+    def test_maintainer(self):
+        datum = UpstreamDatum("Maintainer", "Jelmer Vernooij <jelmer@jelmer.uk>")
+        self.assertEqual(Person("Jelmer Vernooij", "jelmer@jelmer.uk"), datum.value)
+        self.assertEqual("Jelmer Vernooij", datum.value.name)
+
+    def test_invalid(self):
+        with self.assertRaises(TypeError):
+            UpstreamDatum("Maintainer", 42)
+
+
+class CertaintyTests(TestCase):
+    def test_ordering(self):
+        self.assertGreater(Certainty.CERTAIN, Certainty.CONFIDENT)
+        self.assertGreater(Certainty.CONFIDENT, Certainty.LIKELY)
+        self.assertGreater(Certainty.LIKELY, Certainty.POSSIBLE)
+        self.assertLess(Certainty.POSSIBLE, "likely")
+        self.assertEqual(
+            [Certainty.POSSIBLE, Certainty.LIKELY, Certainty.CERTAIN],
+            sorted([Certainty.CERTAIN, Certainty.POSSIBLE, Certainty.LIKELY]),
+        )
+
+    def test_datum(self):
+        datum = UpstreamDatum("Name", "foo", Certainty.LIKELY)
+        self.assertIs(Certainty.LIKELY, datum.certainty)
+        self.assertEqual("likely", datum.certainty)
+        datum.certainty = Certainty.CERTAIN
+        self.assertIs(Certainty.CERTAIN, datum.certainty)
+
+    def test_sort_datums(self):
+        datums = [
+            UpstreamDatum("Name", "foo", "possible"),
+            UpstreamDatum("Name", "bar", "certain"),
+        ]
+        datums.sort(key=lambda d: d.certainty, reverse=True)
+        self.assertEqual("bar", datums[0].value)
+
+    def test_invalid(self):
+        with self.assertRaises(ValueError):
+            UpstreamDatum("Name", "foo", "very-sure")
+
+
+class OriginTests(TestCase):
+    def test_path(self):
+        origin = Origin.path("setup.py")
+        self.assertEqual("path", origin.kind)
+        self.assertEqual("setup.py", origin.path)
+        self.assertIsNone(origin.url)
+
+    def test_url(self):
+        origin = Origin.url("https://pypi.org/pypi/foo/json")
+        self.assertEqual("url", origin.kind)
+        self.assertEqual("https://pypi.org/pypi/foo/json", origin.url)
+        self.assertIsNone(origin.path)
+
+    def test_other(self):
+        origin = Origin.other("POD")
+        self.assertEqual("other", origin.kind)
+        self.assertEqual("POD", origin.other)
+
+    def test_datum(self):
+        datum = UpstreamDatum("Name", "foo", "certain", Origin.url("https://example.com/"))
+        self.assertEqual("url", datum.origin.kind)
+        datum.origin = "setup.py"
+        self.assertEqual("path", datum.origin.kind)
+        # Origins compare equal to their string form
+        self.assertEqual("setup.py", datum.origin)
+        self.assertEqual({"setup.py"}, {datum.origin})
+
+    def test_origins(self):
+        datum = UpstreamDatum("Name", "foo", "certain", "setup.py")
+        self.assertEqual([Origin.path("setup.py")], datum.origins)
+        self.assertEqual([], UpstreamDatum("Name", "foo").origins)
+
+
+class UpstreamDatumHashTests(TestCase):
+    def test_set(self):
+        datums = {
+            UpstreamDatum("Name", "foo", "certain"),
+            UpstreamDatum("Name", "foo", "certain"),
+            UpstreamDatum("Name", "foo", "likely"),
+            UpstreamDatum("Keywords", ["a", "b"], "certain"),
+        }
+        self.assertEqual(3, len(datums))
+        self.assertIn(UpstreamDatum("Keywords", ["a", "b"], "certain"), datums)
+
+    def test_dict_key(self):
+        d = {UpstreamDatum("Homepage", "https://example.com/"): 1}
+        self.assertEqual(1, d[UpstreamDatum("Homepage", "https://example.com/")])
+
+    def test_certainty_not_hashed(self):
+        datum = UpstreamDatum("Name", "foo", "likely")
+        h = hash(datum)
+        datum.certainty = "certain"
+        self.assertEqual(h, hash(datum))
+        self.assertEqual(h, hash(UpstreamDatum("Name", "foo", origin="./setup.py")))
+
+
+class UpstreamMetadataMappingTests(TestCase):
+    def test_len(self):
+        metadata = UpstreamMetadata.from_dict({"Name": "foo", "Version": "1.2.3"})
+        self.assertEqual(2, len(metadata))
+        self.assertEqual(0, len(UpstreamMetadata()))
+
+    def test_keys(self):
+        metadata = UpstreamMetadata.from_dict({"Name": "foo", "Version": "1.2.3"})
+        self.assertEqual({"Name", "Version"}, set(metadata.keys()))
+
+    def test_bool(self):
+        self.assertFalse(UpstreamMetadata())
+        self.assertTrue(UpstreamMetadata.from_dict({"Name": "foo"}))
+
+
+class UpstreamMetadataMutationTests(TestCase):
+    def test_pop(self):
+        metadata = UpstreamMetadata.from_dict({"Name": "foo"})
+        self.assertEqual("foo", metadata.pop("Name").value)
+        self.assertNotIn("Name", metadata)
+        self.assertIsNone(metadata.pop("Name", None))
+        self.assertRaises(KeyError, metadata.pop, "Name")
+
+    def test_setdefault(self):
+        metadata = UpstreamMetadata.from_dict({"Name": "foo"})
+        self.assertEqual(
+            "foo", metadata.setdefault("Name", UpstreamDatum("Name", "bar")).value
+        )
+        self.assertEqual(
+            "1.0", metadata.setdefault("Version", UpstreamDatum("Version", "1.0")).value
+        )
+        self.assertEqual("1.0", metadata["Version"].value)
+
+    def test_update_certainty(self):
+        metadata = UpstreamMetadata()
+        metadata["Name"] = UpstreamDatum("Name", "foo", "likely")
+        metadata["Version"] = UpstreamDatum("Version", "1.0", "certain")
+        changed = metadata.update(
+            [
+                UpstreamDatum("Name", "bar", "certain"),
+                UpstreamDatum("Version", "2.0", "possible"),
+                UpstreamDatum("Summary", "A project", "possible"),
+            ]
+        )
+        self.assertEqual({"Name", "Summary"}, {d.field for d in changed})
+        self.assertEqual("bar", metadata["Name"].value)
+        self.assertEqual("1.0", metadata["Version"].value)
+        self.assertEqual("A project", metadata["Summary"].value)
+
+    def test_update_metadata(self):
+        metadata = UpstreamMetadata()
+        metadata.update(UpstreamMetadata.from_dict({"Name": "foo"}, "certain"))
+        self.assertEqual("foo", metadata["Name"].value)
+
+
+class CopyTests(TestCase):
+    def test_metadata_copy(self):
+        metadata = UpstreamMetadata.from_dict({"Name": "foo"})
+        for snapshot in [metadata.copy(), copy.copy(metadata), copy.deepcopy(metadata)]:
+            metadata["Version"] = UpstreamDatum("Version", "1.0")
+            self.assertNotIn("Version", snapshot)
+            self.assertEqual("foo", snapshot["Name"].value)
+            del metadata["Version"]
+
+    def test_datum_copy(self):
+        datum = UpstreamDatum("Name", "foo", "likely")
+        snapshot = copy.deepcopy(datum)
+        datum.certainty = "certain"
+        self.assertEqual("likely", snapshot.certainty)
+        self.assertEqual(datum.copy(), datum)
+
+
+class UpstreamMetadataToDictTests(TestCase):
+    def test_to_dict(self):
+        metadata = UpstreamMetadata()
+        metadata["Name"] = UpstreamDatum("Name", "foo", "certain")
+        metadata["Keywords"] = UpstreamDatum("Keywords", ["a", "b"], "likely")
+        metadata["Author"] = UpstreamDatum("Author", [Person("Jelmer Vernooij")])
+        self.assertEqual(
+            {
+                "Name": "foo",
+                "Keywords": ["a", "b"],
+                "Author": [Person("Jelmer Vernooij")],
+            },
+            metadata.to_dict(),
+        )
+
+    def test_include_certainty(self):
+        metadata = UpstreamMetadata()
+        metadata["Name"] = UpstreamDatum("Name", "foo", "certain")
+        self.assertEqual(
+            {"Name": {"value": "foo", "certainty": Certainty.CERTAIN}},
+            metadata.to_dict(include_certainty=True),
+        )
+        self.assertIsInstance(
+            metadata.to_dict(include_certainty=True)["Name"]["certainty"], Certainty
+        )
+
+
+class UpstreamMetadataDiffTests(TestCase):
+    def _metadata(self, *datums):
+        metadata = UpstreamMetadata()
+        for datum in datums:
+            metadata[datum.field] = datum
+        return metadata
+
+    def test_eq_ignores_order(self):
+        a = self._metadata(
+            UpstreamDatum("Name", "foo", "certain"),
+            UpstreamDatum("Homepage", "https://example.com/", "likely"),
+        )
+        b = self._metadata(
+            UpstreamDatum("Homepage", "https://example.com/", "likely"),
+            UpstreamDatum("Name", "foo", "certain"),
+        )
+        self.assertEqual(a, b)
+        b["Name"] = UpstreamDatum("Name", "bar", "certain")
+        self.assertNotEqual(a, b)
+        self.assertNotEqual(a, "foo")
+
+    def test_diff(self):
+        old = self._metadata(
+            UpstreamDatum("Name", "foo", "certain"),
+            UpstreamDatum("Homepage", "https://example.com/", "likely"),
+            UpstreamDatum("Repository", "https://example.com/foo.git", "likely"),
+            UpstreamDatum("Wiki", "https://example.com/wiki", "likely"),
+        )
+        new = self._metadata(
+            UpstreamDatum("Name", "foo", "certain"),
+            UpstreamDatum("Homepage", "https://example.com/", "certain"),
+            UpstreamDatum("Repository", "https://example.com/bar.git", "likely"),
+            UpstreamDatum("Summary", "A foo", "possible"),
+        )
+        diff = old.diff(new)
+        self.assertTrue(diff)
+        self.assertEqual(["Summary"], [d.field for d in diff.added])
+        self.assertEqual(["Wiki"], [d.field for d in diff.removed])
+        self.assertEqual(
+            [("https://example.com/foo.git", "https://example.com/bar.git")],
+            [(o.value, n.value) for (o, n) in diff.changed],
+        )
+        self.assertEqual(
+            [("likely", "certain")],
+            [(o.certainty, n.certainty) for (o, n) in diff.certainty_changed],
+        )
+
+    def test_diff_empty(self):
+        metadata = self._metadata(UpstreamDatum("Name", "foo", "certain"))
+        self.assertFalse(metadata.diff(metadata.copy()))
+
+
+class UpstreamMetadataMergeTests(TestCase):
+    def setUp(self):
+        super().setUp()
+        self.ours = UpstreamMetadata()
+        self.ours["Name"] = UpstreamDatum("Name", "foo", "likely")
+        self.ours["Homepage"] = UpstreamDatum(
+            "Homepage", "https://example.com/", "certain"
+        )
+        self.theirs = UpstreamMetadata()
+        self.theirs["Name"] = UpstreamDatum("Name", "bar", "certain")
+        self.theirs["Homepage"] = UpstreamDatum(
+            "Homepage", "https://example.org/", "likely"
+        )
+        self.theirs["Summary"] = UpstreamDatum("Summary", "A foo", "possible")
+
+    def test_prefer_higher_certainty(self):
+        changed = self.ours.merge(self.theirs)
+        self.assertEqual({"Name", "Summary"}, {d.field for d in changed})
+        self.assertEqual("bar", self.ours["Name"].value)
+        self.assertEqual("https://example.com/", self.ours["Homepage"].value)
+
+    def test_tiebreak_on_origin(self):
+        self.theirs["Homepage"] = UpstreamDatum(
+            "Homepage", "https://example.org/", "certain", origin="setup.py"
+        )
+        self.ours.merge(self.theirs)
+        self.assertEqual("https://example.org/", self.ours["Homepage"].value)
+
+    def test_prefer_self(self):
+        changed = self.ours.merge(self.theirs, strategy="prefer_self")
+        self.assertEqual(["Summary"], [d.field for d in changed])
+        self.assertEqual("foo", self.ours["Name"].value)
+
+    def test_prefer_other(self):
+        self.ours.merge(self.theirs, strategy="prefer_other")
+        self.assertEqual("bar", self.ours["Name"].value)
+        self.assertEqual("https://example.org/", self.ours["Homepage"].value)
+
+    def test_unknown_strategy(self):
+        self.assertRaises(ValueError, self.ours.merge, self.theirs, strategy="random")
+
+
+class PickleTests(TestCase):
+    def test_origin(self):
+        for origin in [
+            Origin.path("setup.py"),
+            Origin.url("https://example.com/setup.py"),
+            Origin.other("the wind"),
+        ]:
+            self.assertEqual(origin, pickle.loads(pickle.dumps(origin)))
+
+    def test_datum(self):
+        datum = UpstreamDatum(
+            "Author", [Person("Jelmer Vernooij")], "likely", origin="setup.py"
+        )
+        self.assertEqual(datum, pickle.loads(pickle.dumps(datum)))
+
+    def test_metadata(self):
+        metadata = UpstreamMetadata()
+        metadata["Name"] = UpstreamDatum("Name", "foo", "certain")
+        metadata["Keywords"] = UpstreamDatum("Keywords", ["a", "b"], "possible")
+        self.assertEqual(metadata, pickle.loads(pickle.dumps(metadata)))
+
+
+class YamlTests(TestCase):
+    def test_to_yaml(self):
+        metadata = UpstreamMetadata()
+        metadata["Repository"] = UpstreamDatum(
+            "Repository", "https://github.com/jelmer/foo.git", "certain"
+        )
+        metadata["Bug-Database"] = UpstreamDatum(
+            "Bug-Database", "https://github.com/jelmer/foo/issues", "likely"
+        )
+        metadata["Repository-Browse"] = UpstreamDatum(
+            "Repository-Browse", "https://github.com/jelmer/foo", "likely"
+        )
+        self.assertEqual(
+            """\
+---
+Bug-Database: https://github.com/jelmer/foo/issues
+Repository: https://github.com/jelmer/foo.git
+Repository-Browse: https://github.com/jelmer/foo
+""",
+            metadata.to_yaml(),
+        )
+
+    def test_name_and_contact_first(self):
+        metadata = UpstreamMetadata()
+        metadata["Bug-Database"] = UpstreamDatum(
+            "Bug-Database", "https://github.com/jelmer/foo/issues"
+        )
+        metadata["Contact"] = UpstreamDatum("Contact", "Jelmer <jelmer@example.com>")
+        metadata["Name"] = UpstreamDatum("Name", "foo")
+        self.assertEqual(
+            """\
+---
+Name: foo
+Contact: Jelmer <jelmer@example.com>
+Bug-Database: https://github.com/jelmer/foo/issues
+""",
+            metadata.to_yaml(),
+        )
+
+    def test_people_and_registry(self):
+        metadata = UpstreamMetadata()
+        metadata["Author"] = UpstreamDatum(
+            "Author", [Person("Jelmer Vernooij", "jelmer@example.com")]
+        )
+        metadata["Registry"] = UpstreamDatum("Registry", [("PyPI", "foo")])
+        self.assertEqual(
+            """\
+---
+Author:
+- Jelmer Vernooij <jelmer@example.com>
+Registry:
+- Name: PyPI
+  Entry: foo
+""",
+            metadata.to_yaml(),
+        )
+
+    def test_from_yaml(self):
+        metadata = UpstreamMetadata.from_yaml(
+            """\
+---
+Author:
+- Jelmer Vernooij <jelmer@example.com>
+Debian-ITP: 12345
+Reference:
+  Title: Unknown to us
+Registry:
+- Name: PyPI
+  Entry: foo
+Repository: https://github.com/jelmer/foo.git
+Version: 1.0
+""",
+            certainty="certain",
+        )
+        self.assertEqual(
+            {"Author", "Debian-ITP", "Registry", "Repository", "Version"},
+            set(metadata.keys()),
+        )
+        self.assertEqual(
+            [Person("Jelmer Vernooij", "jelmer@example.com")],
+            metadata["Author"].value,
+        )
+        self.assertEqual(12345, metadata["Debian-ITP"].value)
+        self.assertEqual([{"Name": "PyPI", "Entry": "foo"}], metadata["Registry"].value)
+        self.assertEqual("1.0", metadata["Version"].value)
+        self.assertEqual("certain", metadata["Repository"].certainty)
+
+    def test_roundtrip(self):
+        metadata = UpstreamMetadata()
+        metadata["Name"] = UpstreamDatum("Name", "foo")
+        metadata["Keywords"] = UpstreamDatum("Keywords", ["a", "b"])
+        self.assertEqual(metadata, UpstreamMetadata.from_yaml(metadata.to_yaml()))
+
+    def test_from_empty(self):
+        self.assertFalse(UpstreamMetadata.from_yaml(""))
+
+    def test_from_yaml_single_string(self):
+        metadata = UpstreamMetadata.from_yaml(
+            """\
+Keywords: foo
+Screenshots: https://example.com/screenshot.png
+"""
+        )
+        self.assertEqual(["foo"], metadata["Keywords"].value)
+        self.assertEqual(
+            ["https://example.com/screenshot.png"], metadata["Screenshots"].value
+        )
+
+    def test_from_yaml_invalid(self):
+        self.assertRaises(
+            ValueError, UpstreamMetadata.from_yaml, 'Debian-ITP: "#123"\n'
+        )
+        self.assertRaises(ValueError, UpstreamMetadata.from_yaml, "Wiki: [a, b]\n")
@@ -0,0 +1,2532 @@
+#!/usr/bin/python3
+# Copyright (C) 2024 Jelmer Vernooij <jelmer@debian.org>
+#
+# This program is free software; you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation; either version 2 of the License, or
+# (at your option) any later version.
+#
+# This program is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with this program; if not, write to the Free Software
+# Foundation, Inc., 51 Franklin Street, Fifth Floor, Boston, MA 02110-1301 USA
+
+import os
+import shutil
+import subprocess
+import tempfile
+from unittest import TestCase
+
+from upstream_ontologist import Person, UpstreamDatum, UpstreamMetadata
+from upstream_ontologist.guess import (
+    add_known_bad_pattern,
+    remove_known_bad_pattern,
+    extrapolate_fields,
+    register_extrapolation,
+    unregister_extrapolation,
+    guess_from_app_src,
+    guess_from_authors_file,
+    guess_from_appimage_builder_yml,
+    guess_from_bower_json,
+    guess_from_build_gradle,
+    guess_from_cabal,
+    guess_from_changelog_file,
+    guess_from_chart_yaml,
+    guess_from_citation_cff,
+    guess_from_codeowners,
+    guess_from_codemeta_json,
+    guess_from_conanfile,
+    guess_from_conda_meta,
+    guess_from_csproj,
+    guess_from_debian_changelog,
+    guess_from_debian_control,
+    guess_from_debian_upstream_metadata,
+    guess_from_debian_copyright,
+    guess_from_debian_watch,
+    guess_from_deps_edn,
+    guess_from_desktop_file,
+    guess_from_dist_ini,
+    guess_from_docker_compose,
+    guess_from_dockerfile,
+    guess_from_dub_json,
+    guess_from_dub_sdl,
+    guess_from_flatpak_manifest,
+    guess_from_funding_yml,
+    guess_from_gemfile,
+    guess_from_git_config,
+    guess_from_github_settings,
+    guess_from_gitmodules,
+    guess_from_gradle_properties,
+    guess_from_issue_template_config,
+    guess_from_license_file,
+    guess_from_makefile_pl,
+    guess_from_meta_json,
+    guess_from_meta_yml,
+    guess_from_mix_exs,
+    guess_from_mkdocs_yml,
+    guess_from_module_bazel,
+    guess_from_nimble,
+    guess_from_nix,
+    guess_from_opam,
+    guess_from_package_swift,
+    guess_from_package_xml,
+    guess_from_pkgbuild,
+    guess_from_pod,
+    guess_from_podspec,
+    guess_from_project_clj,
+    guess_from_project_toml,
+    guess_from_pubspec_yaml,
+    guess_from_rebar_config,
+    guess_from_rockspec,
+    guess_from_rpm_spec,
+    guess_from_security_md,
+    guess_from_shard_yml,
+    guess_from_snapcraft_yaml,
+    guess_from_sphinx_conf,
+    guess_from_spi_yml,
+    guess_from_vcpkg_json,
+    guess_from_wscript,
+    guess_from_zenodo_json,
+    guess_upstream_metadata,
+    guess_upstream_metadata_items,
+    update_metadata_file,
+    guess_version_from_git,
+)
+
+
+class GuesserTestCase(TestCase):
+    def setUp(self):
+        super().setUp()
+        self.tmpdir = tempfile.mkdtemp()
+        self.addCleanup(shutil.rmtree, self.tmpdir)
+
+    def write_file(self, name, content):
+        path = os.path.join(self.tmpdir, name)
+        os.makedirs(os.path.dirname(path), exist_ok=True)
+        with open(path, "w") as f:
+            f.write(content)
+        return path
+
+    def assertGuesses(self, expected, guesses):
+        self.assertEqual(expected, [(d.field, d.value) for d in guesses])
+
+
+class GuessFromDebianWatchTests(GuesserTestCase):
+    def test_git_mode(self):
+        path = self.write_file(
+            "debian/watch",
+            """version=4
+opts="mode=git" https://github.com/jelmer/example refs/tags/v(.*)
+""",
+        )
+        self.assertGuesses(
+            [("Repository", "https://github.com/jelmer/example")],
+            guess_from_debian_watch(path),
+        )
+
+
+class GuessFromDebianControlTests(GuesserTestCase):
+    def test_source_name(self):
+        path = self.write_file(
+            "debian/control",
+            """Source: example
+Homepage: https://example.com/
+
+Package: example
+Description: Example package
+""",
+        )
+        self.write_file("debian/source/format", "3.0 (quilt)\n")
+        self.assertGuesses(
+            [
+                ("Homepage", "https://example.com/"),
+                ("Summary", "Example package"),
+                ("Name", "example"),
+            ],
+            guess_from_debian_control(path),
+        )
+
+
+class GuessFromDebianUpstreamMetadataTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "debian/upstream/metadata",
+            """\
+---
+Bug-Database: https://github.com/jelmer/example/issues
+Reference:
+  Title: Not something we know about
+Repository: https://github.com/jelmer/example.git
+""",
+        )
+        metadata = guess_from_debian_upstream_metadata(path)
+        self.assertEqual(["Bug-Database", "Repository"], sorted(metadata.keys()))
+        self.assertEqual(
+            "https://github.com/jelmer/example.git", metadata["Repository"].value
+        )
+        self.assertEqual("certain", metadata["Repository"].certainty)
+        self.assertEqual(path, metadata["Repository"].origin.path)
+
+    def test_missing(self):
+        self.assertRaises(
+            FileNotFoundError,
+            guess_from_debian_upstream_metadata,
+            os.path.join(self.tmpdir, "metadata"),
+        )
+
+
+class GuessFromDebianCopyrightTests(GuesserTestCase):
+    def test_dep5(self):
+        path = self.write_file(
+            "debian/copyright",
+            """Format: https://www.debian.org/doc/packaging-manuals/copyright-format/1.0/
+Upstream-Name: example
+Upstream-Contact: Jelmer Vernooij <jelmer@example.com>
+Source: https://github.com/jelmer/example
+
+Files: *
+Copyright: 2024 Jelmer Vernooij
+License: GPL-2+
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Contact", "Jelmer Vernooij <jelmer@example.com>"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_debian_copyright(path),
+        )
+
+
+class GuessFromDebianChangelogTests(GuesserTestCase):
+    def test_strips_epoch_and_revision(self):
+        path = self.write_file(
+            "debian/changelog",
+            """example (1:1.2.3-2) unstable; urgency=medium
+
+  * New upstream release.
+
+ -- Jelmer Vernooij <jelmer@debian.org>  Sat, 06 Jan 2024 12:00:00 +0000
+""",
+        )
+        self.assertGuesses(
+            [("Name", "example"), ("Version", "1.2.3")],
+            guess_from_debian_changelog(path),
+        )
+
+
+class GuessFromMetaJsonTests(GuesserTestCase):
+    def test_resources(self):
+        path = self.write_file(
+            "META.json",
+            """{
+  "name": "Example-Dist",
+  "abstract": "An example distribution",
+  "resources": {
+    "repository": {
+      "url": "https://github.com/jelmer/example.git",
+      "web": "https://github.com/jelmer/example"
+    },
+    "bugtracker": {"web": "https://github.com/jelmer/example/issues"}
+  }
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example-Dist"),
+                ("Summary", "An example distribution"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("Repository-Browse", "https://github.com/jelmer/example"),
+            ],
+            guess_from_meta_json(path),
+        )
+
+
+class GuessFromMetaYmlTests(GuesserTestCase):
+    def test_resources(self):
+        path = self.write_file(
+            "META.yml",
+            """---
+name: Example-Dist
+license: perl
+resources:
+  repository: https://github.com/jelmer/example.git
+  bugtracker: https://github.com/jelmer/example/issues
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example-Dist"),
+                ("License", "perl"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+            ],
+            guess_from_meta_yml(path),
+        )
+
+
+class GuessFromMakefilePLTests(GuesserTestCase):
+    def test_meta_merge(self):
+        path = self.write_file(
+            "Makefile.PL",
+            """use ExtUtils::MakeMaker;
+WriteMakefile(
+    NAME => 'Example::Dist',
+    ABSTRACT => 'An example distribution',
+    META_MERGE => {
+        resources => {
+            repository => {
+                type => 'git',
+                url => 'https://github.com/jelmer/example.git',
+                web => 'https://github.com/jelmer/example',
+            },
+            bugtracker => {
+                web => 'https://github.com/jelmer/example/issues',
+            },
+        },
+    },
+);
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example-Dist"),
+                ("Summary", "An example distribution"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("Repository-Browse", "https://github.com/jelmer/example"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+            ],
+            guess_from_makefile_pl(path),
+        )
+
+
+class GuessFromDistIniTests(GuesserTestCase):
+    def test_meta_resources(self):
+        path = self.write_file(
+            "dist.ini",
+            """name = Example-Dist
+license = Perl_5
+
+[MetaResources]
+repository.url = https://github.com/jelmer/example.git
+bugtracker.web = https://github.com/jelmer/example/issues
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example-Dist"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("License", "Perl_5"),
+            ],
+            guess_from_dist_ini(path),
+        )
+
+
+class GuessFromCabalTests(GuesserTestCase):
+    def test_haskell_package(self):
+        path = self.write_file(
+            "example.cabal",
+            """name:                example
+synopsis:            An example package
+homepage:            https://github.com/jelmer/example
+bug-reports:         https://github.com/jelmer/example/issues
+license:             BSD3
+
+source-repository head
+  type:     git
+  location: https://github.com/jelmer/example
+""",
+        )
+        guesses = guess_from_cabal(path)
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example package"),
+                ("Homepage", "https://github.com/jelmer/example"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("License", "BSD3"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Haskell-Package", "example"),
+            ],
+            guesses,
+        )
+        self.assertEqual({path}, {d.origin for d in guesses})
+
+
+class GuessFromOpamTests(GuesserTestCase):
+    def test_opam(self):
+        path = self.write_file(
+            "opam",
+            """opam-version: "2.0"
+synopsis: "An example library"
+maintainer: "Jelmer Vernooij <jelmer@jelmer.uk>"
+license: "MIT"
+homepage: "https://github.com/jelmer/example"
+doc: "https://jelmer.github.io/example"
+bug-reports: "https://github.com/jelmer/example/issues"
+dev-repo: "git+https://github.com/jelmer/example.git"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Summary", "An example library"),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+                ("License", "MIT"),
+                ("Homepage", "https://github.com/jelmer/example"),
+                ("Documentation", "https://jelmer.github.io/example"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+            ],
+            guess_from_opam(path),
+        )
+
+    def test_scanned(self):
+        self.write_file(
+            "opam",
+            """opam-version: "2.0"
+dev-repo: "git+https://github.com/jelmer/example.git"
+""",
+        )
+        self.assertIn(
+            ("Repository", "https://github.com/jelmer/example.git", "./opam"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+
+class GuessFromPubspecYamlTests(GuesserTestCase):
+    def test_pubspec(self):
+        path = self.write_file(
+            "pubspec.yaml",
+            """name: example
+description: An example Dart package.
+version: 1.0.0
+homepage: https://example.dev
+repository: https://github.com/jelmer/example
+issue_tracker: https://github.com/jelmer/example/issues
+documentation: https://example.dev/docs
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Description", "An example Dart package."),
+                ("Version", "1.0.0"),
+                ("Homepage", "https://example.dev"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Documentation", "https://example.dev/docs"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+            ],
+            guess_from_pubspec_yaml(path),
+        )
+
+
+class GuessFromPackageXmlTests(GuesserTestCase):
+    def test_pecl(self):
+        path = self.write_file(
+            "package.xml",
+            """<?xml version="1.0" encoding="UTF-8"?>
+<package version="2.0" xmlns="http://pear.php.net/dtd/package-2.0">
+ <name>example</name>
+ <channel>pecl.php.net</channel>
+ <summary>An example extension</summary>
+ <lead>
+  <name>Jelmer Vernooij</name>
+  <user>jelmer</user>
+  <email>jelmer@jelmer.uk</email>
+  <active>yes</active>
+ </lead>
+ <license>PHP</license>
+ <providesextension>example</providesextension>
+</package>
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example extension"),
+                ("License", "PHP"),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+                ("Pecl-Package", "example"),
+            ],
+            guess_from_package_xml(path),
+        )
+
+    def test_ros(self):
+        path = self.write_file(
+            "package.xml",
+            """<?xml version="1.0"?>
+<package format="2">
+  <name>example</name>
+  <version>1.2.3</version>
+  <description>An example ROS package</description>
+  <maintainer email="jelmer@jelmer.uk">Jelmer Vernooij</maintainer>
+  <license>BSD</license>
+  <url type="website">https://wiki.ros.org/example</url>
+  <url type="repository">https://github.com/jelmer/example</url>
+  <url type="bugtracker">https://github.com/jelmer/example/issues</url>
+  <buildtool_depend>catkin</buildtool_depend>
+</package>
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Description", "An example ROS package"),
+                ("License", "BSD"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+                ("Version", "1.2.3"),
+                ("Homepage", "https://wiki.ros.org/example"),
+            ],
+            guess_from_package_xml(path),
+        )
+
+
+class GuessFromPodTests(GuesserTestCase):
+    def test_pod(self):
+        self.assertGuesses(
+            [
+                ("Description", "Does example things."),
+                ("Name", "Example::Module"),
+                ("Summary", "An example module"),
+                ("Homepage", "https://example.com/"),
+            ],
+            guess_from_pod(
+                """=head1 NAME
+
+Example::Module - An example module
+
+=head1 DESCRIPTION
+
+Does example things.
+
+=head1 SEE ALSO
+
+L<Example website|https://example.com/>
+"""
+            ),
+        )
+
+
+class GuessFromSecurityMdTests(GuesserTestCase):
+    def test_email(self):
+        path = self.write_file(
+            ".github/SECURITY.md",
+            """# Security Policy
+
+Please report security issues to security@example.com.
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Security-MD", ".github/SECURITY.md"),
+                ("Security-Contact", "mailto:security@example.com"),
+            ],
+            guess_from_security_md(path),
+        )
+
+    def test_url(self):
+        path = self.write_file(
+            "SECURITY.md",
+            """# Security Policy
+
+Vulnerabilities can be reported at https://example.com/security.
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Security-MD", "SECURITY.md"),
+                ("Security-Contact", "https://example.com/security"),
+            ],
+            guess_from_security_md(path),
+        )
+
+
+class GuessFromGitConfigTests(GuesserTestCase):
+    def test_remotes(self):
+        self.write_file(
+            ".git/config",
+            """[core]
+\trepositoryformatversion = 0
+[remote "origin"]
+\turl = https://github.com/jelmer/fork
+\tfetch = +refs/heads/*:refs/remotes/origin/*
+[remote "upstream"]
+\turl = https://github.com/jelmer/example
+\tfetch = +refs/heads/*:refs/remotes/upstream/*
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Repository", "https://github.com/jelmer/fork"),
+            ],
+            guess_from_git_config(self.tmpdir),
+        )
+
+    def test_trust_package(self):
+        path = self.write_file(
+            ".git/config",
+            """[remote "origin"]
+\turl = https://github.com/jelmer/fork
+""",
+        )
+        self.assertGuesses([], guess_from_git_config(path, trust_package=True))
+
+
+class GuessFromCsprojTests(GuesserTestCase):
+    def test_csproj(self):
+        path = self.write_file(
+            "Example.csproj",
+            """<Project Sdk="Microsoft.NET.Sdk">
+  <PropertyGroup>
+    <TargetFramework>net8.0</TargetFramework>
+    <PackageId>Example</PackageId>
+    <Version>1.2.3</Version>
+    <Authors>Jelmer Vernooij; Someone Else</Authors>
+    <Description>An example library</Description>
+    <PackageProjectUrl>https://example.com/</PackageProjectUrl>
+    <RepositoryUrl>https://github.com/jelmer/example</RepositoryUrl>
+    <PackageLicenseExpression>MIT</PackageLicenseExpression>
+  </PropertyGroup>
+</Project>
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example"),
+                ("Version", "1.2.3"),
+                (
+                    "Author",
+                    [Person("Jelmer Vernooij"), Person("Someone Else")],
+                ),
+                ("Description", "An example library"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("License", "MIT"),
+            ],
+            guess_from_csproj(path),
+        )
+
+
+class GuessFromCitationCffTests(GuesserTestCase):
+    def test_citation(self):
+        path = self.write_file(
+            "CITATION.cff",
+            """cff-version: 1.2.0
+title: example
+message: If you use this software, please cite it as below.
+authors:
+  - family-names: Vernooij
+    given-names: Jelmer
+    orcid: https://orcid.org/0000-0000-0000-0000
+repository-code: https://github.com/jelmer/example
+url: https://example.com/
+license: Apache-2.0
+preferred-citation:
+  type: article
+  doi: 10.1234/example
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                (
+                    "Author",
+                    [
+                        Person(
+                            "Jelmer Vernooij",
+                            url="https://orcid.org/0000-0000-0000-0000",
+                        )
+                    ],
+                ),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Homepage", "https://example.com/"),
+                ("License", "Apache-2.0"),
+                ("Cite-As", "https://doi.org/10.1234/example"),
+            ],
+            guess_from_citation_cff(path),
+        )
+
+
+class GuessFromCodemetaJsonTests(GuesserTestCase):
+    def test_codemeta(self):
+        path = self.write_file(
+            "codemeta.json",
+            """{
+  "@context": "https://doi.org/10.5063/schema/codemeta-2.0",
+  "@type": "SoftwareSourceCode",
+  "name": "example",
+  "codeRepository": "https://github.com/jelmer/example",
+  "issueTracker": "https://github.com/jelmer/example/issues",
+  "license": "https://spdx.org/licenses/GPL-2.0-or-later",
+  "author": [
+    {
+      "@type": "Person",
+      "givenName": "Jelmer",
+      "familyName": "Vernooij",
+      "email": "jelmer@jelmer.uk"
+    }
+  ],
+  "funding": "https://example.com/funding"
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("License", "GPL-2.0-or-later"),
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+                ("Funding", "https://example.com/funding"),
+            ],
+            guess_from_codemeta_json(path),
+        )
+
+
+class GuessFromZenodoJsonTests(GuesserTestCase):
+    def test_zenodo(self):
+        path = self.write_file(
+            ".zenodo.json",
+            """{
+  "title": "example",
+  "creators": [
+    {"name": "Vernooij, Jelmer", "orcid": "0000-0000-0000-0000"}
+  ],
+  "license": {"id": "MIT"},
+  "keywords": ["metadata", "packaging"],
+  "related_identifiers": [
+    {
+      "identifier": "https://github.com/jelmer/example",
+      "relation": "isSupplementTo",
+      "scheme": "url"
+    }
+  ]
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                (
+                    "Author",
+                    [
+                        Person(
+                            "Jelmer Vernooij",
+                            url="https://orcid.org/0000-0000-0000-0000",
+                        )
+                    ],
+                ),
+                ("License", "MIT"),
+                ("Keywords", ["metadata", "packaging"]),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_zenodo_json(path),
+        )
+
+
+class GuessFromFundingYmlTests(GuesserTestCase):
+    def test_funding(self):
+        path = self.write_file(
+            ".github/FUNDING.yml",
+            """github: [jelmer]
+open_collective: example
+ko_fi: jelmer
+custom: ["https://www.paypal.me/jelmer"]
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Funding", "https://github.com/sponsors/jelmer"),
+                ("Funding", "https://opencollective.com/example"),
+                ("Funding", "https://ko-fi.com/jelmer"),
+                ("Donation", "https://www.paypal.me/jelmer"),
+            ],
+            guess_from_funding_yml(path),
+        )
+
+
+class GuessFromSnapcraftYamlTests(GuesserTestCase):
+    def test_snapcraft(self):
+        path = self.write_file(
+            "snap/snapcraft.yaml",
+            """name: example
+summary: An example snap
+description: |
+  This is an example snap.
+license: GPL-3.0
+website: https://example.com/
+contact: mailto:jelmer@jelmer.uk
+issues: https://github.com/jelmer/example/issues
+source-code: https://github.com/jelmer/example
+parts:
+  example:
+    plugin: python
+    source: https://github.com/someone/else.git
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example snap"),
+                ("Description", "This is an example snap."),
+                ("License", "GPL-3.0"),
+                ("Homepage", "https://example.com/"),
+                ("Contact", "mailto:jelmer@jelmer.uk"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_snapcraft_yaml(path),
+        )
+
+
+class GuessFromFlatpakManifestTests(GuesserTestCase):
+    def test_manifest(self):
+        path = self.write_file(
+            "io.github.jelmer.Example.yml",
+            """app-id: io.github.jelmer.Example
+runtime: org.gnome.Platform
+command: example
+modules:
+  - name: libfoo
+    sources:
+      - type: git
+        url: https://github.com/someone/libfoo
+  - name: example
+    buildsystem: meson
+    sources:
+      - type: git
+        url: https://github.com/jelmer/example.git
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Homepage", "https://github.com/jelmer/Example"),
+                ("Name", "example"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+            ],
+            guess_from_flatpak_manifest(path),
+        )
+
+    def test_not_a_manifest(self):
+        path = self.write_file("org.example.data.json", '{"foo": "bar"}')
+        self.assertGuesses([], guess_from_flatpak_manifest(path))
+
+
+class GuessFromDesktopFileTests(GuesserTestCase):
+    def test_desktop(self):
+        path = self.write_file(
+            "example.desktop",
+            """[Desktop Entry]
+Type=Application
+Name=Example
+Name[nl]=Voorbeeld
+Comment=An example application
+Icon=example
+X-AppImage-Homepage=https://example.com/
+
+[Desktop Action New]
+Name=New Window
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example"),
+                ("Summary", "An example application"),
+                ("Homepage", "https://example.com/"),
+            ],
+            guess_from_desktop_file(path),
+        )
+
+    def test_appimage_builder(self):
+        path = self.write_file(
+            "AppImageBuilder.yml",
+            """version: 1
+AppDir:
+  path: ./AppDir
+  app_info:
+    id: com.example.Example
+    name: Example
+    icon: example
+    version: 1.2.3
+    exec: usr/bin/example
+""",
+        )
+        self.assertGuesses(
+            [("Name", "Example"), ("Version", "1.2.3")],
+            guess_from_appimage_builder_yml(path),
+        )
+
+
+class GuessFromCondaMetaTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "conda.recipe/meta.yaml",
+            "package:\n  name: example\nabout:\n  home: https://example.com/\n",
+        )
+        self.assertIn(
+            ("Homepage", "https://example.com/", "./conda.recipe/meta.yaml"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_recipe(self):
+        path = self.write_file(
+            "recipe/meta.yaml",
+            """{% set name = "Example" %}
+{% set version = "1.2.3" %}
+
+package:
+  name: {{ name|lower }}
+  version: {{ version }}
+
+source:
+  url: https://pypi.io/packages/source/e/example/example-{{ version }}.tar.gz
+  sha256: 0000000000000000000000000000000000000000000000000000000000000000
+
+build:
+  number: 0
+  script: {{ PYTHON }} -m pip install . -vv
+
+about:
+  home: https://example.com/
+  dev_url: https://github.com/jelmer/example
+  doc_url: https://example.readthedocs.io/
+  license: MIT
+  summary: An example package
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Documentation", "https://example.readthedocs.io/"),
+                ("License", "MIT"),
+                ("Summary", "An example package"),
+                (
+                    "Download",
+                    "https://pypi.io/packages/source/e/example/example-1.2.3.tar.gz",
+                ),
+            ],
+            guess_from_conda_meta(path),
+        )
+
+
+class GuessFromRpmSpecTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "example.spec",
+            "Name: example\nVersion: 1.2.3\nURL: https://example.com/\n",
+        )
+        self.assertIn(
+            ("Homepage", "https://example.com/", "./example.spec"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_spec(self):
+        path = self.write_file(
+            "example.spec",
+            """%global srcname example
+
+Name:           python-%{srcname}
+Version:        1.2.3
+Release:        1%{?dist}
+Summary:        An example package
+License:        MIT
+URL:            https://example.com/%{srcname}
+Source0:        https://example.com/%{srcname}-%{version}.tar.gz
+VCS:            git:https://github.com/jelmer/example
+
+%description
+Example is an example package.
+
+%package -n python3-%{srcname}
+Summary:        %{summary}
+
+%description -n python3-%{srcname}
+Python 3 bindings.
+
+%prep
+%autosetup -n %{srcname}-%{version}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "python-example"),
+                ("Version", "1.2.3"),
+                ("Summary", "An example package"),
+                ("License", "MIT"),
+                ("Homepage", "https://example.com/example"),
+                ("Download", "https://example.com/example-1.2.3.tar.gz"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Description", "Example is an example package."),
+            ],
+            guess_from_rpm_spec(path),
+        )
+
+
+class GuessFromPkgbuildTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "PKGBUILD",
+            'pkgname=example\npkgver=1.2.3\nurl="https://example.com/"\n',
+        )
+        self.assertIn(
+            ("Homepage", "https://example.com/", "./PKGBUILD"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_pkgbuild(self):
+        path = self.write_file(
+            "PKGBUILD",
+            """# Maintainer: Jelmer Vernooij <jelmer@jelmer.uk>
+pkgname=example
+pkgver=1.2.3
+pkgrel=1
+pkgdesc="An example package"
+arch=('any')
+url="https://example.com/"
+license=('GPL-2.0-or-later')
+source=("$pkgname-$pkgver::git+https://github.com/jelmer/example.git#tag=v$pkgver")
+sha256sums=('SKIP')
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Summary", "An example package"),
+                ("Homepage", "https://example.com/"),
+                ("License", "GPL-2.0-or-later"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+            ],
+            guess_from_pkgbuild(path),
+        )
+
+    def test_vcs_package(self):
+        path = self.write_file(
+            "PKGBUILD",
+            """pkgname=example-git
+pkgver=r123.abcdef
+source=("git+https://github.com/jelmer/example")
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_pkgbuild(path),
+        )
+
+
+class GuessFromNixTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "flake.nix",
+            """{
+  description = "An example package";
+  outputs = { self, nixpkgs }: {
+    packages.default = nixpkgs.stdenv.mkDerivation {
+      pname = "example";
+      meta.homepage = "https://example.com/";
+    };
+  };
+}
+""",
+        )
+        self.assertIn(
+            ("Summary", "An example package", "./flake.nix"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_derivation(self):
+        path = self.write_file(
+            "default.nix",
+            """{ lib, stdenv, fetchFromGitHub }:
+
+stdenv.mkDerivation rec {
+  pname = "example";
+  version = "1.2.3";
+
+  src = fetchFromGitHub {
+    owner = "jelmer";
+    repo = pname;
+    rev = "v${version}";
+    hash = "sha256-AAAA";
+  };
+
+  meta = with lib; {
+    description = "An example package";
+    homepage = "https://example.com/";
+    license = licenses.mit;
+  };
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Homepage", "https://example.com/"),
+                ("Summary", "An example package"),
+                ("License", "mit"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_nix(path),
+        )
+
+
+class GuessFromGradleTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "gradle.properties",
+            "VERSION_NAME=1.2.3\nPOM_URL=https://example.com/\n",
+        )
+        self.assertIn(
+            ("Homepage", "https://example.com/", "./gradle.properties"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_build_gradle_kts(self):
+        path = self.write_file(
+            "build.gradle.kts",
+            """plugins {
+    `maven-publish`
+}
+
+publishing {
+    publications {
+        create<MavenPublication>("maven") {
+            pom {
+                name.set("example")
+                description.set("An example library")
+                url.set("https://example.com/")
+                licenses {
+                    license {
+                        name.set("The Apache License, Version 2.0")
+                        url.set("https://www.apache.org/licenses/LICENSE-2.0.txt")
+                    }
+                }
+                scm {
+                    connection.set("scm:git:git://github.com/jelmer/example.git")
+                    url.set("https://github.com/jelmer/example")
+                }
+            }
+        }
+    }
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example library"),
+                ("Homepage", "https://example.com/"),
+                ("License", "The Apache License, Version 2.0"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_build_gradle(path),
+        )
+
+    def test_gradle_properties(self):
+        path = self.write_file(
+            "gradle.properties",
+            """org.gradle.jvmargs=-Xmx2g
+VERSION_NAME=1.2.3
+POM_URL=https://example.com/
+POM_SCM_URL=https://github.com/jelmer/example
+POM_LICENSE_NAME=MIT
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Version", "1.2.3"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("License", "MIT"),
+            ],
+            guess_from_gradle_properties(path),
+        )
+
+
+class GuessFromMixExsTests(GuesserTestCase):
+    def test_scanned(self):
+        self.write_file(
+            "mix.exs",
+            """defmodule Example.MixProject do
+  use Mix.Project
+
+  def project do
+    [app: :example, version: "1.2.3", description: "An example library"]
+  end
+end
+""",
+        )
+        self.assertIn(
+            ("Summary", "An example library", "./mix.exs"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+    def test_mix_exs(self):
+        path = self.write_file(
+            "mix.exs",
+            """defmodule Example.MixProject do
+  use Mix.Project
+
+  @version "1.2.3"
+  @source_url "https://github.com/jelmer/example"
+
+  def project do
+    [
+      app: :example,
+      version: @version,
+      elixir: "~> 1.14",
+      description: "An example library",
+      source_url: @source_url,
+      package: package(),
+      deps: deps()
+    ]
+  end
+
+  defp package do
+    [
+      licenses: ["Apache-2.0"],
+      links: %{
+        "GitHub" => @source_url,
+        "Changelog" => "https://hexdocs.pm/example/changelog.html"
+      }
+    ]
+  end
+
+  defp deps do
+    [{:ex_doc, "~> 0.31", only: :dev, runtime: false}]
+  end
+end
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Registry", [{"Name": "Hex", "Entry": "example"}]),
+                ("Version", "1.2.3"),
+                ("Summary", "An example library"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("License", "Apache-2.0"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Changelog", "https://hexdocs.pm/example/changelog.html"),
+            ],
+            guess_from_mix_exs(path),
+        )
+
+
+class GuessFromErlangTests(GuesserTestCase):
+    def test_app_src(self):
+        path = self.write_file(
+            "src/example.app.src",
+            """{application, example, [
+    {description, "An example application"},
+    {vsn, "1.2.3"},
+    {registered, []},
+    {applications, [kernel, stdlib]},
+    {licenses, ["Apache-2.0"]},
+    {links, [{"GitHub", "https://github.com/jelmer/example"}]}
+]}.
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Registry", [{"Name": "Hex", "Entry": "example"}]),
+                ("Summary", "An example application"),
+                ("Version", "1.2.3"),
+                ("License", "Apache-2.0"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_app_src(path),
+        )
+
+    def test_rebar_config(self):
+        path = self.write_file(
+            "rebar.config",
+            """{erl_opts, [debug_info]}.
+{ex_doc, [
+    {source_url, <<"https://github.com/jelmer/example">>},
+    {extras, [<<"README.md">>]}
+]}.
+""",
+        )
+        self.assertGuesses(
+            [("Repository", "https://github.com/jelmer/example")],
+            guess_from_rebar_config(path),
+        )
+
+    def test_scanned(self):
+        self.write_file("src/example.app.src", '{application, example, [{vsn, "1.2.3"}]}.')
+        self.assertIn(
+            ("Version", "1.2.3", "./src/example.app.src"),
+            [
+                (d.field, d.value, d.origin)
+                for d in guess_upstream_metadata_items(self.tmpdir)
+            ],
+        )
+
+
+class GuessFromSwiftTests(GuesserTestCase):
+    def test_package_swift(self):
+        path = self.write_file(
+            "Package.swift",
+            """// swift-tools-version:5.9
+import PackageDescription
+
+let package = Package(
+    name: "Example",
+    dependencies: [
+        .package(url: "https://github.com/apple/swift-argument-parser", from: "1.0.0"),
+    ],
+    targets: [.target(name: "Example")]
+)
+""",
+        )
+        self.assertGuesses([("Name", "Example")], guess_from_package_swift(path))
+
+    def test_spi_yml(self):
+        path = self.write_file(
+            ".spi.yml",
+            """version: 1
+external_links:
+  documentation: "https://example.com/docs"
+""",
+        )
+        self.assertGuesses(
+            [("Documentation", "https://example.com/docs")], guess_from_spi_yml(path)
+        )
+
+
+class GuessFromPodspecTests(GuesserTestCase):
+    def test_ruby(self):
+        path = self.write_file(
+            "Example.podspec",
+            """Pod::Spec.new do |s|
+  s.name             = 'Example'
+  s.version          = '1.2.3'
+  s.summary          = 'An example pod'
+  s.description      = <<-DESC
+    Example does example things.
+                       DESC
+  s.homepage         = 'https://example.com/'
+  s.screenshots      = [ 'https://example.com/screenshot.png' ]
+  s.license          = { :type => 'MIT', :file => 'LICENSE' }
+  s.author           = { 'Jelmer Vernooij' => 'jelmer@jelmer.uk' }
+  s.source           = { :git => 'https://github.com/jelmer/example.git', :tag => s.version.to_s }
+end
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example"),
+                ("Homepage", "https://example.com/"),
+                ("Summary", "An example pod"),
+                ("Description", "Example does example things."),
+                ("License", "MIT"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+                ("Screenshots", ["https://example.com/screenshot.png"]),
+            ],
+            guess_from_podspec(path),
+        )
+
+    def test_json(self):
+        path = self.write_file(
+            "Example.podspec.json",
+            """{
+  "name": "Example",
+  "homepage": "https://example.com/",
+  "license": {"type": "MIT"},
+  "authors": {"Jelmer Vernooij": "jelmer@jelmer.uk"},
+  "source": {"git": "https://github.com/jelmer/example.git", "tag": "1.2.3"},
+  "summary": "An example pod"
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example"),
+                ("Homepage", "https://example.com/"),
+                ("Summary", "An example pod"),
+                ("License", "MIT"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+            ],
+            guess_from_podspec(path),
+        )
+
+
+class GuessFromDubTests(GuesserTestCase):
+    def test_json(self):
+        path = self.write_file(
+            "dub.json",
+            """{
+  "name": "example",
+  "description": "An example D library",
+  "homepage": "https://example.com/",
+  "license": "BSL-1.0",
+  "authors": ["Jelmer Vernooij <jelmer@jelmer.uk>"],
+  "configurations": [{"name": "library"}]
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+                ("Summary", "An example D library"),
+                ("Homepage", "https://example.com/"),
+                ("License", "BSL-1.0"),
+                ("Name", "example"),
+                ("Registry", [{"Name": "DUB", "Entry": "example"}]),
+            ],
+            guess_from_dub_json(path),
+        )
+
+    def test_sdl(self):
+        path = self.write_file(
+            "dub.sdl",
+            """name "example"
+description "An example D library"
+authors "Jelmer Vernooij" "Somebody Else"
+license "BSL-1.0"
+configuration "unittest" {
+    name "ignored"
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Registry", [{"Name": "DUB", "Entry": "example"}]),
+                ("Summary", "An example D library"),
+                ("Author", [Person("Jelmer Vernooij"), Person("Somebody Else")]),
+                ("License", "BSL-1.0"),
+            ],
+            guess_from_dub_sdl(path),
+        )
+
+
+class GuessFromShardYmlTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "shard.yml",
+            """name: example
+version: 0.1.0
+description: |
+  An example shard
+authors:
+  - Jelmer Vernooij <jelmer@jelmer.uk>
+license: MIT
+crystal: ">= 1.0.0"
+documentation: https://jelmer.github.io/example/
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "0.1.0"),
+                ("Summary", "An example shard"),
+                ("License", "MIT"),
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+                ("Documentation", "https://jelmer.github.io/example/"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_shard_yml(path),
+        )
+
+    def test_repository(self):
+        path = self.write_file(
+            "shard.yml",
+            """name: example
+homepage: https://github.com/jelmer/example
+repository: https://git.example.com/example.git
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Homepage", "https://github.com/jelmer/example"),
+                ("Repository", "https://git.example.com/example.git"),
+            ],
+            guess_from_shard_yml(path),
+        )
+
+
+class GuessFromClojureTests(GuesserTestCase):
+    def test_project_clj(self):
+        path = self.write_file(
+            "project.clj",
+            """(defproject org.example/example "1.0.0"
+  :description "An example library"
+  :url "https://example.com/"
+  ;; The license
+  :license {:name "EPL-2.0"
+            :url "https://www.eclipse.org/legal/epl-2.0/"}
+  :scm {:name "git" :url "https://github.com/jelmer/example"}
+  :dependencies [[org.clojure/clojure "1.11.1"]]
+  :repositories [["releases" {:url "https://repo.example.com/"}]])
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.0.0"),
+                ("Summary", "An example library"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("License", "EPL-2.0"),
+            ],
+            guess_from_project_clj(path),
+        )
+
+    def test_deps_edn(self):
+        path = self.write_file(
+            "deps.edn",
+            """{:paths ["src"]
+ :deps {org.clojure/clojure {:mvn/version "1.11.1"}}
+ :mvn/repos {"clojars" {:url "https://repo.clojars.org/"}}
+ :aliases
+ {:jar {:extra-deps {com.github.seancorfield/depstar {:mvn/version "2.1.303"}}
+        :exec-args {:lib org.example/example
+                    :description "An example library"
+                    :licenses [{:name "MIT"}]
+                    :scm {:url "https://github.com/jelmer/example"}}}}}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example library"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("License", "MIT"),
+            ],
+            guess_from_deps_edn(path),
+        )
+
+
+    def test_truncated_project_clj(self):
+        path = self.write_file("project.clj", '(defproject foo "1.0" :x \u00e9')
+        # PanicException isn't an Exception subclass, so this also checks for a panic
+        self.assertRaisesRegex(
+            Exception, "no defproject form found", guess_from_project_clj, path
+        )
+
+class GuessFromNimbleTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "example.nimble",
+            """# Package
+
+version       = "0.1.0"
+author        = "Jelmer Vernooij"
+description   = "An example package"
+license       = "MIT"
+srcDir        = "src"
+
+# Dependencies
+
+requires "nim >= 1.6.0"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Registry", [{"Name": "Nimble", "Entry": "example"}]),
+                ("Version", "0.1.0"),
+                ("Author", [Person("Jelmer Vernooij")]),
+                ("Summary", "An example package"),
+                ("License", "MIT"),
+            ],
+            guess_from_nimble(path),
+        )
+
+
+class GuessFromBowerJsonTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "bower.json",
+            """{
+  "name": "example",
+  "description": "An example library",
+  "homepage": "https://example.com/",
+  "license": ["MIT", "Apache-2.0"],
+  "repository": {"type": "git", "url": "https://github.com/jelmer/example.git"},
+  "authors": [
+    "Jelmer Vernooij <jelmer@jelmer.uk> (https://www.jelmer.uk/)",
+    {"name": "Somebody Else", "email": "somebody@example.com"}
+  ]
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example library"),
+                ("Homepage", "https://example.com/"),
+                ("License", "MIT OR Apache-2.0"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                (
+                    "Author",
+                    [
+                        Person(
+                            "Jelmer Vernooij",
+                            "jelmer@jelmer.uk",
+                            "https://www.jelmer.uk/",
+                        ),
+                        Person("Somebody Else", "somebody@example.com"),
+                    ],
+                ),
+            ],
+            guess_from_bower_json(path),
+        )
+
+
+class GuessFromWscriptTests(GuesserTestCase):
+    WSCRIPT = """#!/usr/bin/env python
+APPNAME='example'
+VERSION = "1.2.3"
+HOMEPAGE = 'https://example.com/'
+
+def configure(conf):
+    conf.load('compiler_c')
+"""
+
+    def test_guess(self):
+        path = self.write_file("wscript", self.WSCRIPT)
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Homepage", "https://example.com/"),
+            ],
+            guess_from_wscript(path),
+        )
+
+    def test_scanned(self):
+        self.write_file("wscript", self.WSCRIPT)
+        items = [
+            (d.field, d.value, d.origin)
+            for d in guess_upstream_metadata_items(self.tmpdir)
+        ]
+        self.assertIn(("Homepage", "https://example.com/", "./wscript"), items)
+
+
+class GuessFromVcpkgJsonTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "vcpkg.json",
+            """{
+  "name": "example",
+  "version-semver": "1.2.3",
+  "description": ["An example library", "It does example things."],
+  "homepage": "https://example.com/",
+  "license": "MIT",
+  "maintainers": "Jelmer Vernooij <jelmer@jelmer.uk>",
+  "dependencies": ["fmt"]
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Summary", "An example library"),
+                ("Description", "It does example things."),
+                ("Homepage", "https://example.com/"),
+                ("License", "MIT"),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+            ],
+            guess_from_vcpkg_json(path),
+        )
+
+
+class GuessFromConanfileTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "conanfile.py",
+            """from conan import ConanFile
+
+
+class ExampleConan(ConanFile):
+    name = "example"
+    version = "1.2.3"
+    description = ("An example library "
+                   "for examples")
+    license = "MIT", "Apache-2.0"
+    url = "https://github.com/conan-io/conan-center-index"
+    homepage = "https://example.com/"
+    topics = ("example", "demo")
+    settings = "os", "compiler", "build_type", "arch"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Summary", "An example library for examples"),
+                ("Homepage", "https://example.com/"),
+                ("License", "MIT OR Apache-2.0"),
+                ("Keywords", ["example", "demo"]),
+            ],
+            guess_from_conanfile(path),
+        )
+
+
+class GuessFromModuleBazelTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "MODULE.bazel",
+            """module(
+    name = "rules_example",
+    version = "1.2.3",
+    compatibility_level = 1,
+)
+
+bazel_dep(name = "platforms", version = "0.0.8")
+
+git_override(
+    module_name = "rules_example",
+    remote = "https://github.com/jelmer/rules_example.git",
+    commit = "abcdef",
+)
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "rules_example"),
+                ("Version", "1.2.3"),
+                ("Repository", "https://github.com/jelmer/rules_example.git"),
+            ],
+            guess_from_module_bazel(path),
+        )
+
+
+class GuessFromGemfileTests(GuesserTestCase):
+    def test_gemspec_path(self):
+        os.mkdir(os.path.join(self.tmpdir, "gem"))
+        self.write_file(
+            "gem/example.gemspec",
+            """Gem::Specification.new do |s|
+  s.name = "example"
+  s.homepage = "https://example.com/"
+end
+""",
+        )
+        path = self.write_file(
+            "Gemfile",
+            """source "https://rubygems.org"
+
+gemspec path: "gem"
+gem "example", github: "jelmer/example"
+gem "rake", git: "https://github.com/ruby/rake.git"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+            ],
+            guess_from_gemfile(path),
+        )
+
+
+class GuessFromRockspecTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "example-1.0-1.rockspec",
+            """package = "example"
+version = "1.0-1"
+source = {
+   url = "git+https://github.com/jelmer/example.git",
+   tag = "v1.0",
+}
+description = {
+   summary = "An example rock",
+   detailed = [[
+      Example does example things.
+   ]],
+   homepage = "https://example.com/",
+   license = "MIT",
+   issues_url = "https://github.com/jelmer/example/issues",
+}
+dependencies = {
+   "lua >= 5.1",
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.0"),
+                ("Repository", "https://github.com/jelmer/example.git"),
+                ("Summary", "An example rock"),
+                ("Description", "Example does example things."),
+                ("Homepage", "https://example.com/"),
+                ("License", "MIT"),
+                ("Bug-Database", "https://github.com/jelmer/example/issues"),
+            ],
+            guess_from_rockspec(path),
+        )
+
+
+class GuessFromProjectTomlTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "Project.toml",
+            """name = "Example"
+uuid = "7876af07-990d-54b4-ab0e-23690620f79a"
+authors = ["Jelmer Vernooij <jelmer@jelmer.uk>"]
+version = "0.5.1"
+
+[deps]
+Test = "8dfed614-e22c-5e08-85e1-65c5234f0b40"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "Example"),
+                ("Registry", [{"Name": "Julia", "Entry": "Example"}]),
+                ("Version", "0.5.1"),
+                ("Author", [Person("Jelmer Vernooij", "jelmer@jelmer.uk")]),
+            ],
+            guess_from_project_toml(path),
+        )
+
+    def test_environment(self):
+        path = self.write_file(
+            "Project.toml",
+            """[deps]
+Test = "8dfed614-e22c-5e08-85e1-65c5234f0b40"
+""",
+        )
+        self.assertGuesses([], guess_from_project_toml(path))
+
+
+class GuessFromDockerTests(GuesserTestCase):
+    def test_dockerfile(self):
+        path = self.write_file(
+            "Dockerfile",
+            """FROM debian:bookworm
+LABEL org.opencontainers.image.source="https://github.com/jelmer/example" \\
+      org.opencontainers.image.url=https://example.com/ \\
+      org.opencontainers.image.licenses="Apache-2.0"
+LABEL maintainer="Jelmer Vernooij <jelmer@jelmer.uk>"
+RUN apt-get update
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Homepage", "https://example.com/"),
+                ("License", "Apache-2.0"),
+            ],
+            guess_from_dockerfile(path),
+        )
+
+    def test_docker_compose(self):
+        path = self.write_file(
+            "docker-compose.yml",
+            """services:
+  web:
+    build:
+      context: .
+      labels:
+        org.opencontainers.image.documentation: https://example.com/docs
+    labels:
+      - "org.opencontainers.image.source=https://github.com/jelmer/example"
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Documentation", "https://example.com/docs"),
+            ],
+            guess_from_docker_compose(path),
+        )
+
+
+class GuessFromChartYamlTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "Chart.yaml",
+            """apiVersion: v2
+name: example
+version: 0.3.0
+appVersion: "1.2.3"
+description: An example application
+home: https://example.com/
+icon: https://example.com/icon.png
+sources:
+  - https://github.com/jelmer/example
+maintainers:
+  - name: Jelmer Vernooij
+    email: jelmer@jelmer.uk
+annotations:
+  artifacthub.io/license: Apache-2.0
+  artifacthub.io/links: |
+    - name: Documentation
+      url: https://example.com/docs
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Version", "1.2.3"),
+                ("Summary", "An example application"),
+                ("Homepage", "https://example.com/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+                ("License", "Apache-2.0"),
+                ("Documentation", "https://example.com/docs"),
+            ],
+            guess_from_chart_yaml(path),
+        )
+
+
+class GuessFromGitmodulesTests(GuesserTestCase):
+    def test_single(self):
+        path = self.write_file(
+            ".gitmodules",
+            """[submodule "upstream"]
+\tpath = upstream
+\turl = https://github.com/jelmer/example.git
+""",
+        )
+        self.assertGuesses(
+            [("Repository", "https://github.com/jelmer/example.git")],
+            guess_from_gitmodules(path),
+        )
+
+    def test_multiple(self):
+        path = self.write_file(
+            ".gitmodules",
+            """[submodule "a"]
+\tpath = vendor/a
+\turl = https://github.com/jelmer/a.git
+[submodule "b"]
+\tpath = vendor/b
+\turl = https://github.com/jelmer/b.git
+""",
+        )
+        self.assertGuesses([], guess_from_gitmodules(path))
+
+
+class GuessFromGithubConfigTests(GuesserTestCase):
+    def test_issue_template_config(self):
+        os.makedirs(os.path.join(self.tmpdir, ".github", "ISSUE_TEMPLATE"))
+        path = self.write_file(
+            ".github/ISSUE_TEMPLATE/config.yml",
+            """blank_issues_enabled: false
+contact_links:
+  - name: Report a bug
+    url: https://bugs.example.com/new
+    about: File bugs in our tracker
+  - name: Questions
+    url: https://github.com/jelmer/example/discussions
+    about: Ask questions in discussions
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Bug-Submit", "https://bugs.example.com/new"),
+                ("Contact", "https://github.com/jelmer/example/discussions"),
+            ],
+            guess_from_issue_template_config(path),
+        )
+
+    def test_codeowners(self):
+        path = self.write_file(
+            "CODEOWNERS",
+            """# Default owners
+*       @jelmer @example/team
+/docs/  docs@example.com
+""",
+        )
+        self.assertGuesses(
+            [
+                (
+                    "Maintainer",
+                    Person("jelmer", url="https://github.com/jelmer"),
+                ),
+            ],
+            guess_from_codeowners(path),
+        )
+
+    def test_settings(self):
+        os.mkdir(os.path.join(self.tmpdir, ".github"))
+        path = self.write_file(
+            ".github/settings.yml",
+            """repository:
+  name: example
+  description: An example project
+  homepage: https://example.com/
+  topics: example, demo
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example project"),
+                ("Homepage", "https://example.com/"),
+                ("Keywords", ["example", "demo"]),
+            ],
+            guess_from_github_settings(path),
+        )
+
+
+class ReadTheDocsTests(GuesserTestCase):
+    def test_documentation(self):
+        self.write_file(".readthedocs.yaml", "version: 2\n")
+        self.write_file("dub.json", '{"name": "example_project"}')
+        metadata = guess_upstream_metadata(self.tmpdir)
+        self.assertEqual(
+            "https://example-project.readthedocs.io/",
+            metadata["Documentation"].value,
+        )
+        self.assertEqual("possible", metadata["Documentation"].certainty)
+
+
+class GuessFromSphinxConfTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "docs/conf.py",
+            """import os
+import sys
+
+project = 'example'
+copyright = '2024, Jelmer Vernooij'
+author = 'Jelmer Vernooij'
+release = example.__version__
+
+html_theme = 'sphinx_rtd_theme'
+html_context = {
+    "display_github": True,
+    "github_user": "jelmer",
+    "github_repo": "example",
+}
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Author", [Person("Jelmer Vernooij")]),
+                ("Copyright", "2024, Jelmer Vernooij"),
+                ("Repository", "https://github.com/jelmer/example"),
+            ],
+            guess_from_sphinx_conf(path),
+        )
+
+
+class GuessFromMkdocsYmlTests(GuesserTestCase):
+    def test_guess(self):
+        path = self.write_file(
+            "mkdocs.yml",
+            """site_name: example
+site_url: https://example.readthedocs.io/
+site_description: An example project
+repo_url: https://github.com/jelmer/example
+copyright: Copyright &copy; 2024 Jelmer Vernooij
+theme:
+  name: material
+markdown_extensions:
+  - pymdownx.emoji:
+      emoji_index: !!python/name:material.extensions.emoji.twemoji
+""",
+        )
+        self.assertGuesses(
+            [
+                ("Name", "example"),
+                ("Summary", "An example project"),
+                ("Documentation", "https://example.readthedocs.io/"),
+                ("Repository", "https://github.com/jelmer/example"),
+                ("Copyright", "Copyright &copy; 2024 Jelmer Vernooij"),
+            ],
+            guess_from_mkdocs_yml(path),
+        )
+
+
+class GuessFromAuthorsFileTests(GuesserTestCase):
+    def test_sections(self):
+        path = self.write_file(
+            "AUTHORS",
+            """Maintainers:
+  Jelmer Vernooij <jelmer@jelmer.uk>
+
+Authors:
+  * Somebody Else <somebody@example.com>
+  * Another Person (https://example.com/~another)
+
+Translators:
+  Translator <translator@example.com>
+""",
+        )
+        self.assertGuesses(
+            [
+                (
+                    "Author",
+                    [
+                        Person("Somebody Else", "somebody@example.com"),
+                        Person("Another Person", url="https://example.com/~another"),
+                    ],
+                ),
+                ("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk")),
+            ],
+            guess_from_authors_file(path),
+        )
+
+    def test_maintainers(self):
+        path = self.write_file(
+            "MAINTAINERS.md",
+            """Maintainers
+===========
+
+- Jelmer Vernooij <jelmer@jelmer.uk>
+
+The maintainers review and merge all changes to the project.
+""",
+        )
+        self.assertGuesses(
+            [("Maintainer", Person("Jelmer Vernooij", "jelmer@jelmer.uk"))],
+            guess_from_authors_file(path),
+        )
+
+
+class GuessFromLicenseFileTests(GuesserTestCase):
+    def test_mit(self):
+        path = self.write_file(
+            "LICENSE",
+            """MIT License
+
+Copyright (c) 2024 Jelmer Vernooij
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction.
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+""",
+        )
+        self.assertGuesses([("License", "MIT")], guess_from_license_file(path))
+
+    def test_lgpl(self):
+        path = self.write_file(
+            "COPYING",
+            """                   GNU LESSER GENERAL PUBLIC LICENSE
+                       Version 3, 29 June 2007
+
+  This version of the GNU Lesser General Public License incorporates
+the terms and conditions of version 3 of the GNU General Public
+License, supplemented by the additional permissions listed below.
+""",
+        )
+        self.assertGuesses([("License", "LGPL-3.0")], guess_from_license_file(path))
+
+    def test_unknown(self):
+        path = self.write_file("LICENSE", "All rights reserved.\n")
+        self.assertGuesses([], guess_from_license_file(path))
+
+    def test_gpl3_mentions_other_licenses(self):
+        # The GPLv3 refers to the Affero GPL in section 13 and to the Lesser GPL in
+        # its final paragraph
+        path = self.write_file(
+            "COPYING",
+            """                    GNU GENERAL PUBLIC LICENSE
+                       Version 3, 29 June 2007
+
+  13. Use with the GNU Affero General Public License.
+
+  Notwithstanding any other provision of this License, you have
+permission to link or combine any covered work with a work licensed
+under version 3 of the GNU Affero General Public License into a single
+combined work, and to convey the resulting work.
+
+  The GNU General Public License does not permit incorporating your program
+into proprietary programs.  If your program is a subroutine library, you
+may consider it more useful to permit linking proprietary applications with
+the library.  If this is what you want to do, use the GNU Lesser General
+Public License instead of this License.  But first, please read
+<https://www.gnu.org/licenses/why-not-lgpl.html>.
+""",
+        )
+        self.assertGuesses([("License", "GPL-3.0")], guess_from_license_file(path))
+
+    def test_full_texts(self):
+        for name, expected in [
+            ("GPL-2", "GPL-2.0"),
+            ("GPL-3", "GPL-3.0"),
+            ("LGPL-2.1", "LGPL-2.1"),
+            ("LGPL-3", "LGPL-3.0"),
+            ("Apache-2.0", "Apache-2.0"),
+            ("MPL-2.0", "MPL-2.0"),
+        ]:
+            path = os.path.join("/usr/share/common-licenses", name)
+            if not os.path.exists(path):
+                continue
+            with self.subTest(name):
+                self.assertGuesses(
+                    [("License", expected)], guess_from_license_file(path)
+                )
+
+
+class GuessFromChangelogFileTests(GuesserTestCase):
+    def test_keepachangelog(self):
+        path = self.write_file(
+            "CHANGELOG.md",
+            """# Changelog
+
+All notable changes to this project will be documented in this file.
+
+## [Unreleased]
+
+- Something new.
+
+## [1.2.3] - 2024-01-01
+
+- Something old.
+""",
+        )
+        self.assertGuesses([("Version", "1.2.3")], guess_from_changelog_file(path))
+
+    def test_news(self):
+        path = self.write_file(
+            "NEWS",
+            """0.21.1\tUNRELEASED
+
+ * Fix a bug.
+
+0.21.0\t2023-01-01
+
+ * Add a feature, requiring Python 3.8.
+""",
+        )
+        self.assertGuesses([("Version", "0.21.0")], guess_from_changelog_file(path))
+
+    def test_hosted_changelog(self):
+        self.write_file("CHANGELOG.md", "## 1.0.0\n")
+        self.write_file(
+            "mkdocs.yml",
+            "site_name: example\nrepo_url: https://github.com/jelmer/example\n",
+        )
+        metadata = guess_upstream_metadata(self.tmpdir)
+        self.assertEqual(
+            "https://github.com/jelmer/example/blob/HEAD/CHANGELOG.md",
+            metadata["Changelog"].value,
+        )
+
+
+class GuessVersionFromGitTests(GuesserTestCase):
+    def git(self, *args):
+        env = dict(
+            os.environ,
+            GIT_AUTHOR_NAME="Jelmer Vernooij",
+            GIT_AUTHOR_EMAIL="jelmer@jelmer.uk",
+            GIT_COMMITTER_NAME="Jelmer Vernooij",
+            GIT_COMMITTER_EMAIL="jelmer@jelmer.uk",
+        )
+        subprocess.check_call(
+            ["git", *args], cwd=self.tmpdir, env=env, stdout=subprocess.DEVNULL
+        )
+
+    def test_tag(self):
+        self.git("init", "-q")
+        self.write_file("README", "Example")
+        self.git("add", "README")
+        self.git("commit", "-q", "-m", "Initial commit")
+        self.git("tag", "v1.2.3")
+        self.write_file("README", "Example project")
+        self.git("commit", "-q", "-a", "-m", "Update README")
+        self.assertGuesses([("Version", "1.2.3")], guess_version_from_git(self.tmpdir))
+
+    def test_no_tags(self):
+        self.git("init", "-q")
+        self.assertGuesses([], guess_version_from_git(self.tmpdir))
+
+
+class MailmapTests(GuesserTestCase):
+    def test_canonicalize_authors(self):
+        self.write_file(
+            "AUTHORS.md",
+            """# Authors
+
+- Jelmer Vernooij <jelmer@jelmer.uk>
+- Jelmer <jelmer@debian.org>
+- Somebody Else <somebody@example.com>
+""",
+        )
+        self.write_file(
+            ".mailmap",
+            """Jelmer Vernooij <jelmer@jelmer.uk> <jelmer@debian.org>
+Somebody Else <somebody@example.org> <somebody@example.com>
+""",
+        )
+        metadata = guess_upstream_metadata(self.tmpdir)
+        self.assertEqual(
+            [
+                Person("Jelmer Vernooij", "jelmer@jelmer.uk"),
+                Person("Somebody Else", "somebody@example.org"),
+            ],
+            metadata["Author"].value,
+        )
+
+
+class KnownBadPatternTests(GuesserTestCase):
+    pattern = r"https://intranet\.example\.org/.*"
+
+    def setUp(self):
+        super().setUp()
+        self.write_file(
+            "mkdocs.yml",
+            "site_name: example\nsite_url: https://intranet.example.org/example/\n",
+        )
+
+    def test_filtered(self):
+        add_known_bad_pattern("Documentation", self.pattern)
+        self.addCleanup(remove_known_bad_pattern, "Documentation", self.pattern)
+        metadata = guess_upstream_metadata(self.tmpdir)
+        self.assertNotIn("Documentation", metadata)
+
+    def test_removed(self):
+        add_known_bad_pattern("Documentation", self.pattern)
+        remove_known_bad_pattern("Documentation", self.pattern)
+        metadata = guess_upstream_metadata(self.tmpdir)
+        self.assertEqual(
+            "https://intranet.example.org/example/", metadata["Documentation"].value
+        )
+
+
+class ExtrapolateFieldsTests(TestCase):
+    def test_from_repository(self):
+        metadata = UpstreamMetadata()
+        metadata["Repository"] = UpstreamDatum(
+            "Repository", "https://github.com/jelmer/example.git", "certain"
+        )
+        extrapolate_fields(metadata, net_access=False)
+        self.assertEqual(
+            "https://github.com/jelmer/example",
+            metadata["Repository-Browse"].value,
+        )
+        self.assertEqual(
+            "https://github.com/jelmer/example/issues", metadata["Bug-Database"].value
+        )
+        self.assertEqual(
+            "https://github.com/jelmer/example/issues/new",
+            metadata["Bug-Submit"].value,
+        )
+
+    def test_registered_rule(self):
+        def wiki_from_homepage(metadata):
+            return [UpstreamDatum("Wiki", metadata["Homepage"].value + "wiki")]
+
+        register_extrapolation(["Homepage"], ["Wiki"], wiki_from_homepage)
+        self.addCleanup(unregister_extrapolation, wiki_from_homepage)
+        metadata = UpstreamMetadata()
+        metadata["Homepage"] = UpstreamDatum(
+            "Homepage", "https://extrapolate.example.com/", "likely"
+        )
+        extrapolate_fields(metadata, net_access=False)
+        self.assertEqual("https://extrapolate.example.com/wiki", metadata["Wiki"].value)
+        self.assertEqual("likely", metadata["Wiki"].certainty)
+
+    def test_unregister(self):
+        def wiki_from_homepage(metadata):
+            return [UpstreamDatum("Wiki", metadata["Homepage"].value + "wiki")]
+
+        register_extrapolation(["Homepage"], ["Wiki"], wiki_from_homepage)
+        unregister_extrapolation(wiki_from_homepage)
+        metadata = UpstreamMetadata()
+        metadata["Homepage"] = UpstreamDatum(
+            "Homepage", "https://extrapolate.example.com/", "likely"
+        )
+        extrapolate_fields(metadata, net_access=False)
+        self.assertNotIn("Wiki", metadata)
+
+
+class UpdateMetadataFileTests(GuesserTestCase):
+    def setUp(self):
+        super().setUp()
+        self.metadata = UpstreamMetadata()
+        self.metadata["Repository"] = UpstreamDatum(
+            "Repository", "https://github.com/jelmer/example.git", "certain"
+        )
+        self.metadata["Bug-Database"] = UpstreamDatum(
+            "Bug-Database", "https://github.com/jelmer/example/issues", "certain"
+        )
+        self.metadata["Wiki"] = UpstreamDatum(
+            "Wiki", "https://github.com/jelmer/example/wiki", "possible"
+        )
+
+    def read_file(self, path):
+        with open(path) as f:
+            return f.read()
+
+    def test_preserves_existing(self):
+        path = self.write_file(
+            "debian/upstream/metadata",
+            """\
+---
+# Maintained by hand
+Reference:
+  Title: Something
+Repository: https://github.com/jelmer/example.git
+""",
+        )
+        self.assertEqual(["Bug-Database"], update_metadata_file(path, self.metadata))
+        self.assertEqual(
+            """\
+---
+# Maintained by hand
+Reference:
+  Title: Something
+Repository: https://github.com/jelmer/example.git
+Bug-Database: https://github.com/jelmer/example/issues
+""",
+            self.read_file(path),
+        )
+
+    def test_replaces_in_place(self):
+        path = self.write_file(
+            "debian/upstream/metadata",
+            """\
+---
+Repository:
+  https://example.com/example.git
+
+# Moved from example.com
+Name: example
+""",
+        )
+        self.assertEqual(
+            ["Repository", "Bug-Database"], update_metadata_file(path, self.metadata)
+        )
+        self.assertEqual(
+            """\
+---
+Repository: https://github.com/jelmer/example.git
+
+# Moved from example.com
+Name: example
+Bug-Database: https://github.com/jelmer/example/issues
+""",
+            self.read_file(path),
+        )
+
+    def test_replaces_keeping_comments(self):
+        path = self.write_file(
+            "debian/upstream/metadata",
+            """\
+---
+Repository: https://example.com/example.git  # mirror
+Author:
+- Someone Else  # left in 2020
+# see AUTHORS
+- Another One
+Summary: "Not a # comment"  # but this is
+""",
+        )
+        self.metadata["Author"] = UpstreamDatum(
+            "Author", ["Jelmer Vernooij <jelmer@example.com>"], "certain"
+        )
+        self.metadata["Summary"] = UpstreamDatum("Summary", "An example", "certain")
+        self.assertEqual(
+            ["Author", "Bug-Database", "Repository", "Summary"],
+            sorted(update_metadata_file(path, self.metadata)),
+        )
+        self.assertEqual(
+            """\
+---
+Repository: https://github.com/jelmer/example.git  # mirror
+# left in 2020
+# see AUTHORS
+Author:
+- Jelmer Vernooij <jelmer@example.com>
+Summary: An example  # but this is
+Bug-Database: https://github.com/jelmer/example/issues
+""",
+            self.read_file(path),
+        )
+
+    def test_keeps_existing_over_uncertain(self):
+        path = self.write_file(
+            "metadata", "Wiki: https://example.com/wiki\n"
+        )
+        self.assertEqual(
+            ["Bug-Database", "Repository"],
+            sorted(
+                update_metadata_file(path, self.metadata, minimum_certainty="possible")
+            ),
+        )
+        self.assertTrue(
+            self.read_file(path).startswith("Wiki: https://example.com/wiki\n")
+        )
+
+    def test_minimum_certainty(self):
+        path = self.write_file("debian/upstream/metadata", "---\n")
+        self.assertEqual(
+            ["Bug-Database", "Repository", "Wiki"],
+            sorted(
+                update_metadata_file(path, self.metadata, minimum_certainty="possible")
+            ),
+        )
+
+    def test_creates_file(self):
+        path = os.path.join(self.tmpdir, "metadata")
+        update_metadata_file(path, self.metadata)
+        self.assertEqual(
+            """\
+---
+Bug-Database: https://github.com/jelmer/example/issues
+Repository: https://github.com/jelmer/example.git
+""",
+            self.read_file(path),
+        )
+
+    def test_nothing_to_do(self):
+        path = self.write_file(
+            "metadata", "Repository: https://github.com/jelmer/example.git"
+        )
+        del self.metadata["Bug-Database"]
+        self.assertEqual([], update_metadata_file(path, self.metadata))
+        self.assertEqual(
+            "Repository: https://github.com/jelmer/example.git", self.read_file(path)
+        )
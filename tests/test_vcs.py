@@ -22,6 +22,7 @@ from upstream_ontologist.vcs import (
     canonical_git_repo_url,
     find_public_repo_url,
     fixup_rcp_style_git_repo_url,
+    repo_url_from_merge_request_url,
 )
 
 
@@ -42,6 +43,16 @@ class CanonicalizeVcsUrlTests(TestCase):
             canonical_git_repo_url("https://salsa.debian.org/jelmer/example.git"),
         )
 
+    def test_bitbucket(self):
+        self.assertEqual(
+            "https://bitbucket.org/jelmer/example.git",
+            canonical_git_repo_url("https://bitbucket.org/jelmer/example"),
+        )
+        self.assertEqual(
+            "https://bitbucket.org/jelmer/example.git",
+            canonical_git_repo_url("https://bitbucket.org/jelmer/example/src/main/"),
+        )
+
 
 class FindPublicVcsUrlTests(TestCase):
     def test_github(self):
@@ -97,3 +108,32 @@ class FixupRcpStyleUrlTests(TestCase):
             "ssh://git@salsa.debian.org/jelmer/example",
             fixup_rcp_style_git_repo_url("ssh://git@salsa.debian.org/jelmer/example"),
         )
+
+
+class RepoUrlFromMergeRequestUrlTests(TestCase):
+    def test_github(self):
+        self.assertEqual(
+            "https://github.com/jelmer/example",
+            repo_url_from_merge_request_url(
+                "https://github.com/jelmer/example/pull/12/files"
+            ),
+        )
+
+    def test_gitlab(self):
+        self.assertEqual(
+            "https://salsa.debian.org/python-team/packages/example",
+            repo_url_from_merge_request_url(
+                "https://salsa.debian.org/python-team/packages/example/-/merge_requests/3"
+            ),
+        )
+
+    def test_gitea(self):
+        self.assertEqual(
+            "https://codeberg.org/jelmer/example",
+            repo_url_from_merge_request_url("https://codeberg.org/jelmer/example/pulls/4"),
+        )
+
+    def test_not_merge_request(self):
+        self.assertIsNone(
+            repo_url_from_merge_request_url("https://github.com/jelmer/example/pulls")
+        )